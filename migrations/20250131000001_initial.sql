@@ -30,20 +30,4 @@ CREATE INDEX IF NOT EXISTS idx_testimoni_user_id ON testimoni(user_id);
 CREATE INDEX IF NOT EXISTS idx_testimoni_is_approved ON testimoni(is_approved);
 CREATE INDEX IF NOT EXISTS idx_testimoni_created_at ON testimoni(created_at);
 
--- Insert default admin user (password: admin123)
-INSERT INTO users (id, full_name, email, password_hash, is_admin, created_at, updated_at)
-VALUES (
-    gen_random_uuid(),
-    'Admin User',
-    'admin@tabungin.com',
-    '$2b$12$mEOUp/O8NClRVs2ZDXekQevkYo61qjeiyTQeQTx1aakBJTY1SeY5m',
-    TRUE,
-    NOW(),
-    NOW()
-) ON CONFLICT (email) DO UPDATE
-SET 
-    full_name = EXCLUDED.full_name,
-    password_hash = EXCLUDED.password_hash,
-    is_admin = EXCLUDED.is_admin,
-    updated_at = NOW();
-
+-- Admin default tidak lagi dibuat di migrasi, jalankan `cargo run --bin seed -- admin`
@@ -0,0 +1,24 @@
+-- Role-based authorization menggantikan kolom users.is_admin
+
+CREATE TABLE IF NOT EXISTS roles (
+    name VARCHAR(20) PRIMARY KEY,
+    description TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+INSERT INTO roles (name, description)
+VALUES
+    ('admin', 'Akses penuh ke seluruh fitur admin'),
+    ('moderator', 'Hanya bisa melihat dan approve/reject testimoni'),
+    ('user', 'User biasa')
+ON CONFLICT (name) DO NOTHING;
+
+ALTER TABLE users
+ADD COLUMN IF NOT EXISTS role VARCHAR(20) NOT NULL DEFAULT 'user' REFERENCES roles(name);
+
+-- Migrasi admin lama ke role admin
+UPDATE users SET role = 'admin' WHERE is_admin = TRUE;
+
+ALTER TABLE users DROP COLUMN IF EXISTS is_admin;
+
+CREATE INDEX IF NOT EXISTS idx_users_role ON users(role);
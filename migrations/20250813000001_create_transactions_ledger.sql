@@ -0,0 +1,96 @@
+-- Ledger transaksi append-only sebagai satu-satunya sumber saldo
+-- savings_targets.current_amount dan user_statistics.total_saved sekarang hanya cache
+-- yang di-maintain trigger dari tabel ini
+
+CREATE TABLE IF NOT EXISTS transactions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    savings_target_id UUID NOT NULL REFERENCES savings_targets(id) ON DELETE CASCADE,
+    transaction_type VARCHAR(20) NOT NULL
+        CHECK (transaction_type IN ('deposit', 'withdrawal', 'transfer', 'adjustment', 'reversal')),
+    -- Positif = uang masuk ke target, negatif = uang keluar
+    amount DECIMAL(15,2) NOT NULL CHECK (amount <> 0),
+    description TEXT,
+    -- Menghubungkan beberapa entry yang terjadi bersamaan (misal dua sisi transfer)
+    group_id UUID,
+    reverses_transaction_id UUID UNIQUE REFERENCES transactions(id),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_transactions_user_id ON transactions(user_id);
+CREATE INDEX IF NOT EXISTS idx_transactions_savings_target_id ON transactions(savings_target_id);
+CREATE INDEX IF NOT EXISTS idx_transactions_group_id ON transactions(group_id);
+CREATE INDEX IF NOT EXISTS idx_transactions_created_at ON transactions(created_at);
+
+-- Ledger tidak boleh diubah. DELETE hanya boleh lewat cascade (hapus target/user),
+-- koreksi saldo harus lewat entry 'reversal' atau 'adjustment'
+CREATE OR REPLACE FUNCTION prevent_transactions_mutation()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' AND pg_trigger_depth() > 1 THEN
+        RETURN OLD;
+    END IF;
+
+    RAISE EXCEPTION 'transactions ledger is append-only (% not allowed)', TG_OP;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trigger_prevent_transactions_mutation
+    BEFORE UPDATE OR DELETE ON transactions
+    FOR EACH ROW
+    EXECUTE FUNCTION prevent_transactions_mutation();
+
+-- user_statistics seharusnya satu baris per user (ON CONFLICT (user_id) butuh unique constraint)
+DELETE FROM user_statistics a
+USING user_statistics b
+WHERE a.user_id = b.user_id
+  AND (a.updated_at, a.id) < (b.updated_at, b.id);
+
+ALTER TABLE user_statistics
+ADD CONSTRAINT user_statistics_user_id_key UNIQUE (user_id);
+
+-- Saldo target dan total user diturunkan dari ledger
+CREATE OR REPLACE FUNCTION apply_ledger_transaction()
+RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE savings_targets
+    SET current_amount = COALESCE(current_amount, 0) + NEW.amount,
+        is_completed = COALESCE(current_amount, 0) + NEW.amount >= target_amount,
+        updated_at = NOW()
+    WHERE id = NEW.savings_target_id;
+
+    INSERT INTO user_statistics (user_id, total_saved, streak_days, daily_average, achievements_count, last_deposit_date)
+    VALUES (NEW.user_id, NEW.amount, 0, 0, 0, NULL)
+    ON CONFLICT (user_id) DO UPDATE SET
+        total_saved = COALESCE(user_statistics.total_saved, 0) + NEW.amount,
+        updated_at = NOW();
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Trigger sync lama (sync_trigger.sql) menghitung ulang saldo dari activities, tidak dipakai lagi
+DROP TRIGGER IF EXISTS trigger_sync_target_amount ON activities;
+DROP FUNCTION IF EXISTS sync_target_current_amount();
+
+-- Saldo awal: satu entry adjustment per target sesuai current_amount saat ini
+INSERT INTO transactions (user_id, savings_target_id, transaction_type, amount, description, created_at)
+SELECT user_id, id, 'adjustment', current_amount, 'Saldo awal (migrasi ke ledger)', COALESCE(created_at, NOW())
+FROM savings_targets
+WHERE COALESCE(current_amount, 0) <> 0;
+
+-- Samakan total_saved dengan ledger
+UPDATE user_statistics us
+SET total_saved = COALESCE((SELECT SUM(t.amount) FROM transactions t WHERE t.user_id = us.user_id), 0),
+    updated_at = NOW();
+
+INSERT INTO user_statistics (user_id, total_saved, streak_days, daily_average, achievements_count, last_deposit_date)
+SELECT user_id, SUM(amount), 0, 0, 0, NULL
+FROM transactions
+GROUP BY user_id
+ON CONFLICT (user_id) DO NOTHING;
+
+CREATE TRIGGER trigger_apply_ledger_transaction
+    AFTER INSERT ON transactions
+    FOR EACH ROW
+    EXECUTE FUNCTION apply_ledger_transaction();
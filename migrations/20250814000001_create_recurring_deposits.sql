@@ -0,0 +1,23 @@
+-- Aturan tabungan otomatis (setoran berulang) per target
+CREATE TABLE IF NOT EXISTS recurring_deposits (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    savings_target_id UUID NOT NULL REFERENCES savings_targets(id) ON DELETE CASCADE,
+    amount DECIMAL(15,2) NOT NULL CHECK (amount > 0),
+    frequency VARCHAR(10) NOT NULL CHECK (frequency IN ('daily', 'weekly', 'monthly')),
+    -- Hanya untuk monthly; kalau bulan lebih pendek dipakai tanggal terakhir bulan itu
+    day_of_month SMALLINT CHECK (day_of_month BETWEEN 1 AND 31),
+    start_date DATE NOT NULL,
+    end_date DATE,
+    next_run_date DATE NOT NULL,
+    is_paused BOOLEAN NOT NULL DEFAULT false,
+    last_run_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CHECK (end_date IS NULL OR end_date >= start_date),
+    CHECK (frequency = 'monthly' OR day_of_month IS NULL)
+);
+
+CREATE INDEX IF NOT EXISTS idx_recurring_deposits_user_id ON recurring_deposits(user_id);
+CREATE INDEX IF NOT EXISTS idx_recurring_deposits_savings_target_id ON recurring_deposits(savings_target_id);
+CREATE INDEX IF NOT EXISTS idx_recurring_deposits_due ON recurring_deposits(next_run_date) WHERE NOT is_paused;
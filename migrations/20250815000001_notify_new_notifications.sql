@@ -0,0 +1,17 @@
+-- Kabari listener setiap ada notifikasi baru supaya bisa di-push ke client (SSE).
+-- Payload hanya id + user_id (batas pg_notify 8000 byte), isi notifikasi diambil ulang oleh listener.
+CREATE OR REPLACE FUNCTION notify_new_notification()
+RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM pg_notify('notifications', json_build_object(
+        'id', NEW.id,
+        'user_id', NEW.user_id
+    )::text);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trigger_notify_new_notification
+    AFTER INSERT ON notifications
+    FOR EACH ROW
+    EXECUTE FUNCTION notify_new_notification();
@@ -0,0 +1,33 @@
+-- Index untuk global search (/search): full-text (config 'simple', tanpa stemming bahasa)
+-- dan trigram untuk pencarian sebagian kata / typo
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX IF NOT EXISTS idx_savings_targets_search_fts
+    ON savings_targets USING GIN (to_tsvector('simple', name));
+CREATE INDEX IF NOT EXISTS idx_savings_targets_search_trgm
+    ON savings_targets USING GIN (name gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_activities_search_fts
+    ON activities USING GIN (to_tsvector('simple', title || ' ' || COALESCE(description, '')));
+CREATE INDEX IF NOT EXISTS idx_activities_search_trgm
+    ON activities USING GIN (title gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_reminders_search_fts
+    ON reminders USING GIN (to_tsvector('simple', title || ' ' || COALESCE(description, '')));
+CREATE INDEX IF NOT EXISTS idx_reminders_search_trgm
+    ON reminders USING GIN (title gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_notifications_search_fts
+    ON notifications USING GIN (to_tsvector('simple', message));
+CREATE INDEX IF NOT EXISTS idx_notifications_search_trgm
+    ON notifications USING GIN (message gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_users_search_fts
+    ON users USING GIN (to_tsvector('simple', full_name || ' ' || email));
+CREATE INDEX IF NOT EXISTS idx_users_search_trgm
+    ON users USING GIN ((full_name || ' ' || email) gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_testimoni_search_fts
+    ON testimoni USING GIN (to_tsvector('simple', content));
+CREATE INDEX IF NOT EXISTS idx_testimoni_search_trgm
+    ON testimoni USING GIN (content gin_trgm_ops);
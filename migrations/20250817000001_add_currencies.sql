@@ -0,0 +1,40 @@
+-- Multi-currency: mata uang per target dan mata uang utama (home currency) per user.
+-- Daftar kode yang didukung ada di src/utils/currency.rs, di sini cukup format ISO 4217.
+ALTER TABLE users
+    ADD COLUMN IF NOT EXISTS currency VARCHAR(3) NOT NULL DEFAULT 'IDR' CHECK (currency ~ '^[A-Z]{3}$');
+
+ALTER TABLE savings_targets
+    ADD COLUMN IF NOT EXISTS currency VARCHAR(3) NOT NULL DEFAULT 'IDR' CHECK (currency ~ '^[A-Z]{3}$');
+
+-- Kurs: 1 base_currency = rate quote_currency, berlaku mulai effective_date
+CREATE TABLE IF NOT EXISTS exchange_rates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    base_currency VARCHAR(3) NOT NULL CHECK (base_currency ~ '^[A-Z]{3}$'),
+    quote_currency VARCHAR(3) NOT NULL CHECK (quote_currency ~ '^[A-Z]{3}$'),
+    rate NUMERIC(20,10) NOT NULL CHECK (rate > 0),
+    effective_date DATE NOT NULL DEFAULT CURRENT_DATE,
+    source VARCHAR(10) NOT NULL DEFAULT 'manual' CHECK (source IN ('manual', 'import')),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CHECK (base_currency <> quote_currency),
+    UNIQUE (base_currency, quote_currency, effective_date)
+);
+
+-- Kurs from -> to yang berlaku pada on_date: kurs terbaru dari pasangan langsung atau
+-- kebalikannya (1 / rate). NULL kalau belum ada kurs sama sekali.
+CREATE OR REPLACE FUNCTION exchange_rate(from_currency VARCHAR, to_currency VARCHAR, on_date DATE)
+RETURNS NUMERIC AS $$
+    SELECT CASE WHEN from_currency = to_currency THEN 1::numeric ELSE (
+        SELECT r.rate FROM (
+            SELECT rate, effective_date, 0 AS preference
+            FROM exchange_rates
+            WHERE base_currency = from_currency AND quote_currency = to_currency AND effective_date <= on_date
+            UNION ALL
+            SELECT 1 / rate, effective_date, 1 AS preference
+            FROM exchange_rates
+            WHERE base_currency = to_currency AND quote_currency = from_currency AND effective_date <= on_date
+        ) r
+        ORDER BY r.effective_date DESC, r.preference
+        LIMIT 1
+    ) END
+$$ LANGUAGE sql STABLE;
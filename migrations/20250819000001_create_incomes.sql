@@ -0,0 +1,21 @@
+-- Kategori dipakai untuk pemasukan maupun pengeluaran; budget hanya untuk kategori pengeluaran
+ALTER TABLE expense_categories
+    ADD COLUMN IF NOT EXISTS kind VARCHAR(10) NOT NULL DEFAULT 'expense' CHECK (kind IN ('income', 'expense'));
+
+ALTER TABLE expenses ADD COLUMN IF NOT EXISTS payee VARCHAR(100);
+
+-- Pemasukan, nominal dalam mata uang utama user seperti expenses
+CREATE TABLE IF NOT EXISTS incomes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    category_id UUID NOT NULL REFERENCES expense_categories(id),
+    amount DECIMAL(15,2) NOT NULL CHECK (amount > 0),
+    payee VARCHAR(100),
+    description VARCHAR(255),
+    received_on DATE NOT NULL DEFAULT CURRENT_DATE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_incomes_user_received_on ON incomes(user_id, received_on DESC);
+CREATE INDEX IF NOT EXISTS idx_incomes_category_id ON incomes(category_id);
@@ -0,0 +1,38 @@
+-- Target tabungan bersama. savings_targets.user_id tetap pembuat target,
+-- hak akses ditentukan dari tabel anggota ini.
+CREATE TABLE IF NOT EXISTS savings_target_members (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    savings_target_id UUID NOT NULL REFERENCES savings_targets(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role VARCHAR(20) NOT NULL CHECK (role IN ('owner', 'contributor', 'viewer')),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (savings_target_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_savings_target_members_user_id ON savings_target_members(user_id);
+
+-- Semua target yang sudah ada: pembuatnya jadi owner
+INSERT INTO savings_target_members (savings_target_id, user_id, role, created_at)
+SELECT id, user_id, 'owner', COALESCE(created_at, NOW())
+FROM savings_targets
+ON CONFLICT (savings_target_id, user_id) DO NOTHING;
+
+-- Undangan lewat email, penerima belum tentu sudah punya akun
+CREATE TABLE IF NOT EXISTS savings_target_invitations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    savings_target_id UUID NOT NULL REFERENCES savings_targets(id) ON DELETE CASCADE,
+    email VARCHAR(255) NOT NULL,
+    role VARCHAR(20) NOT NULL CHECK (role IN ('owner', 'contributor', 'viewer')),
+    invited_by UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'accepted', 'declined', 'revoked')),
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() + INTERVAL '7 days',
+    responded_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Satu undangan pending per email per target
+CREATE UNIQUE INDEX IF NOT EXISTS idx_target_invitations_pending
+    ON savings_target_invitations(savings_target_id, LOWER(email))
+    WHERE status = 'pending';
+CREATE INDEX IF NOT EXISTS idx_target_invitations_email ON savings_target_invitations(LOWER(email));
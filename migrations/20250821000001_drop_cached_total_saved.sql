@@ -0,0 +1,20 @@
+-- total_saved dan daily_average di user_statistics menjumlah nominal ledger apa adanya (lintas mata uang,
+-- dikreditkan ke penyetor walau targetnya milik orang lain) dan tidak dibaca lagi sejak statistik
+-- dihitung dari saldo target yang sudah dikonversi. Hapus supaya hanya ada satu definisi total_saved.
+
+CREATE OR REPLACE FUNCTION apply_ledger_transaction()
+RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE savings_targets
+    SET current_amount = COALESCE(current_amount, 0) + NEW.amount,
+        is_completed = COALESCE(current_amount, 0) + NEW.amount >= target_amount,
+        updated_at = NOW()
+    WHERE id = NEW.savings_target_id;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+ALTER TABLE user_statistics
+DROP COLUMN IF EXISTS total_saved,
+DROP COLUMN IF EXISTS daily_average;
@@ -0,0 +1,5 @@
+-- Rule yang gagal diproses tidak lagi dicoba ulang setiap putaran worker: kegagalan dicatat,
+-- rule dicoba lagi besok dan di-pause setelah beberapa kali gagal berturut-turut
+ALTER TABLE recurring_deposits
+ADD COLUMN IF NOT EXISTS failure_count INTEGER NOT NULL DEFAULT 0,
+ADD COLUMN IF NOT EXISTS last_error TEXT;
@@ -0,0 +1,14 @@
+-- Tiket sekali pakai untuk membuka stream SSE. EventSource tidak bisa mengirim header Authorization,
+-- jadi client menukar access token dengan tiket berumur pendek yang boleh muncul di URL (dan access log)
+-- Yang disimpan hanya hash SHA-256 tiketnya
+
+CREATE TABLE IF NOT EXISTS stream_tickets (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    session_id UUID NOT NULL REFERENCES user_sessions(id) ON DELETE CASCADE,
+    ticket_hash VARCHAR(64) NOT NULL UNIQUE,
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_stream_tickets_user_id ON stream_tickets(user_id);
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+use actix_web::web;
+use sqlx::PgPool;
+
+use crate::config::Config;
+use crate::errors;
+use crate::handlers;
+use crate::mailer::Mailer;
+use crate::services::notification_hub::NotificationHub;
+
+/// Dependency yang di-share ke semua handler lewat `web::Data`
+#[derive(Clone)]
+pub struct AppState {
+    pub pool: PgPool,
+    pub config: Config,
+    pub mailer: Arc<dyn Mailer>,
+    pub notification_hub: Arc<NotificationHub>,
+}
+
+impl AppState {
+    /// Pasang state, error handler extractor dan semua route. Dipakai main.rs dan integration
+    /// test, jadi App di test sama dengan production (minus CORS dan Logger).
+    pub fn configure(&self, cfg: &mut web::ServiceConfig) {
+        cfg.app_data(web::Data::new(self.pool.clone()))
+            .app_data(web::Data::new(self.config.clone()))
+            .app_data(web::Data::from(self.mailer.clone()))
+            .app_data(web::Data::from(self.notification_hub.clone()))
+            .app_data(web::JsonConfig::default().error_handler(errors::extractor_error))
+            .app_data(web::QueryConfig::default().error_handler(errors::extractor_error))
+            .app_data(web::PathConfig::default().error_handler(errors::extractor_error))
+            .service(
+                web::scope("/api/v1")
+                    .service(handlers::auth::auth_routes())
+                    .service(handlers::user::user_routes())
+                    .service(handlers::testimoni::testimoni_routes())
+                    .service(handlers::dashboard::dashboard_routes())
+                    .service(handlers::password::password_routes())
+                    .service(handlers::savings::savings_routes())
+                    .service(handlers::activity::activity_routes())
+                    .service(handlers::statistics::statistics_routes())
+                    .service(handlers::reminder::reminder_routes())
+                    .service(handlers::recurring_deposit::recurring_deposit_routes())
+                    .service(handlers::notification::notification_routes())
+                    .service(handlers::search::search_routes())
+                    .service(handlers::currency::currency_routes())
+                    .service(handlers::budget::budget_routes())
+                    .service(handlers::expense::expense_routes())
+                    .service(handlers::income::income_routes())
+                    .service(handlers::report::report_routes())
+                    .configure(handlers::docs::docs_routes)
+            )
+            .service(handlers::health::health_check);
+    }
+}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+use bcrypt::{hash, DEFAULT_COST};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+const EMAIL: &str = "admin@tabungin.com";
+const PASSWORD: &str = "admin123";
+
+/// Admin default untuk lokal dev/staging. Admin yang sudah ada tidak diubah (password tetap).
+pub async fn seed(conn: &mut PgConnection) -> Result<()> {
+    let password_hash = hash(PASSWORD, DEFAULT_COST)?;
+
+    let created = sqlx::query!(
+        r#"
+        INSERT INTO users (id, full_name, email, password_hash, role)
+        VALUES ($1, 'Admin User', $2, $3, 'admin')
+        ON CONFLICT (email) DO NOTHING
+        "#,
+        Uuid::new_v4(),
+        EMAIL,
+        password_hash
+    )
+    .execute(&mut *conn)
+    .await?
+    .rows_affected();
+
+    if created > 0 {
+        println!("   ✅ {} dibuat (password: {})", EMAIL, PASSWORD);
+    } else {
+        println!("   ⏭️  {} sudah ada", EMAIL);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use anyhow::Result;
+use bcrypt::{hash, DEFAULT_COST};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+pub const EMAIL: &str = "umar@app.com";
+const PASSWORD: &str = "password123";
+const TARGET_NAME: &str = "Dana Darurat";
+
+const WELCOME_NOTIFICATIONS: &[(&str, &str)] = &[
+    ("Selamat datang di Tabungin!", "success"),
+    ("Yuk buat target tabungan pertamamu dan mulai menabung hari ini.", "info"),
+];
+
+pub struct DemoUser {
+    pub user_id: Uuid,
+    pub target_id: Uuid,
+}
+
+pub async fn seed(conn: &mut PgConnection) -> Result<()> {
+    ensure(conn).await?;
+    Ok(())
+}
+
+/// Buat user demo beserta target "Dana Darurat" kalau belum ada, dipakai juga oleh profile lain
+pub async fn ensure(conn: &mut PgConnection) -> Result<DemoUser> {
+    let user_id = ensure_user(conn).await?;
+    let target_id = ensure_target(conn, user_id).await?;
+
+    let has_notifications = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM notifications WHERE user_id = $1) as "exists!""#,
+        user_id
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    if !has_notifications {
+        for (message, notification_type) in WELCOME_NOTIFICATIONS {
+            sqlx::query!(
+                "INSERT INTO notifications (user_id, message, type) VALUES ($1, $2, $3)",
+                user_id,
+                message,
+                notification_type
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+    }
+
+    Ok(DemoUser { user_id, target_id })
+}
+
+async fn ensure_user(conn: &mut PgConnection) -> Result<Uuid> {
+    if let Some(user_id) = sqlx::query_scalar!("SELECT id FROM users WHERE LOWER(email) = $1", EMAIL)
+        .fetch_optional(&mut *conn)
+        .await?
+    {
+        println!("   ⏭️  {} sudah ada", EMAIL);
+        return Ok(user_id);
+    }
+
+    let user_id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO users (id, full_name, email, password_hash, role, created_at, updated_at)
+        VALUES ($1, 'Umar', $2, $3, 'user', NOW() - INTERVAL '30 days', NOW())
+        RETURNING id
+        "#,
+        Uuid::new_v4(),
+        EMAIL,
+        hash(PASSWORD, DEFAULT_COST)?
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    println!("   ✅ {} dibuat (password: {})", EMAIL, PASSWORD);
+    Ok(user_id)
+}
+
+async fn ensure_target(conn: &mut PgConnection, user_id: Uuid) -> Result<Uuid> {
+    if let Some(target_id) = sqlx::query_scalar!(
+        "SELECT id FROM savings_targets WHERE user_id = $1 AND name = $2",
+        user_id,
+        TARGET_NAME
+    )
+    .fetch_optional(&mut *conn)
+    .await?
+    {
+        return Ok(target_id);
+    }
+
+    // Reminder deadline ikut dibuat trigger create_target_reminders
+    let target_id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO savings_targets (user_id, name, target_amount, icon, icon_color, target_date, created_at)
+        VALUES ($1, $2, 5000000, '🛡️', 'bg-blue-500', CURRENT_DATE + 180, NOW() - INTERVAL '30 days')
+        RETURNING id
+        "#,
+        user_id,
+        TARGET_NAME
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    sqlx::query!(
+        "INSERT INTO savings_target_members (savings_target_id, user_id, role) VALUES ($1, $2, 'owner')",
+        target_id,
+        user_id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO activities (user_id, savings_target_id, activity_type, title, description, amount, icon, icon_color, created_at)
+        VALUES ($1, $2, 'target_created', 'Target baru dibuat', $3, 0, '🎯', 'bg-red-500', NOW() - INTERVAL '30 days')
+        "#,
+        user_id,
+        target_id,
+        format!("Target \"{}\" berhasil dibuat", TARGET_NAME)
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    println!("   ✅ Target {} dibuat", TARGET_NAME);
+    Ok(target_id)
+}
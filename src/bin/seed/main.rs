@@ -0,0 +1,75 @@
+//! Seeder data demo. Migrasi hanya berisi schema, data contoh dibuat eksplisit per environment:
+//!
+//!     cargo run --bin seed -- demo-user streak testimoni
+//!     cargo run --bin seed -- --list
+//!
+//! Setiap profile idempotent (aman dijalankan ulang) dan jalan di transaksinya sendiri.
+mod admin;
+mod demo_user;
+mod streak;
+mod testimoni;
+
+use anyhow::{bail, Result};
+use sqlx::{PgConnection, PgPool};
+use std::env;
+
+use tabungin_api::db;
+
+const PROFILES: &[(&str, &str)] = &[
+    ("admin", "Admin default admin@tabungin.com (password: admin123)"),
+    ("demo-user", "User demo umar@app.com dengan target Dana Darurat dan notifikasi sambutan"),
+    ("streak", "Deposit harian 16 hari terakhir untuk user demo (ikut membuat demo-user)"),
+    ("testimoni", "10 user reviewer dan testimoni (sebagian menunggu moderasi)"),
+];
+
+async fn run_profile(conn: &mut PgConnection, profile: &str) -> Result<()> {
+    match profile {
+        "admin" => admin::seed(conn).await,
+        "demo-user" => demo_user::seed(conn).await,
+        "streak" => streak::seed(conn).await,
+        "testimoni" => testimoni::seed(conn).await,
+        _ => bail!("Unknown seed profile: {}", profile),
+    }
+}
+
+fn print_usage() {
+    println!("Usage: cargo run --bin seed -- <profile>...\n");
+    println!("Profiles:");
+    for (name, description) in PROFILES {
+        println!("  {:<10} {}", name, description);
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenv::dotenv().ok();
+
+    let profiles: Vec<String> = env::args().skip(1).collect();
+    if profiles.is_empty() || profiles.iter().any(|arg| arg == "--list" || arg == "--help") {
+        print_usage();
+        return Ok(());
+    }
+    if let Some(unknown) = profiles
+        .iter()
+        .find(|profile| !PROFILES.iter().any(|(name, _)| name == profile))
+    {
+        print_usage();
+        bail!("Unknown seed profile: {}", unknown);
+    }
+
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPool::connect(&database_url).await?;
+
+    // Seeder butuh schema terbaru
+    db::run_migrations(&pool).await?;
+
+    for profile in &profiles {
+        println!("🌱 Seeding {}...", profile);
+        let mut tx = pool.begin().await?;
+        run_profile(&mut tx, profile).await?;
+        tx.commit().await?;
+    }
+
+    println!("🎉 Seeding selesai: {}", profiles.join(", "));
+    Ok(())
+}
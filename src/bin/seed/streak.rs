@@ -0,0 +1,96 @@
+use anyhow::Result;
+use bigdecimal::BigDecimal;
+use chrono::{Duration, FixedOffset, Utc};
+use sqlx::PgConnection;
+
+use crate::demo_user;
+
+/// Nominal deposit harian, yang terakhir untuk hari ini (WIB)
+const DAILY_AMOUNTS: [i64; 16] = [
+    75000, 82000, 68000, 91000, 76000, 85000, 79000, 88000,
+    72000, 95000, 81000, 77000, 90000, 83000, 74000, 86000,
+];
+
+/// Deposit lewat ledger (saldo target ikut ter-update trigger) plus activity
+/// deposit, satu per hari sampai hari ini. Hari yang sudah punya deposit dilewati, jadi
+/// menjalankan ulang besoknya cukup menyambung streak.
+pub async fn seed(conn: &mut PgConnection) -> Result<()> {
+    let demo = demo_user::ensure(conn).await?;
+    let today = Utc::now()
+        .with_timezone(&FixedOffset::east_opt(7 * 3600).unwrap())
+        .date_naive();
+
+    let mut created = 0;
+    for (i, amount) in DAILY_AMOUNTS.iter().enumerate() {
+        let date = today - Duration::days((DAILY_AMOUNTS.len() - 1 - i) as i64);
+
+        let exists = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM activities
+                WHERE user_id = $1 AND activity_type = 'deposit'
+                  AND (created_at AT TIME ZONE 'Asia/Jakarta')::date = $2
+            ) as "exists!"
+            "#,
+            demo.user_id,
+            date
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+        if exists {
+            continue;
+        }
+
+        // 10:00 WIB
+        let deposited_at = date.and_hms_opt(3, 0, 0).unwrap().and_utc();
+        let amount = BigDecimal::from(*amount);
+
+        sqlx::query!(
+            r#"
+            INSERT INTO transactions (user_id, savings_target_id, transaction_type, amount, description, created_at)
+            VALUES ($1, $2, 'deposit', $3, 'Nabung harian', $4)
+            "#,
+            demo.user_id,
+            demo.target_id,
+            amount,
+            deposited_at
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO activities (user_id, savings_target_id, activity_type, title, description, amount, icon, icon_color, created_at)
+            VALUES ($1, $2, 'deposit', $3, 'Deposit rutin untuk dana darurat', $4, '💰', 'bg-green-500', $5)
+            "#,
+            demo.user_id,
+            demo.target_id,
+            format!("Nabung Hari Ke-{}", i + 1),
+            amount,
+            deposited_at
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        created += 1;
+    }
+
+    // Saldo sudah diurus trigger ledger, streak diisi di sini
+    sqlx::query!(
+        r#"
+        UPDATE user_statistics
+        SET streak_days = GREATEST(streak_days, $2),
+            last_deposit_date = $3,
+            updated_at = NOW()
+        WHERE user_id = $1
+        "#,
+        demo.user_id,
+        DAILY_AMOUNTS.len() as i32,
+        today
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    println!("   ✅ {} deposit baru untuk {}", created, demo_user::EMAIL);
+    Ok(())
+}
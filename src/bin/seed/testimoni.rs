@@ -0,0 +1,179 @@
+use anyhow::Result;
+use bcrypt::{hash, DEFAULT_COST};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+const REVIEWER_PASSWORD: &str = "password123";
+
+/// (nama, email, umur akun dalam hari)
+const REVIEWERS: &[(&str, &str, i32)] = &[
+    ("Sari Wijaya", "sari.wijaya@gmail.com", 30),
+    ("Budi Santoso", "budi.santoso@yahoo.com", 25),
+    ("Andi Pratama", "andi.pratama@gmail.com", 20),
+    ("Maya Sari", "maya.sari@outlook.com", 18),
+    ("Rizki Firmansyah", "rizki.firmansyah@gmail.com", 15),
+    ("Dewi Lestari", "dewi.lestari@yahoo.com", 12),
+    ("Arif Rahman", "arif.rahman@gmail.com", 10),
+    ("Lisa Indrawati", "lisa.indrawati@gmail.com", 8),
+    ("Joko Widodo", "joko.widodo@yahoo.com", 6),
+    ("Sinta Maharani", "sinta.maharani@outlook.com", 4),
+];
+
+/// (isi, rating, approved, berapa hari lalu)
+const TESTIMONI: &[(&str, i32, bool, i32)] = &[
+    (
+        "Aplikasi Tabungin sangat membantu dalam financial planning! Target tabungan untuk wedding berhasil tercapai dalam 8 bulan. Fitur automatic saving dan budget tracking-nya luar biasa. Highly recommended! 💍",
+        5,
+        true,
+        7
+    ),
+    (
+        "Interface yang user-friendly dan fitur-fitur yang lengkap. Sebagai working mom, saya terbantu dengan fitur family budgeting. Anak-anak juga jadi belajar menabung dari aplikasi ini. Great job team! 👨‍👩‍👧‍👦",
+        5,
+        true,
+        14
+    ),
+    (
+        "Dulu saya tipe yang boros dan impulsive buying. Setelah pakai Tabungin, spending habits jadi lebih terkontrol. Fitur expense categorization sangat membantu analisis pengeluaran bulanan.",
+        4,
+        true,
+        21
+    ),
+    (
+        "Aplikasi bagus untuk pemula yang ingin belajar financial literacy. Dashboard analytics cukup informatif. Mungkin bisa ditambah fitur investment planning untuk yang sudah advanced.",
+        4,
+        true,
+        10
+    ),
+    (
+        "Fitur goal-based saving sangat motivational! Progress tracking yang visual membuat semangat terus menabung. Customer support juga responsif ketika ada technical issue.",
+        5,
+        true,
+        5
+    ),
+    (
+        "Sebagai freelancer dengan income fluktuatif, Tabungin membantu mengatur cash flow dengan lebih baik. Fitur flexible budgeting sesuai dengan nature pekerjaan freelance.",
+        4,
+        true,
+        18
+    ),
+    (
+        "Security system yang reliable dan user experience yang smooth. Multi-device sync juga bekerja dengan baik. Overall satisfied dengan performance aplikasi ini.",
+        4,
+        true,
+        12
+    ),
+    (
+        "Aplikasi cukup bagus untuk basic saving activities. Loading speed kadang agak lambat dan beberapa fitur advanced masih perlu improvement. Tapi untuk harga segini worth it lah.",
+        3,
+        true,
+        25
+    ),
+    (
+        "Game-changer untuk financial management! Dari yang tadinya selalu minus di akhir bulan, sekarang bisa saving 25% dari salary. Achievement system-nya juga fun dan engaging! 🏆",
+        5,
+        false,
+        2
+    ),
+    (
+        "Baru pakai 2 minggu tapi sudah keliatan hasilnya. Spending awareness jadi meningkat drastis. Notification reminder juga membantu maintain saving discipline. Keep up the good work!",
+        5,
+        false,
+        1
+    ),
+    (
+        "Integration dengan mobile banking akan lebih praktis. Dark mode juga masih belum ada. Tapi untuk core functionality sudah sangat membantu organizing finances.",
+        3,
+        false,
+        3
+    ),
+    (
+        "Excellent app for financial education! Artikel-artikel di education center sangat bermanfaat. Features lengkap dan execution-nya solid. Definitely recommend to everyone! 📚",
+        5,
+        false,
+        4
+    ),
+];
+
+/// User reviewer (bisa login dengan password123) dan testimoninya. Testimoni yang isinya
+/// sudah ada untuk user yang sama dilewati.
+pub async fn seed(conn: &mut PgConnection) -> Result<()> {
+    let password_hash = hash(REVIEWER_PASSWORD, DEFAULT_COST)?;
+
+    let mut reviewers = Vec::with_capacity(REVIEWERS.len());
+    for (full_name, email, days_ago) in REVIEWERS {
+        sqlx::query!(
+            r#"
+            INSERT INTO users (id, full_name, email, password_hash, role, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, 'user', NOW() - INTERVAL '1 day' * $5, NOW() - INTERVAL '1 day' * $5)
+            ON CONFLICT (email) DO NOTHING
+            "#,
+            Uuid::new_v4(),
+            full_name,
+            email,
+            password_hash,
+            *days_ago as f64
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        let user_id = sqlx::query_scalar!("SELECT id FROM users WHERE email = $1", email)
+            .fetch_one(&mut *conn)
+            .await?;
+        reviewers.push(user_id);
+    }
+
+    let mut inserted_count = 0;
+    for (i, (content, rating, is_approved, days_ago)) in TESTIMONI.iter().enumerate() {
+        let user_id = reviewers[i % reviewers.len()];
+
+        let inserted = sqlx::query!(
+            r#"
+            INSERT INTO testimoni (id, user_id, content, rating, is_approved, created_at, updated_at)
+            SELECT $1, $2, $3, $4, $5, NOW() - INTERVAL '1 day' * $6, NOW() - INTERVAL '1 day' * $6
+            WHERE NOT EXISTS (SELECT 1 FROM testimoni WHERE user_id = $2 AND content = $3)
+            "#,
+            Uuid::new_v4(),
+            user_id,
+            content,
+            rating,
+            is_approved,
+            *days_ago as f64
+        )
+        .execute(&mut *conn)
+        .await?
+        .rows_affected();
+
+        inserted_count += inserted;
+    }
+
+    // Generate summary
+    let summary = sqlx::query!(
+        r#"
+        SELECT 
+            rating,
+            is_approved,
+            COUNT(*) as count
+        FROM testimoni
+        GROUP BY rating, is_approved
+        ORDER BY rating DESC, is_approved DESC
+        "#
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    
+    println!("\n📊 Testimoni Summary:");
+    println!("┌─────────┬──────────┬───────┐");
+    println!("│ Rating  │ Approved │ Count │");
+    println!("├─────────┼──────────┼───────┤");
+    for row in summary {
+        println!("│   {}     │    {}     │   {}   │", 
+                 row.rating, 
+                 if row.is_approved.unwrap_or(false) { "✓" } else { "✗" }, 
+                 row.count.unwrap_or(0));
+    }
+    println!("└─────────┴──────────┴───────┘");
+    
+    println!("   ✅ {} testimoni baru dari {} reviewer", inserted_count, reviewers.len());
+    Ok(())
+}
@@ -41,8 +41,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let umar_hashed = hash("password123", DEFAULT_COST)?;
         sqlx::query(
             r#"
-            INSERT INTO users (id, full_name, email, password_hash, is_admin, created_at, updated_at)
-            VALUES (gen_random_uuid(), 'Umar Said', 'umar@app.com', $1, false, NOW(), NOW())
+            INSERT INTO users (id, full_name, email, password_hash, role, created_at, updated_at)
+            VALUES (gen_random_uuid(), 'Umar Said', 'umar@app.com', $1, 'user', NOW(), NOW())
             "#
         )
         .bind(umar_hashed)
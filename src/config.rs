@@ -1,9 +1,49 @@
+use crate::mailer::templates::Locale;
+
 #[derive(Clone)]
 pub struct Config {
     pub database_url: String,
     pub jwt_secret: String,
     pub host: String,
     pub port: u16,
+    pub frontend_url: String,
+    pub mail: MailConfig,
+    pub jobs: JobsConfig,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MailBackend {
+    Smtp,
+    File,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SmtpTls {
+    None,
+    StartTls,
+    Tls,
+}
+
+/// Background job (worker) yang jalan bersama server
+#[derive(Clone)]
+pub struct JobsConfig {
+    pub enabled: bool,
+    pub recurring_deposit_interval_secs: u64,
+    pub reminder_dispatch_interval_secs: u64,
+    pub reminder_email_enabled: bool,
+}
+
+#[derive(Clone)]
+pub struct MailConfig {
+    pub backend: MailBackend,
+    pub from: String,
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub smtp_username: Option<String>,
+    pub smtp_password: Option<String>,
+    pub smtp_tls: SmtpTls,
+    pub spool_dir: String,
+    pub default_locale: Locale,
 }
 
 impl Config {
@@ -19,6 +59,77 @@ impl Config {
                 .unwrap_or_else(|_| "8080".to_string())
                 .parse()
                 .expect("PORT must be a valid number"),
+            frontend_url: std::env::var("FRONTEND_URL")
+                .unwrap_or_else(|_| "http://localhost:3000".to_string()),
+            mail: MailConfig::from_env(),
+            jobs: JobsConfig::from_env(),
+        }
+    }
+}
+
+impl JobsConfig {
+    pub fn from_env() -> Self {
+        Self {
+            enabled: std::env::var("JOBS_ENABLED")
+                .map(|value| value != "false" && value != "0")
+                .unwrap_or(true),
+            recurring_deposit_interval_secs: std::env::var("RECURRING_DEPOSIT_INTERVAL_SECS")
+                .unwrap_or_else(|_| "300".to_string())
+                .parse()
+                .expect("RECURRING_DEPOSIT_INTERVAL_SECS must be a valid number"),
+            reminder_dispatch_interval_secs: std::env::var("REMINDER_DISPATCH_INTERVAL_SECS")
+                .unwrap_or_else(|_| "600".to_string())
+                .parse()
+                .expect("REMINDER_DISPATCH_INTERVAL_SECS must be a valid number"),
+            reminder_email_enabled: std::env::var("REMINDER_EMAIL_ENABLED")
+                .map(|value| value != "false" && value != "0")
+                .unwrap_or(true),
+        }
+    }
+}
+
+impl MailConfig {
+    pub fn from_env() -> Self {
+        // Default pakai file spool supaya lokal dev tidak butuh SMTP server
+        let backend = match std::env::var("MAIL_BACKEND")
+            .unwrap_or_else(|_| "file".to_string())
+            .to_lowercase()
+            .as_str()
+        {
+            "smtp" => MailBackend::Smtp,
+            "file" => MailBackend::File,
+            other => panic!("MAIL_BACKEND must be 'smtp' or 'file', got '{}'", other),
+        };
+
+        let smtp_tls = match std::env::var("SMTP_TLS")
+            .unwrap_or_else(|_| "starttls".to_string())
+            .to_lowercase()
+            .as_str()
+        {
+            "none" => SmtpTls::None,
+            "starttls" => SmtpTls::StartTls,
+            "tls" => SmtpTls::Tls,
+            other => panic!("SMTP_TLS must be 'none', 'starttls' or 'tls', got '{}'", other),
+        };
+
+        Self {
+            backend,
+            from: std::env::var("MAIL_FROM")
+                .unwrap_or_else(|_| "Tabungin <no-reply@tabungin.com>".to_string()),
+            smtp_host: std::env::var("SMTP_HOST")
+                .unwrap_or_else(|_| "localhost".to_string()),
+            smtp_port: std::env::var("SMTP_PORT")
+                .unwrap_or_else(|_| "587".to_string())
+                .parse()
+                .expect("SMTP_PORT must be a valid number"),
+            smtp_username: std::env::var("SMTP_USERNAME").ok(),
+            smtp_password: std::env::var("SMTP_PASSWORD").ok(),
+            smtp_tls,
+            spool_dir: std::env::var("MAIL_SPOOL_DIR")
+                .unwrap_or_else(|_| "mail_spool".to_string()),
+            default_locale: std::env::var("MAIL_DEFAULT_LOCALE")
+                .map(|code| Locale::from_code(&code))
+                .unwrap_or(Locale::Id),
         }
     }
 }
@@ -0,0 +1,56 @@
+use anyhow::Result;
+use sqlx::migrate::Migrator;
+use sqlx::PgPool;
+
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// Migrasi lama yang dulu juga mengisi data demo dan sudah dijadikan schema-only (data demo
+/// pindah ke `src/bin/seed`). Database yang sudah menjalankan versi lama tidak perlu apa-apa,
+/// cukup checksum di `_sqlx_migrations` disamakan supaya sqlx tidak menolak migrasi yang berubah.
+const SCHEMA_ONLY_REWRITES: &[i64] = &[
+    20250131000001,
+    20250131000002,
+    20250805000001,
+    20250805000003,
+    20250806000001,
+    20250808000001,
+    20250809000001,
+    20250811000001,
+];
+
+/// Jalankan semua migrasi yang belum diterapkan
+pub async fn run_migrations(pool: &PgPool) -> Result<()> {
+    accept_schema_only_rewrites(pool).await?;
+    MIGRATOR.run(pool).await?;
+    Ok(())
+}
+
+async fn accept_schema_only_rewrites(pool: &PgPool) -> Result<()> {
+    // Database baru belum punya tabel migrasi
+    let has_table: bool = sqlx::query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+        .fetch_one(pool)
+        .await?;
+    if !has_table {
+        return Ok(());
+    }
+
+    for migration in MIGRATOR.iter() {
+        if !SCHEMA_ONLY_REWRITES.contains(&migration.version) {
+            continue;
+        }
+
+        let result = sqlx::query(
+            "UPDATE _sqlx_migrations SET checksum = $1 WHERE version = $2 AND checksum <> $1",
+        )
+        .bind(migration.checksum.as_ref())
+        .bind(migration.version)
+        .execute(pool)
+        .await?;
+
+        if result.rows_affected() > 0 {
+            println!("ℹ️  Migrasi {} sekarang schema-only, checksum diperbarui", migration.version);
+        }
+    }
+
+    Ok(())
+}
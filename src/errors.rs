@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+
+use actix_web::{http::StatusCode, HttpRequest, HttpResponse, ResponseError};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::ValidationErrors;
+
+/// Error aplikasi yang dipetakan ke response HTTP. Service tetap memakai `anyhow::Result`;
+/// error yang perlu status khusus dibuat sebagai `AppError` lalu di-downcast lagi di handler.
+#[derive(Debug, thiserror::Error)]
+pub enum AppError {
+    #[error("Validation failed")]
+    Validation(#[from] ValidationErrors),
+    #[error("{0}")]
+    BadRequest(String),
+    #[error("{0}")]
+    Unauthorized(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Conflict(String),
+    /// Request valid tapi melanggar aturan bisnis (saldo tidak cukup, dsb)
+    #[error("{0}")]
+    Unprocessable(String),
+    #[error("Internal server error")]
+    Internal(anyhow::Error),
+}
+
+/// Detail satu error validasi pada field
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FieldError {
+    pub code: String,
+    pub message: String,
+}
+
+/// Body JSON untuk semua response error
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ErrorBody {
+    pub error: String,
+    /// Kode stabil untuk dicek client, misal `not_found`, `validation_failed`
+    pub code: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<BTreeMap<String, Vec<FieldError>>>,
+}
+
+impl AppError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::Validation(_) => "validation_failed",
+            AppError::BadRequest(_) => "bad_request",
+            AppError::Unauthorized(_) => "unauthorized",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::NotFound(_) => "not_found",
+            AppError::Conflict(_) => "conflict",
+            AppError::Unprocessable(_) => "unprocessable_entity",
+            AppError::Internal(_) => "internal_error",
+        }
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            AppError::Validation(_) => "Validation failed",
+            AppError::BadRequest(_) => "Bad request",
+            AppError::Unauthorized(_) => "Unauthorized",
+            AppError::Forbidden(_) => "Forbidden",
+            AppError::NotFound(_) => "Not found",
+            AppError::Conflict(_) => "Conflict",
+            AppError::Unprocessable(_) => "Unprocessable entity",
+            AppError::Internal(_) => "Internal server error",
+        }
+    }
+
+    pub fn body(&self) -> ErrorBody {
+        let fields = match self {
+            AppError::Validation(errors) => Some(
+                errors
+                    .field_errors()
+                    .into_iter()
+                    .map(|(field, errors)| {
+                        let errors = errors
+                            .iter()
+                            .map(|error| FieldError {
+                                code: error.code.to_string(),
+                                message: error
+                                    .message
+                                    .as_ref()
+                                    .map(|message| message.to_string())
+                                    .unwrap_or_else(|| error.code.to_string()),
+                            })
+                            .collect();
+                        (field.to_string(), errors)
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        };
+
+        ErrorBody {
+            error: self.title().to_string(),
+            code: self.code().to_string(),
+            message: self.to_string(),
+            fields,
+        }
+    }
+}
+
+impl ResponseError for AppError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            AppError::Validation(_) | AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::Unprocessable(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        // Detail error internal hanya masuk log, tidak dikirim ke client
+        if let AppError::Internal(e) = self {
+            eprintln!("❌ Internal error: {:#}", e);
+        }
+        HttpResponse::build(self.status_code()).json(self.body())
+    }
+}
+
+/// Dipasang di JsonConfig/QueryConfig/PathConfig supaya body/query/path yang tidak bisa
+/// di-parse juga memakai format error yang sama
+pub fn extractor_error<E: std::fmt::Display>(error: E, _req: &HttpRequest) -> actix_web::Error {
+    AppError::BadRequest(error.to_string()).into()
+}
+
+impl From<anyhow::Error> for AppError {
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<AppError>() {
+            Ok(app_error) => app_error,
+            Err(error) => AppError::Internal(error),
+        }
+    }
+}
+
+impl From<sqlx::Error> for AppError {
+    fn from(error: sqlx::Error) -> Self {
+        AppError::Internal(error.into())
+    }
+}
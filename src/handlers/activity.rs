@@ -1,77 +1,91 @@
-use actix_web::{web, HttpResponse, Result, Scope};
+use actix_web::{web, HttpResponse, Scope};
 use sqlx::PgPool;
 use validator::Validate;
 
 use crate::services::activity_service::{
     create_activity, get_user_activities, get_recent_activities_for_dashboard
 };
-use crate::middleware::auth::AuthenticatedUser;
-use crate::models::CreateActivityRequest;
-use crate::utils::response::{ErrorResponse, ApiResponse};
+use crate::middleware::auth::{AuthenticatedUser, AdminUser};
+use crate::models::{Activity, ActivityResponse, CreateActivityRequest, ActivityFilter};
+use crate::utils::pagination::{ListQuery, Page, Sort, SortField};
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::response::ApiResponse;
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/activities",
+    tag = "activities",
+    security(("bearer_auth" = [])),
+    request_body = CreateActivityRequest,
+    responses(
+        (status = 201, description = "Activity created", body = ApiResponse<Activity>),
+        (status = 400, description = "Invalid request", body = ErrorBody),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn create_activity_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
     req: web::Json<CreateActivityRequest>,
-) -> Result<HttpResponse> {
-    if let Err(errors) = req.validate() {
-        return Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Validation failed".to_string(),
-            message: format!("{:?}", errors),
-        }));
-    }
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
 
-    match create_activity(&pool, user.id, req.into_inner()).await {
-        Ok(activity) => Ok(HttpResponse::Created().json(ApiResponse {
-            success: true,
-            message: "Activity created successfully".to_string(),
-            data: Some(activity),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to create activity".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    let activity = create_activity(&pool, user.id, req.into_inner()).await?;
+    Ok(HttpResponse::Created().json(ApiResponse {
+        success: true,
+        message: "Activity created successfully".to_string(),
+        data: Some(activity),
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/activities",
+    tag = "activities",
+    security(("bearer_auth" = [])),
+    params(ListQuery, ActivityFilter),
+    responses(
+        (status = 200, description = "Activities of the logged in user", body = ApiResponse<Page<ActivityResponse>>),
+        (status = 400, description = "Invalid pagination parameters", body = ErrorBody),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn get_user_activities_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
-    query: web::Query<serde_json::Map<String, serde_json::Value>>,
-) -> Result<HttpResponse> {
-    let limit = query
-        .get("limit")
-        .and_then(|v| v.as_i64())
-        .map(|v| v as i32);
+    query: web::Query<ListQuery>,
+    filter: web::Query<ActivityFilter>,
+) -> Result<HttpResponse, AppError> {
+    let page = query.parse(&[SortField::CreatedAt], Sort::desc(SortField::CreatedAt))?;
 
-    match get_user_activities(&pool, user.id, limit).await {
-        Ok(activities) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Activities retrieved successfully".to_string(),
-            data: Some(activities),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to retrieve activities".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    let activities = get_user_activities(&pool, user.id, &filter, &page).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Activities retrieved successfully".to_string(),
+        data: Some(activities),
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/activities/recent",
+    tag = "activities",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Latest activities of all users (admin only)", body = ApiResponse<Vec<ActivityResponse>>),
+        (status = 403, description = "Admin privileges required", body = ErrorBody),
+    )
+)]
 pub async fn get_recent_activities_handler(
-    _user: AuthenticatedUser, // Admin access
+    _user: AdminUser,
     pool: web::Data<PgPool>,
-) -> Result<HttpResponse> {
-    match get_recent_activities_for_dashboard(&pool).await {
-        Ok(activities) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Recent activities retrieved successfully".to_string(),
-            data: Some(activities),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to retrieve recent activities".to_string(),
-            message: e.to_string(),
-        })),
-    }
+) -> Result<HttpResponse, AppError> {
+    let activities = get_recent_activities_for_dashboard(&pool).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Recent activities retrieved successfully".to_string(),
+        data: Some(activities),
+    }))
 }
 
 pub fn activity_routes() -> Scope {
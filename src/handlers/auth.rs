@@ -1,71 +1,145 @@
-use actix_web::{web, HttpResponse, Result, Scope};
+use actix_web::{web, HttpResponse, Scope};
 use sqlx::PgPool;
 use validator::Validate;
 
-use crate::models::{RegisterRequest, LoginRequest};
+use crate::middleware::auth::AuthenticatedUser;
+use crate::models::{RegisterRequest, LoginRequest, RefreshTokenRequest, AuthResponse, RevokedSessions};
 use crate::services::auth_service::{register_user, login_user};
-use crate::utils::response::{ErrorResponse, ApiResponse};
+use crate::services::session_service::{refresh_session, revoke_session, revoke_all_sessions};
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::response::{ApiResponse, Empty};
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/register",
+    tag = "auth",
+    request_body = RegisterRequest,
+    responses(
+        (status = 201, description = "User registered", body = ApiResponse<AuthResponse>),
+        (status = 400, description = "Invalid request", body = ErrorBody),
+        (status = 409, description = "Email already registered", body = ErrorBody),
+    )
+)]
 pub async fn register_handler(
     pool: web::Data<PgPool>,
     form: web::Json<RegisterRequest>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     // Validate request
-    if let Err(errors) = form.validate() {
-        return Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Validation failed".to_string(),
-            message: format!("{:?}", errors),
-        }));
-    }
+    form.validate()?;
 
     // Check if passwords match
     if form.password != form.confirm_password {
-        return Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Password mismatch".to_string(),
-            message: "Password and confirm password do not match".to_string(),
-        }));
+        return Err(AppError::BadRequest("Password and confirm password do not match".to_string()));
     }
 
-    match register_user(&pool, &form).await {
-        Ok(auth_response) => Ok(HttpResponse::Created().json(ApiResponse {
-            success: true,
-            message: "User registered successfully".to_string(),
-            data: Some(auth_response),
-        })),
-        Err(e) => Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Registration failed".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    let auth_response = register_user(&pool, &form).await?;
+    Ok(HttpResponse::Created().json(ApiResponse {
+        success: true,
+        message: "User registered successfully".to_string(),
+        data: Some(auth_response),
+    }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/login",
+    tag = "auth",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "Login successful", body = ApiResponse<AuthResponse>),
+        (status = 400, description = "Invalid request", body = ErrorBody),
+        (status = 401, description = "Invalid email or password", body = ErrorBody),
+    )
+)]
 pub async fn login_handler(
     pool: web::Data<PgPool>,
     form: web::Json<LoginRequest>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     // Validate request
-    if let Err(errors) = form.validate() {
-        return Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Validation failed".to_string(),
-            message: format!("{:?}", errors),
-        }));
-    }
+    form.validate()?;
 
-    match login_user(&pool, &form).await {
-        Ok(auth_response) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Login successful".to_string(),
-            data: Some(auth_response),
-        })),
-        Err(e) => Ok(HttpResponse::Unauthorized().json(ErrorResponse {
-            error: "Login failed".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    let auth_response = login_user(&pool, &form).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Login successful".to_string(),
+        data: Some(auth_response),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/refresh",
+    tag = "auth",
+    request_body = RefreshTokenRequest,
+    responses(
+        (status = 200, description = "Token refreshed", body = ApiResponse<AuthResponse>),
+        (status = 401, description = "Refresh token invalid, expired or reused", body = ErrorBody),
+    )
+)]
+pub async fn refresh_handler(
+    pool: web::Data<PgPool>,
+    form: web::Json<RefreshTokenRequest>,
+) -> Result<HttpResponse, AppError> {
+    // Validate request
+    form.validate()?;
+
+    let auth_response = refresh_session(&pool, &form.refresh_token).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Token refreshed successfully".to_string(),
+        data: Some(auth_response),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/logout",
+    tag = "auth",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Current session revoked", body = ApiResponse<Empty>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
+pub async fn logout_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, AppError> {
+    revoke_session(&pool, user.session_id, user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse::<Empty> {
+        success: true,
+        message: "Logged out successfully".to_string(),
+        data: None,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/logout-all",
+    tag = "auth",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "All sessions revoked", body = ApiResponse<RevokedSessions>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
+pub async fn logout_all_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, AppError> {
+    let revoked = revoke_all_sessions(pool.get_ref(), user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Logged out from all devices".to_string(),
+        data: Some(RevokedSessions { revoked_sessions: revoked }),
+    }))
 }
 
 pub fn auth_routes() -> Scope {
     web::scope("/auth")
         .route("/register", web::post().to(register_handler))
         .route("/login", web::post().to(login_handler))
+        .route("/refresh", web::post().to(refresh_handler))
+        .route("/logout", web::post().to(logout_handler))
+        .route("/logout-all", web::post().to(logout_all_handler))
 }
@@ -0,0 +1,241 @@
+use actix_web::{web, HttpResponse, Scope};
+use sqlx::PgPool;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::services::budget_service::{
+    create_category, delete_budget, delete_category, get_budget_summary, get_categories, parse_month,
+    set_budget, update_category,
+};
+use crate::middleware::auth::AuthenticatedUser;
+use crate::models::{
+    Budget, BudgetSummary, CategoryFilter, CreateExpenseCategoryRequest, ExpenseCategory, SetBudgetRequest,
+    UpdateExpenseCategoryRequest,
+};
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::response::{ApiResponse, Empty};
+
+fn category_not_found(category_id: Uuid) -> AppError {
+    AppError::NotFound(format!("Expense category with ID {} not found", category_id))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/budgets/categories",
+    tag = "budgets",
+    security(("bearer_auth" = [])),
+    params(CategoryFilter),
+    responses(
+        (status = 200, description = "Income and expense categories of the logged in user", body = ApiResponse<Vec<ExpenseCategory>>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
+pub async fn get_categories_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    filter: web::Query<CategoryFilter>,
+) -> Result<HttpResponse, AppError> {
+    let categories = get_categories(&pool, user.id, filter.kind).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Categories retrieved successfully".to_string(),
+        data: Some(categories),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/budgets/categories",
+    tag = "budgets",
+    security(("bearer_auth" = [])),
+    request_body = CreateExpenseCategoryRequest,
+    responses(
+        (status = 201, description = "Expense category created", body = ApiResponse<ExpenseCategory>),
+        (status = 400, description = "Invalid request", body = ErrorBody),
+        (status = 409, description = "Category with the same name already exists", body = ErrorBody),
+    )
+)]
+pub async fn create_category_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    req: web::Json<CreateExpenseCategoryRequest>,
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+
+    let category = create_category(&pool, user.id, &req).await?;
+    Ok(HttpResponse::Created().json(ApiResponse {
+        success: true,
+        message: "Expense category created successfully".to_string(),
+        data: Some(category),
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/budgets/categories/{id}",
+    tag = "budgets",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Expense category id")),
+    request_body = UpdateExpenseCategoryRequest,
+    responses(
+        (status = 200, description = "Expense category updated", body = ApiResponse<ExpenseCategory>),
+        (status = 404, description = "Expense category not found", body = ErrorBody),
+        (status = 409, description = "Category with the same name already exists", body = ErrorBody),
+    )
+)]
+pub async fn update_category_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+    req: web::Json<UpdateExpenseCategoryRequest>,
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+    let category_id = path.into_inner();
+
+    let category = update_category(&pool, category_id, user.id, &req)
+        .await?
+        .ok_or_else(|| category_not_found(category_id))?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Expense category updated successfully".to_string(),
+        data: Some(category),
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/budgets/categories/{id}",
+    tag = "budgets",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Expense category id")),
+    responses(
+        (status = 200, description = "Expense category and its budgets deleted", body = ApiResponse<Empty>),
+        (status = 404, description = "Expense category not found", body = ErrorBody),
+        (status = 409, description = "Category still has incomes or expenses", body = ErrorBody),
+    )
+)]
+pub async fn delete_category_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let category_id = path.into_inner();
+
+    if !delete_category(&pool, category_id, user.id).await? {
+        return Err(category_not_found(category_id));
+    }
+
+    Ok(HttpResponse::Ok().json(ApiResponse::<Empty> {
+        success: true,
+        message: "Expense category deleted successfully".to_string(),
+        data: None,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/budgets/{month}",
+    tag = "budgets",
+    security(("bearer_auth" = [])),
+    params(("month" = String, Path, description = "Month in `YYYY-MM` format", example = "2025-08")),
+    responses(
+        (status = 200, description = "Planned vs actual vs remaining per category", body = ApiResponse<BudgetSummary>),
+        (status = 400, description = "Invalid month", body = ErrorBody),
+    )
+)]
+pub async fn get_budget_summary_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<String>,
+) -> Result<HttpResponse, AppError> {
+    let month = parse_month(&path)?;
+
+    let summary = get_budget_summary(&pool, user.id, month).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Budget summary retrieved successfully".to_string(),
+        data: Some(summary),
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/budgets/{month}/categories/{category_id}",
+    tag = "budgets",
+    security(("bearer_auth" = [])),
+    params(
+        ("month" = String, Path, description = "Month in `YYYY-MM` format", example = "2025-08"),
+        ("category_id" = Uuid, Path, description = "Expense category id"),
+    ),
+    request_body = SetBudgetRequest,
+    responses(
+        (status = 200, description = "Monthly limit saved, replaces the previous limit", body = ApiResponse<Budget>),
+        (status = 400, description = "Invalid month or amount", body = ErrorBody),
+        (status = 404, description = "Expense category not found", body = ErrorBody),
+    )
+)]
+pub async fn set_budget_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<(String, Uuid)>,
+    req: web::Json<SetBudgetRequest>,
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+    let (month, category_id) = path.into_inner();
+    let month = parse_month(&month)?;
+
+    let budget = set_budget(&pool, user.id, category_id, month, &req.amount)
+        .await?
+        .ok_or_else(|| category_not_found(category_id))?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Budget saved successfully".to_string(),
+        data: Some(budget),
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/budgets/{month}/categories/{category_id}",
+    tag = "budgets",
+    security(("bearer_auth" = [])),
+    params(
+        ("month" = String, Path, description = "Month in `YYYY-MM` format", example = "2025-08"),
+        ("category_id" = Uuid, Path, description = "Expense category id"),
+    ),
+    responses(
+        (status = 200, description = "Monthly limit removed", body = ApiResponse<Empty>),
+        (status = 404, description = "No budget for this category and month", body = ErrorBody),
+    )
+)]
+pub async fn delete_budget_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<(String, Uuid)>,
+) -> Result<HttpResponse, AppError> {
+    let (month, category_id) = path.into_inner();
+    let month = parse_month(&month)?;
+
+    if !delete_budget(&pool, user.id, category_id, month).await? {
+        return Err(AppError::NotFound("Budget not found".to_string()));
+    }
+
+    Ok(HttpResponse::Ok().json(ApiResponse::<Empty> {
+        success: true,
+        message: "Budget deleted successfully".to_string(),
+        data: None,
+    }))
+}
+
+pub fn budget_routes() -> Scope {
+    web::scope("/budgets")
+        .route("/categories", web::get().to(get_categories_handler))
+        .route("/categories", web::post().to(create_category_handler))
+        .route("/categories/{id}", web::put().to(update_category_handler))
+        .route("/categories/{id}", web::delete().to(delete_category_handler))
+        .route("/{month}", web::get().to(get_budget_summary_handler))
+        .route("/{month}/categories/{category_id}", web::put().to(set_budget_handler))
+        .route("/{month}/categories/{category_id}", web::delete().to(delete_budget_handler))
+}
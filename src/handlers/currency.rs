@@ -0,0 +1,114 @@
+use actix_web::{web, HttpResponse, Scope};
+use sqlx::PgPool;
+use validator::Validate;
+
+use crate::services::currency_service::{get_exchange_rates, import_exchange_rates, upsert_exchange_rate};
+use crate::middleware::auth::{AuthenticatedUser, AdminUser};
+use crate::models::{ExchangeRate, ExchangeRateImportResult, ExchangeRateQuery, UpsertExchangeRateRequest};
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::currency::{Currency, CURRENCIES};
+use crate::utils::response::ApiResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/currencies",
+    tag = "currencies",
+    responses(
+        (status = 200, description = "Supported ISO 4217 currencies", body = ApiResponse<Vec<Currency>>),
+    )
+)]
+pub async fn get_currencies_handler() -> Result<HttpResponse, AppError> {
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Currencies retrieved successfully".to_string(),
+        data: Some(CURRENCIES.to_vec()),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/currencies/rates",
+    tag = "currencies",
+    security(("bearer_auth" = [])),
+    params(ExchangeRateQuery),
+    responses(
+        (status = 200, description = "Latest rate per currency pair effective on the given date", body = ApiResponse<Vec<ExchangeRate>>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
+pub async fn get_exchange_rates_handler(
+    _user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    query: web::Query<ExchangeRateQuery>,
+) -> Result<HttpResponse, AppError> {
+    let rates = get_exchange_rates(&pool, &query).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Exchange rates retrieved successfully".to_string(),
+        data: Some(rates),
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/currencies/rates",
+    tag = "currencies",
+    security(("bearer_auth" = [])),
+    request_body = UpsertExchangeRateRequest,
+    responses(
+        (status = 200, description = "Rate saved, replaces the rate of the same pair and date", body = ApiResponse<ExchangeRate>),
+        (status = 400, description = "Unsupported currency or invalid rate", body = ErrorBody),
+        (status = 403, description = "Admin privileges required", body = ErrorBody),
+    )
+)]
+pub async fn upsert_exchange_rate_handler(
+    _user: AdminUser,
+    pool: web::Data<PgPool>,
+    req: web::Json<UpsertExchangeRateRequest>,
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+
+    let rate = upsert_exchange_rate(&pool, &req).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Exchange rate saved successfully".to_string(),
+        data: Some(rate),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/currencies/rates/import",
+    tag = "currencies",
+    security(("bearer_auth" = [])),
+    request_body(
+        content = String,
+        content_type = "text/csv",
+        description = "`base_currency,quote_currency,rate[,effective_date]` per line, header optional",
+    ),
+    responses(
+        (status = 200, description = "All rows imported", body = ApiResponse<ExchangeRateImportResult>),
+        (status = 400, description = "Invalid row, nothing was imported", body = ErrorBody),
+        (status = 403, description = "Admin privileges required", body = ErrorBody),
+    )
+)]
+pub async fn import_exchange_rates_handler(
+    _user: AdminUser,
+    pool: web::Data<PgPool>,
+    body: String,
+) -> Result<HttpResponse, AppError> {
+    let imported = import_exchange_rates(&pool, &body).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: format!("{} exchange rates imported", imported),
+        data: Some(ExchangeRateImportResult { imported }),
+    }))
+}
+
+pub fn currency_routes() -> Scope {
+    web::scope("/currencies")
+        .route("", web::get().to(get_currencies_handler))
+        .route("/rates", web::get().to(get_exchange_rates_handler))
+        .route("/rates", web::put().to(upsert_exchange_rate_handler))
+        .route("/rates/import", web::post().to(import_exchange_rates_handler))
+}
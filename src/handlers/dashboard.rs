@@ -1,84 +1,134 @@
-use actix_web::{web, HttpResponse, Result, Scope};
+use actix_web::{web, HttpResponse, Scope};
 use sqlx::PgPool;
 
 use crate::services::dashboard_service::{
-    get_dashboard_stats, get_user_analytics, get_trend_data, get_rating_distribution
+    get_dashboard_stats, get_user_analytics, get_trend_data, get_rating_distribution,
+    DashboardStats, RatingDistribution, TrendData, UserAnalytics
 };
-use crate::middleware::auth::AuthenticatedUser;
-use crate::utils::response::{ErrorResponse, ApiResponse};
+use crate::services::currency_service::get_user_currency;
+use crate::services::ledger_service::reconcile;
+use crate::middleware::auth::{AuthenticatedUser, AdminUser};
+use crate::models::ReconciliationReport;
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::response::ApiResponse;
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/dashboard/stats",
+    tag = "dashboard",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Global stats (admin only)", body = ApiResponse<DashboardStats>),
+        (status = 403, description = "Admin privileges required", body = ErrorBody),
+    )
+)]
 pub async fn get_dashboard_stats_handler(
-    _user: AuthenticatedUser, // Admin access
+    user: AdminUser,
     pool: web::Data<PgPool>,
-) -> Result<HttpResponse> {
-    match get_dashboard_stats(&pool).await {
-        Ok(stats) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Dashboard stats retrieved successfully".to_string(),
-            data: Some(stats),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to retrieve dashboard stats".to_string(),
-            message: e.to_string(),
-        })),
-    }
+) -> Result<HttpResponse, AppError> {
+    let currency = get_user_currency(pool.get_ref(), user.id).await?;
+    let stats = get_dashboard_stats(&pool, &currency).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Dashboard stats retrieved successfully".to_string(),
+        data: Some(stats),
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/dashboard/analytics",
+    tag = "dashboard",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Analytics of the logged in user", body = ApiResponse<UserAnalytics>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn get_user_analytics_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
-) -> Result<HttpResponse> {
-    match get_user_analytics(&pool, user.id).await {
-        Ok(analytics) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "User analytics retrieved successfully".to_string(),
-            data: Some(analytics),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to retrieve user analytics".to_string(),
-            message: e.to_string(),
-        })),
-    }
+) -> Result<HttpResponse, AppError> {
+    let analytics = get_user_analytics(&pool, user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "User analytics retrieved successfully".to_string(),
+        data: Some(analytics),
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/dashboard/trends",
+    tag = "dashboard",
+    security(("bearer_auth" = [])),
+    params(("days" = Option<i32>, Query, description = "Number of days, default 30")),
+    responses(
+        (status = 200, description = "Daily trend (admin only)", body = ApiResponse<Vec<TrendData>>),
+        (status = 403, description = "Admin privileges required", body = ErrorBody),
+    )
+)]
 pub async fn get_trend_data_handler(
-    _user: AuthenticatedUser, // Admin access
+    user: AdminUser,
     pool: web::Data<PgPool>,
     query: web::Query<serde_json::Map<String, serde_json::Value>>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     let days = query
         .get("days")
         .and_then(|v| v.as_i64())
         .unwrap_or(30) as i32;
 
-    match get_trend_data(&pool, days).await {
-        Ok(trends) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Trend data retrieved successfully".to_string(),
-            data: Some(trends),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to retrieve trend data".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    let currency = get_user_currency(pool.get_ref(), user.id).await?;
+    let trends = get_trend_data(&pool, days, &currency).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Trend data retrieved successfully".to_string(),
+        data: Some(trends),
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/dashboard/ratings",
+    tag = "dashboard",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Testimoni rating distribution (admin only)", body = ApiResponse<Vec<RatingDistribution>>),
+        (status = 403, description = "Admin privileges required", body = ErrorBody),
+    )
+)]
 pub async fn get_rating_distribution_handler(
-    _user: AuthenticatedUser, // Admin access
+    _user: AdminUser,
     pool: web::Data<PgPool>,
-) -> Result<HttpResponse> {
-    match get_rating_distribution(&pool).await {
-        Ok(distribution) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Rating distribution retrieved successfully".to_string(),
-            data: Some(distribution),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to retrieve rating distribution".to_string(),
-            message: e.to_string(),
-        })),
-    }
+) -> Result<HttpResponse, AppError> {
+    let distribution = get_rating_distribution(&pool).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Rating distribution retrieved successfully".to_string(),
+        data: Some(distribution),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/dashboard/reconciliation",
+    tag = "dashboard",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Ledger reconciliation for all users (admin only)", body = ApiResponse<ReconciliationReport>),
+        (status = 403, description = "Admin privileges required", body = ErrorBody),
+    )
+)]
+pub async fn get_reconciliation_handler(
+    _user: AdminUser,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, AppError> {
+    let report = reconcile(&pool, None).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Reconciliation completed".to_string(),
+        data: Some(report),
+    }))
 }
 
 pub fn dashboard_routes() -> Scope {
@@ -87,4 +137,5 @@ pub fn dashboard_routes() -> Scope {
         .route("/analytics", web::get().to(get_user_analytics_handler))
         .route("/trends", web::get().to(get_trend_data_handler))
         .route("/ratings", web::get().to(get_rating_distribution_handler))
+        .route("/reconciliation", web::get().to(get_reconciliation_handler))
 }
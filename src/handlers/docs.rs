@@ -0,0 +1,23 @@
+use actix_web::{web, HttpResponse};
+use utoipa::OpenApi;
+use utoipa_redoc::{Redoc, Servable};
+
+use crate::openapi::ApiDoc;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/openapi.json",
+    tag = "docs",
+    responses(
+        (status = 200, description = "OpenAPI 3 specification of this API", content_type = "application/json"),
+    )
+)]
+pub async fn openapi_json_handler() -> HttpResponse {
+    HttpResponse::Ok().json(ApiDoc::openapi())
+}
+
+/// `/openapi.json` untuk tooling/client generator dan `/docs` untuk dokumentasi Redoc
+pub fn docs_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/openapi.json", web::get().to(openapi_json_handler))
+        .service(Redoc::with_url("/docs", ApiDoc::openapi()));
+}
@@ -0,0 +1,135 @@
+use actix_web::{web, HttpResponse, Scope};
+use sqlx::PgPool;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::services::budget_service::{create_expense, delete_expense, get_expenses, update_expense};
+use crate::middleware::auth::AuthenticatedUser;
+use crate::models::{CreateExpenseRequest, Expense, CashflowFilter, UpdateExpenseRequest};
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::response::{ApiResponse, Empty};
+
+fn not_found(expense_id: Uuid) -> AppError {
+    AppError::NotFound(format!("Expense with ID {} not found", expense_id))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/expenses",
+    tag = "budgets",
+    security(("bearer_auth" = [])),
+    request_body = CreateExpenseRequest,
+    responses(
+        (status = 201, description = "Expense recorded, sends a notification when it exceeds the monthly budget", body = ApiResponse<Expense>),
+        (status = 400, description = "Invalid request", body = ErrorBody),
+        (status = 404, description = "Expense category not found", body = ErrorBody),
+    )
+)]
+pub async fn create_expense_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    req: web::Json<CreateExpenseRequest>,
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+
+    let expense = create_expense(&pool, user.id, &req).await?;
+    Ok(HttpResponse::Created().json(ApiResponse {
+        success: true,
+        message: "Expense recorded successfully".to_string(),
+        data: Some(expense),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/expenses",
+    tag = "budgets",
+    security(("bearer_auth" = [])),
+    params(CashflowFilter),
+    responses(
+        (status = 200, description = "Expenses of the logged in user, newest first", body = ApiResponse<Vec<Expense>>),
+        (status = 400, description = "Invalid month", body = ErrorBody),
+    )
+)]
+pub async fn get_expenses_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    filter: web::Query<CashflowFilter>,
+) -> Result<HttpResponse, AppError> {
+    let expenses = get_expenses(&pool, user.id, &filter).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Expenses retrieved successfully".to_string(),
+        data: Some(expenses),
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/expenses/{id}",
+    tag = "budgets",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Expense id")),
+    request_body = UpdateExpenseRequest,
+    responses(
+        (status = 200, description = "Expense updated", body = ApiResponse<Expense>),
+        (status = 400, description = "Invalid request", body = ErrorBody),
+        (status = 404, description = "Expense or expense category not found", body = ErrorBody),
+    )
+)]
+pub async fn update_expense_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+    req: web::Json<UpdateExpenseRequest>,
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+    let expense_id = path.into_inner();
+
+    let expense = update_expense(&pool, expense_id, user.id, &req)
+        .await?
+        .ok_or_else(|| not_found(expense_id))?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Expense updated successfully".to_string(),
+        data: Some(expense),
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/expenses/{id}",
+    tag = "budgets",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Expense id")),
+    responses(
+        (status = 200, description = "Expense deleted", body = ApiResponse<Empty>),
+        (status = 404, description = "Expense not found", body = ErrorBody),
+    )
+)]
+pub async fn delete_expense_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let expense_id = path.into_inner();
+
+    if !delete_expense(&pool, expense_id, user.id).await? {
+        return Err(not_found(expense_id));
+    }
+
+    Ok(HttpResponse::Ok().json(ApiResponse::<Empty> {
+        success: true,
+        message: "Expense deleted successfully".to_string(),
+        data: None,
+    }))
+}
+
+pub fn expense_routes() -> Scope {
+    web::scope("/expenses")
+        .route("", web::post().to(create_expense_handler))
+        .route("", web::get().to(get_expenses_handler))
+        .route("/{id}", web::put().to(update_expense_handler))
+        .route("/{id}", web::delete().to(delete_expense_handler))
+}
@@ -1,6 +1,12 @@
 use actix_web::{get, HttpResponse, Responder};
 use serde_json::json;
 
+#[utoipa::path(
+    tag = "health",
+    responses(
+        (status = 200, description = "Service is running"),
+    )
+)]
 #[get("/health")]
 pub async fn health_check() -> impl Responder {
     HttpResponse::Ok().json(json!({
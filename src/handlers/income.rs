@@ -0,0 +1,135 @@
+use actix_web::{web, HttpResponse, Scope};
+use sqlx::PgPool;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::services::cashflow_service::{create_income, delete_income, get_incomes, update_income};
+use crate::middleware::auth::AuthenticatedUser;
+use crate::models::{CashflowFilter, CreateIncomeRequest, Income, UpdateIncomeRequest};
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::response::{ApiResponse, Empty};
+
+fn not_found(income_id: Uuid) -> AppError {
+    AppError::NotFound(format!("Income with ID {} not found", income_id))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/incomes",
+    tag = "cashflow",
+    security(("bearer_auth" = [])),
+    request_body = CreateIncomeRequest,
+    responses(
+        (status = 201, description = "Income recorded", body = ApiResponse<Income>),
+        (status = 400, description = "Invalid request", body = ErrorBody),
+        (status = 404, description = "Income category not found", body = ErrorBody),
+    )
+)]
+pub async fn create_income_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    req: web::Json<CreateIncomeRequest>,
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+
+    let income = create_income(&pool, user.id, &req).await?;
+    Ok(HttpResponse::Created().json(ApiResponse {
+        success: true,
+        message: "Income recorded successfully".to_string(),
+        data: Some(income),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/incomes",
+    tag = "cashflow",
+    security(("bearer_auth" = [])),
+    params(CashflowFilter),
+    responses(
+        (status = 200, description = "Incomes of the logged in user, newest first", body = ApiResponse<Vec<Income>>),
+        (status = 400, description = "Invalid month", body = ErrorBody),
+    )
+)]
+pub async fn get_incomes_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    filter: web::Query<CashflowFilter>,
+) -> Result<HttpResponse, AppError> {
+    let incomes = get_incomes(&pool, user.id, &filter).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Incomes retrieved successfully".to_string(),
+        data: Some(incomes),
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/incomes/{id}",
+    tag = "cashflow",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Income id")),
+    request_body = UpdateIncomeRequest,
+    responses(
+        (status = 200, description = "Income updated", body = ApiResponse<Income>),
+        (status = 400, description = "Invalid request", body = ErrorBody),
+        (status = 404, description = "Income or income category not found", body = ErrorBody),
+    )
+)]
+pub async fn update_income_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+    req: web::Json<UpdateIncomeRequest>,
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+    let income_id = path.into_inner();
+
+    let income = update_income(&pool, income_id, user.id, &req)
+        .await?
+        .ok_or_else(|| not_found(income_id))?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Income updated successfully".to_string(),
+        data: Some(income),
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/incomes/{id}",
+    tag = "cashflow",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Income id")),
+    responses(
+        (status = 200, description = "Income deleted", body = ApiResponse<Empty>),
+        (status = 404, description = "Income not found", body = ErrorBody),
+    )
+)]
+pub async fn delete_income_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let income_id = path.into_inner();
+
+    if !delete_income(&pool, income_id, user.id).await? {
+        return Err(not_found(income_id));
+    }
+
+    Ok(HttpResponse::Ok().json(ApiResponse::<Empty> {
+        success: true,
+        message: "Income deleted successfully".to_string(),
+        data: None,
+    }))
+}
+
+pub fn income_routes() -> Scope {
+    web::scope("/incomes")
+        .route("", web::post().to(create_income_handler))
+        .route("", web::get().to(get_incomes_handler))
+        .route("/{id}", web::put().to(update_income_handler))
+        .route("/{id}", web::delete().to(delete_income_handler))
+}
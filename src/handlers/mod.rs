@@ -8,5 +8,12 @@ pub mod savings;
 pub mod activity;
 pub mod statistics;
 pub mod reminder;
+pub mod recurring_deposit;
 pub mod notification;
-pub mod search;
\ No newline at end of file
+pub mod search;
+pub mod currency;
+pub mod budget;
+pub mod expense;
+pub mod income;
+pub mod report;
+pub mod docs;
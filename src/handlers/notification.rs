@@ -1,55 +1,251 @@
-use actix_web::{get, post, web, HttpResponse, Responder};
+use actix_web::{web, web::Bytes, HttpResponse, Scope};
+use futures::stream;
 use sqlx::PgPool;
-use uuid::Uuid;
-use chrono::{DateTime, Utc};
-use crate::models::Notification;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::{interval, MissedTickBehavior};
+use validator::Validate;
 
-#[get("/notifications/{user_id}")]
-pub async fn get_notifications(
-    user_id: web::Path<Uuid>,
+use crate::services::notification_service::{
+    get_notifications, get_unread_count, mark_as_read, mark_all_as_read, delete_notification,
+    DEFAULT_PER_PAGE
+};
+use crate::services::notification_hub::NotificationHub;
+use crate::services::session_service::{create_stream_ticket, is_session_active};
+use crate::middleware::auth::{AuthenticatedUser, EventStreamUser};
+use crate::models::{
+    MarkedAsRead, NotificationListQuery, NotificationPage, NotificationResponse, StreamTicket, UnreadCount
+};
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::response::{ApiResponse, Empty};
+
+fn not_found(notif_id: i32) -> AppError {
+    AppError::NotFound(format!("Notification with ID {} not found", notif_id))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/notifications",
+    tag = "notifications",
+    security(("bearer_auth" = [])),
+    params(NotificationListQuery),
+    responses(
+        (status = 200, description = "Notifications of the logged in user, newest first", body = ApiResponse<NotificationPage>),
+        (status = 400, description = "Invalid request", body = ErrorBody),
+    )
+)]
+pub async fn get_notifications_handler(
+    user: AuthenticatedUser,
     pool: web::Data<PgPool>,
-) -> impl Responder {
-    match sqlx::query_as!(
-        Notification,
-        r#"SELECT id, user_id, type, message, read, timestamp FROM notifications WHERE user_id = $1 ORDER BY timestamp DESC"#,
-        *user_id
+    query: web::Query<NotificationListQuery>,
+) -> Result<HttpResponse, AppError> {
+    query.validate()?;
+
+    let page = query.page.unwrap_or(1);
+    let per_page = query.per_page.unwrap_or(DEFAULT_PER_PAGE);
+    let unread_only = query.unread_only.unwrap_or(false);
+
+    let notifications = get_notifications(&pool, user.id, unread_only, page, per_page).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Notifications retrieved successfully".to_string(),
+        data: Some(notifications),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/notifications/unread-count",
+    tag = "notifications",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Number of unread notifications", body = ApiResponse<UnreadCount>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
     )
-    .fetch_all(pool.get_ref())
-    .await {
-        Ok(notifs) => {
-            let notif_responses: Vec<_> = notifs.into_iter().map(|n| {
-                serde_json::json!({
-                    "id": n.id,
-                    "user_id": n.user_id,
-                    "type": n.r#type,
-                    "message": n.message,
-                    "read": n.read,
-                    "timestamp": chrono::DateTime::<chrono::Utc>::from_utc(n.timestamp, chrono::Utc)
-                })
-            }).collect();
-            HttpResponse::Ok().json(notif_responses)
-        },
-        Err(e) => HttpResponse::InternalServerError().body(format!("Error: {}", e)),
+)]
+pub async fn get_unread_count_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, AppError> {
+    let count = get_unread_count(&pool, user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Unread count retrieved successfully".to_string(),
+        data: Some(UnreadCount { unread_count: count }),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/notifications/{id}/read",
+    tag = "notifications",
+    security(("bearer_auth" = [])),
+    params(("id" = i32, Path, description = "Notification id")),
+    responses(
+        (status = 200, description = "Notification marked as read", body = ApiResponse<NotificationResponse>),
+        (status = 404, description = "Notification not found", body = ErrorBody),
+    )
+)]
+pub async fn mark_as_read_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<i32>,
+) -> Result<HttpResponse, AppError> {
+    let notif_id = path.into_inner();
+
+    let notification = mark_as_read(&pool, notif_id, user.id)
+        .await?
+        .ok_or_else(|| not_found(notif_id))?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Notification marked as read".to_string(),
+        data: Some(notification),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/notifications/read-all",
+    tag = "notifications",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "All notifications marked as read", body = ApiResponse<MarkedAsRead>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
+pub async fn mark_all_as_read_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, AppError> {
+    let updated = mark_all_as_read(&pool, user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "All notifications marked as read".to_string(),
+        data: Some(MarkedAsRead { updated }),
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/notifications/{id}",
+    tag = "notifications",
+    security(("bearer_auth" = [])),
+    params(("id" = i32, Path, description = "Notification id")),
+    responses(
+        (status = 200, description = "Notification deleted", body = ApiResponse<Empty>),
+        (status = 404, description = "Notification not found", body = ErrorBody),
+    )
+)]
+pub async fn delete_notification_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<i32>,
+) -> Result<HttpResponse, AppError> {
+    let notif_id = path.into_inner();
+
+    if !delete_notification(&pool, notif_id, user.id).await? {
+        return Err(not_found(notif_id));
     }
+
+    Ok(HttpResponse::Ok().json(ApiResponse::<Empty> {
+        success: true,
+        message: "Notification deleted successfully".to_string(),
+        data: None,
+    }))
 }
 
-#[post("/notifications/{user_id}")]
-pub async fn add_notification(
-    user_id: web::Path<i64>,
-    notif: web::Json<Notification>,
-) -> impl Responder {
-    // TODO: Insert to DB
-    HttpResponse::Ok().json(notif.0)
+/// Tiket sekali pakai untuk membuka stream dari EventSource (tidak bisa mengirim header Authorization)
+#[utoipa::path(
+    post,
+    path = "/api/v1/notifications/stream-ticket",
+    tag = "notifications",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 201, description = "Single-use ticket for `GET /notifications/stream?ticket=`", body = ApiResponse<StreamTicket>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
+pub async fn create_stream_ticket_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, AppError> {
+    let ticket = create_stream_ticket(&pool, user.id, user.session_id).await?;
+    Ok(HttpResponse::Created().json(ApiResponse {
+        success: true,
+        message: "Stream ticket created".to_string(),
+        data: Some(ticket),
+    }))
 }
 
-#[post("/notifications/read/{notif_id}")]
-pub async fn mark_as_read(notif_id: web::Path<i64>) -> impl Responder {
-    // TODO: Update DB
-    HttpResponse::Ok().body("Marked as read")
+/// Komentar SSE berkala supaya proxy tidak memutus koneksi yang idle. Di tiap heartbeat
+/// session dicek ulang, jadi stream ikut tertutup setelah logout atau ganti password.
+const STREAM_HEARTBEAT: Duration = Duration::from_secs(15);
+
+/// Server-Sent Events: push notifikasi baru milik user yang login secara real-time
+#[utoipa::path(
+    get,
+    path = "/api/v1/notifications/stream",
+    tag = "notifications",
+    security(("bearer_auth" = [])),
+    params(("ticket" = Option<String>, Query, description = "Single-use ticket from `POST /notifications/stream-ticket`, alternative to the Authorization header for EventSource clients")),
+    responses(
+        (status = 200, description = "Server-Sent Events, one `notification` event per new notification", content_type = "text/event-stream", body = NotificationResponse),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
+pub async fn notification_stream_handler(
+    user: EventStreamUser,
+    pool: web::Data<PgPool>,
+    hub: web::Data<NotificationHub>,
+) -> Result<HttpResponse, AppError> {
+    let user_id = user.id;
+    let session_id = user.session_id;
+    let mut heartbeat = interval(STREAM_HEARTBEAT);
+    heartbeat.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    let events = stream::unfold((hub.subscribe(), heartbeat), move |(mut receiver, mut heartbeat)| {
+        let pool = pool.clone();
+        async move {
+            loop {
+                let event = tokio::select! {
+                    received = receiver.recv() => match received {
+                        Ok(notification) if notification.user_id == user_id => {
+                            let id = notification.id;
+                            let data = serde_json::to_string(&NotificationResponse::from(notification))
+                                .unwrap_or_default();
+                            format!("event: notification\nid: {}\ndata: {}\n\n", id, data)
+                        }
+                        // Notifikasi user lain, atau client terlalu lambat dan sebagian event terlewat
+                        Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => return None,
+                    },
+                    _ = heartbeat.tick() => match is_session_active(&pool, session_id).await {
+                        Ok(true) => ": ping\n\n".to_string(),
+                        // Session dicabut/expired, atau gagal dicek: tutup, client harus auth ulang
+                        Ok(false) | Err(_) => return None,
+                    },
+                };
+
+                return Some((Ok::<_, actix_web::Error>(Bytes::from(event)), (receiver, heartbeat)));
+            }
+        }
+    });
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(("Cache-Control", "no-cache"))
+        .insert_header(("X-Accel-Buffering", "no"))
+        .streaming(events))
 }
 
-pub fn config(cfg: &mut web::ServiceConfig) {
-    cfg.service(get_notifications)
-        .service(add_notification)
-        .service(mark_as_read);
+pub fn notification_routes() -> Scope {
+    web::scope("/notifications")
+        .route("", web::get().to(get_notifications_handler))
+        .route("/stream", web::get().to(notification_stream_handler))
+        .route("/stream-ticket", web::post().to(create_stream_ticket_handler))
+        .route("/unread-count", web::get().to(get_unread_count_handler))
+        .route("/read-all", web::post().to(mark_all_as_read_handler))
+        .route("/{id}/read", web::post().to(mark_as_read_handler))
+        .route("/{id}", web::delete().to(delete_notification_handler))
 }
@@ -1,84 +1,98 @@
-use actix_web::{web, HttpResponse, Result, Scope};
+use actix_web::{web, HttpResponse, Scope};
 use sqlx::PgPool;
+use utoipa::ToSchema;
 use validator::Validate;
 
+use crate::config::Config;
+use crate::mailer::Mailer;
 use crate::models::{ForgotPasswordRequest, ResetPasswordRequest};
 use crate::services::password_service::{forgot_password, reset_password, change_password};
 use crate::middleware::auth::AuthenticatedUser;
-use crate::utils::response::{ErrorResponse, ApiResponse};
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::response::{ApiResponse, Empty};
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/password/forgot",
+    tag = "password",
+    request_body = ForgotPasswordRequest,
+    responses(
+        (status = 200, description = "Reset password email sent", body = ApiResponse<Empty>),
+        (status = 400, description = "Invalid request", body = ErrorBody),
+        (status = 404, description = "No user with this email", body = ErrorBody),
+    )
+)]
 pub async fn forgot_password_handler(
     pool: web::Data<PgPool>,
+    mailer: web::Data<dyn Mailer>,
+    config: web::Data<Config>,
     form: web::Json<ForgotPasswordRequest>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     // Validate request
-    if let Err(errors) = form.validate() {
-        return Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Validation failed".to_string(),
-            message: format!("{:?}", errors),
-        }));
-    }
+    form.validate()?;
 
-    match forgot_password(&pool, &form).await {
-        Ok(reset_token) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Reset password email sent (demo: token returned)".to_string(),
-            data: Some(serde_json::json!({ "reset_token": reset_token })),
-        })),
-        Err(e) => Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Forgot password failed".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    forgot_password(&pool, mailer.get_ref(), &config, &form).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Reset password email sent".to_string(),
+        data: None::<Empty>,
+    }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/password/reset",
+    tag = "password",
+    request_body = ResetPasswordRequest,
+    responses(
+        (status = 200, description = "Password reset", body = ApiResponse<Empty>),
+        (status = 400, description = "Invalid or expired reset token", body = ErrorBody),
+    )
+)]
 pub async fn reset_password_handler(
     pool: web::Data<PgPool>,
     form: web::Json<ResetPasswordRequest>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     // Validate request
-    if let Err(errors) = form.validate() {
-        return Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Validation failed".to_string(),
-            message: format!("{:?}", errors),
-        }));
-    }
+    form.validate()?;
 
-    match reset_password(&pool, &form).await {
-        Ok(message) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message,
-            data: None::<()>,
-        })),
-        Err(e) => Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Reset password failed".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    let message = reset_password(&pool, &form).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message,
+        data: None::<Empty>,
+    }))
 }
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, ToSchema)]
 pub struct ChangePasswordRequest {
     pub old_password: String,
     pub new_password: String,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/password/change",
+    tag = "password",
+    security(("bearer_auth" = [])),
+    request_body = ChangePasswordRequest,
+    responses(
+        (status = 200, description = "Password changed", body = ApiResponse<Empty>),
+        (status = 400, description = "Current password is incorrect", body = ErrorBody),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn change_password_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
     form: web::Json<ChangePasswordRequest>,
-) -> Result<HttpResponse> {
-    match change_password(&pool, user.id, &form.old_password, &form.new_password).await {
-        Ok(message) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message,
-            data: None::<()>,
-        })),
-        Err(e) => Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Change password failed".to_string(),
-            message: e.to_string(),
-        })),
-    }
+) -> Result<HttpResponse, AppError> {
+    let message = change_password(&pool, user.id, &form.old_password, &form.new_password).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message,
+        data: None::<Empty>,
+    }))
 }
 
 pub fn password_routes() -> Scope {
@@ -0,0 +1,292 @@
+use actix_web::{web, HttpResponse, Scope};
+use serde::Deserialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+use utoipa::IntoParams;
+use validator::Validate;
+
+use crate::services::recurring_deposit_service::{
+    create_recurring_deposit, get_user_recurring_deposits, get_recurring_deposit_by_id,
+    delete_recurring_deposit, pause_recurring_deposit, resume_recurring_deposit,
+    skip_next_recurring_deposit, preview_recurring_deposit, preview_schedule, PreviewOccurrence
+};
+use crate::middleware::auth::AuthenticatedUser;
+use crate::models::{CreateRecurringDepositRequest, RecurringDeposit};
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::response::{ApiResponse, Empty};
+
+const DEFAULT_PREVIEW_COUNT: usize = 5;
+const MAX_PREVIEW_COUNT: usize = 52;
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PreviewQuery {
+    pub count: Option<usize>,
+}
+
+impl PreviewQuery {
+    fn count(&self) -> usize {
+        self.count.unwrap_or(DEFAULT_PREVIEW_COUNT).clamp(1, MAX_PREVIEW_COUNT)
+    }
+}
+
+fn not_found(rule_id: Uuid) -> AppError {
+    AppError::NotFound(format!("Recurring deposit with ID {} not found", rule_id))
+}
+
+fn rule_response(
+    result: anyhow::Result<Option<RecurringDeposit>>,
+    rule_id: Uuid,
+    message: &str,
+) -> Result<HttpResponse, AppError> {
+    let rule = result?.ok_or_else(|| not_found(rule_id))?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: message.to_string(),
+        data: Some(rule),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/recurring-deposits",
+    tag = "recurring-deposits",
+    security(("bearer_auth" = [])),
+    request_body = CreateRecurringDepositRequest,
+    responses(
+        (status = 201, description = "Recurring deposit created", body = ApiResponse<RecurringDeposit>),
+        (status = 400, description = "Invalid request or schedule without upcoming occurrences", body = ErrorBody),
+        (status = 404, description = "Savings target not found", body = ErrorBody),
+    )
+)]
+pub async fn create_recurring_deposit_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    req: web::Json<CreateRecurringDepositRequest>,
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+
+    let rule = create_recurring_deposit(&pool, user.id, &req)
+        .await?
+        .ok_or_else(|| {
+            AppError::NotFound(format!("Savings target with ID {} not found", req.savings_target_id))
+        })?;
+
+    Ok(HttpResponse::Created().json(ApiResponse {
+        success: true,
+        message: "Recurring deposit created successfully".to_string(),
+        data: Some(rule),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/recurring-deposits",
+    tag = "recurring-deposits",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Recurring deposits of the logged in user", body = ApiResponse<Vec<RecurringDeposit>>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
+pub async fn get_user_recurring_deposits_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, AppError> {
+    let rules = get_user_recurring_deposits(&pool, user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Recurring deposits retrieved successfully".to_string(),
+        data: Some(rules),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/recurring-deposits/{id}",
+    tag = "recurring-deposits",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Recurring deposit id")),
+    responses(
+        (status = 200, description = "Recurring deposit detail", body = ApiResponse<RecurringDeposit>),
+        (status = 404, description = "Recurring deposit not found", body = ErrorBody),
+    )
+)]
+pub async fn get_recurring_deposit_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let rule_id = path.into_inner();
+    let result = get_recurring_deposit_by_id(&pool, rule_id, user.id).await;
+
+    rule_response(result, rule_id, "Recurring deposit retrieved successfully")
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/recurring-deposits/{id}",
+    tag = "recurring-deposits",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Recurring deposit id")),
+    responses(
+        (status = 200, description = "Recurring deposit deleted", body = ApiResponse<Empty>),
+        (status = 404, description = "Recurring deposit not found", body = ErrorBody),
+    )
+)]
+pub async fn delete_recurring_deposit_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let rule_id = path.into_inner();
+
+    if !delete_recurring_deposit(&pool, rule_id, user.id).await? {
+        return Err(not_found(rule_id));
+    }
+
+    Ok(HttpResponse::Ok().json(ApiResponse::<Empty> {
+        success: true,
+        message: "Recurring deposit deleted successfully".to_string(),
+        data: None,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/recurring-deposits/{id}/pause",
+    tag = "recurring-deposits",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Recurring deposit id")),
+    responses(
+        (status = 200, description = "Recurring deposit paused", body = ApiResponse<RecurringDeposit>),
+        (status = 404, description = "Recurring deposit not found", body = ErrorBody),
+    )
+)]
+pub async fn pause_recurring_deposit_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let rule_id = path.into_inner();
+    let result = pause_recurring_deposit(&pool, rule_id, user.id).await;
+
+    rule_response(result, rule_id, "Recurring deposit paused")
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/recurring-deposits/{id}/resume",
+    tag = "recurring-deposits",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Recurring deposit id")),
+    responses(
+        (status = 200, description = "Recurring deposit resumed", body = ApiResponse<RecurringDeposit>),
+        (status = 404, description = "Recurring deposit not found", body = ErrorBody),
+    )
+)]
+pub async fn resume_recurring_deposit_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let rule_id = path.into_inner();
+    let result = resume_recurring_deposit(&pool, rule_id, user.id).await;
+
+    rule_response(result, rule_id, "Recurring deposit resumed")
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/recurring-deposits/{id}/skip",
+    tag = "recurring-deposits",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Recurring deposit id")),
+    responses(
+        (status = 200, description = "Next occurrence skipped", body = ApiResponse<RecurringDeposit>),
+        (status = 404, description = "Recurring deposit not found", body = ErrorBody),
+    )
+)]
+pub async fn skip_recurring_deposit_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let rule_id = path.into_inner();
+    let result = skip_next_recurring_deposit(&pool, rule_id, user.id).await;
+
+    rule_response(result, rule_id, "Next recurring deposit skipped")
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/recurring-deposits/{id}/preview",
+    tag = "recurring-deposits",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Recurring deposit id"), PreviewQuery),
+    responses(
+        (status = 200, description = "Upcoming occurrences", body = ApiResponse<Vec<PreviewOccurrence>>),
+        (status = 404, description = "Recurring deposit not found", body = ErrorBody),
+    )
+)]
+pub async fn preview_recurring_deposit_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+    query: web::Query<PreviewQuery>,
+) -> Result<HttpResponse, AppError> {
+    let rule_id = path.into_inner();
+
+    let occurrences = preview_recurring_deposit(&pool, rule_id, user.id, query.count())
+        .await?
+        .ok_or_else(|| not_found(rule_id))?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Recurring deposit preview generated".to_string(),
+        data: Some(occurrences),
+    }))
+}
+
+/// Dry-run untuk rule yang belum disimpan
+#[utoipa::path(
+    post,
+    path = "/api/v1/recurring-deposits/preview",
+    tag = "recurring-deposits",
+    security(("bearer_auth" = [])),
+    params(PreviewQuery),
+    request_body = CreateRecurringDepositRequest,
+    responses(
+        (status = 200, description = "Occurrences of an unsaved rule (dry run)", body = ApiResponse<Vec<PreviewOccurrence>>),
+        (status = 400, description = "Invalid request", body = ErrorBody),
+    )
+)]
+pub async fn preview_schedule_handler(
+    _user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    query: web::Query<PreviewQuery>,
+    req: web::Json<CreateRecurringDepositRequest>,
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+
+    let occurrences = preview_schedule(&pool, &req, query.count()).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Recurring deposit preview generated".to_string(),
+        data: Some(occurrences),
+    }))
+}
+
+pub fn recurring_deposit_routes() -> Scope {
+    web::scope("/recurring-deposits")
+        .route("", web::post().to(create_recurring_deposit_handler))
+        .route("", web::get().to(get_user_recurring_deposits_handler))
+        .route("/preview", web::post().to(preview_schedule_handler))
+        .route("/{id}", web::get().to(get_recurring_deposit_handler))
+        .route("/{id}", web::delete().to(delete_recurring_deposit_handler))
+        .route("/{id}/pause", web::post().to(pause_recurring_deposit_handler))
+        .route("/{id}/resume", web::post().to(resume_recurring_deposit_handler))
+        .route("/{id}/skip", web::post().to(skip_recurring_deposit_handler))
+        .route("/{id}/preview", web::get().to(preview_recurring_deposit_handler))
+}
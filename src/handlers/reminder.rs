@@ -1,11 +1,32 @@
-use actix_web::{web, HttpResponse, Result};
-use serde_json::json;
+use actix_web::{web, HttpResponse};
 use uuid::Uuid;
 use chrono::Datelike;
 use sqlx::PgPool;
 
 use crate::services::reminder_service::ReminderService;
 use crate::middleware::auth::AuthenticatedUser;
+use crate::errors::{AppError, ErrorBody};
+use crate::models::ReminderResponse;
+use crate::utils::pagination::{ListQuery, Page, Sort, SortField};
+use crate::utils::response::{ApiResponse, Empty};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UpcomingQuery {
+    /// Look-ahead window in days, default 30
+    days: Option<i32>,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CalendarQuery {
+    /// 1-12, default current month
+    month: Option<i32>,
+    /// Default current year
+    year: Option<i32>,
+}
 
 pub fn reminder_routes() -> actix_web::Scope {
     web::scope("/reminders")
@@ -16,129 +37,139 @@ pub fn reminder_routes() -> actix_web::Scope {
         .route("/{id}/complete", web::put().to(mark_reminder_completed))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/reminders",
+    tag = "reminders",
+    security(("bearer_auth" = [])),
+    params(ListQuery),
+    responses(
+        (status = 200, description = "Reminders of the logged in user", body = ApiResponse<Page<ReminderResponse>>),
+        (status = 400, description = "Invalid pagination parameters", body = ErrorBody),
+    )
+)]
 pub async fn get_user_reminders(
     pool: web::Data<PgPool>,
     user: AuthenticatedUser,
-    query: web::Query<serde_json::Value>,
-) -> Result<HttpResponse> {
+    query: web::Query<ListQuery>,
+) -> Result<HttpResponse, AppError> {
     let reminder_service = ReminderService::new(pool.get_ref().clone());
-    let limit = query.get("limit").and_then(|v| v.as_i64()).map(|l| l as i32);
+    let page = query.parse(
+        &[SortField::ReminderDate, SortField::CreatedAt],
+        Sort::asc(SortField::ReminderDate),
+    )?;
 
-    match reminder_service.get_user_reminders(user.id, limit).await {
-        Ok(reminders) => Ok(HttpResponse::Ok().json(json!({
-            "success": true,
-            "data": reminders
-        }))),
-        Err(err) => {
-            eprintln!("Error getting user reminders: {}", err);
-            Ok(HttpResponse::InternalServerError().json(json!({
-                "success": false,
-                "message": "Failed to get reminders"
-            })))
-        }
-    }
+    let reminders = reminder_service.get_user_reminders(user.id, &page).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Reminders retrieved successfully".to_string(),
+        data: Some(reminders),
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/reminders/upcoming",
+    tag = "reminders",
+    security(("bearer_auth" = [])),
+    params(UpcomingQuery),
+    responses(
+        (status = 200, description = "Upcoming reminders", body = ApiResponse<Vec<ReminderResponse>>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn get_upcoming_reminders(
     pool: web::Data<PgPool>,
     user: AuthenticatedUser,
-    query: web::Query<serde_json::Value>,
-) -> Result<HttpResponse> {
+    query: web::Query<UpcomingQuery>,
+) -> Result<HttpResponse, AppError> {
     let reminder_service = ReminderService::new(pool.get_ref().clone());
-    let days = query.get("days").and_then(|v| v.as_i64()).map(|d| d as i32);
-
-    match reminder_service.get_upcoming_reminders(user.id, days).await {
-        Ok(reminders) => Ok(HttpResponse::Ok().json(json!({
-            "success": true,
-            "data": reminders
-        }))),
-        Err(err) => {
-            eprintln!("Error getting upcoming reminders: {}", err);
-            Ok(HttpResponse::InternalServerError().json(json!({
-                "success": false,
-                "message": "Failed to get upcoming reminders"
-            })))
-        }
-    }
+    let reminders = reminder_service.get_upcoming_reminders(user.id, query.days).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Upcoming reminders retrieved successfully".to_string(),
+        data: Some(reminders),
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/reminders/today",
+    tag = "reminders",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Reminders due today", body = ApiResponse<Vec<ReminderResponse>>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn get_todays_reminders(
     pool: web::Data<PgPool>,
     user: AuthenticatedUser,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     let reminder_service = ReminderService::new(pool.get_ref().clone());
     
-    match reminder_service.get_todays_reminders(user.id).await {
-        Ok(reminders) => Ok(HttpResponse::Ok().json(json!({
-            "success": true,
-            "data": reminders
-        }))),
-        Err(err) => {
-            eprintln!("Error getting today's reminders: {}", err);
-            Ok(HttpResponse::InternalServerError().json(json!({
-                "success": false,
-                "message": "Failed to get today's reminders"
-            })))
-        }
-    }
+    let reminders = reminder_service.get_todays_reminders(user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Today's reminders retrieved successfully".to_string(),
+        data: Some(reminders),
+    }))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/v1/reminders/{id}/complete",
+    tag = "reminders",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Reminder id")),
+    responses(
+        (status = 200, description = "Reminder marked as completed", body = ApiResponse<Empty>),
+        (status = 404, description = "Reminder not found", body = ErrorBody),
+    )
+)]
 pub async fn mark_reminder_completed(
     pool: web::Data<PgPool>,
     user: AuthenticatedUser,
     path: web::Path<Uuid>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     let reminder_service = ReminderService::new(pool.get_ref().clone());
     let reminder_id = path.into_inner();
 
-    match reminder_service.mark_reminder_completed(reminder_id, user.id).await {
-        Ok(true) => Ok(HttpResponse::Ok().json(json!({
-            "success": true,
-            "message": "Reminder marked as completed"
-        }))),
-        Ok(false) => Ok(HttpResponse::NotFound().json(json!({
-            "success": false,
-            "message": "Reminder not found"
-        }))),
-        Err(err) => {
-            eprintln!("Error marking reminder as completed: {}", err);
-            Ok(HttpResponse::InternalServerError().json(json!({
-                "success": false,
-                "message": "Failed to mark reminder as completed"
-            })))
-        }
+    if !reminder_service.mark_reminder_completed(reminder_id, user.id).await? {
+        return Err(AppError::NotFound("Reminder not found".to_string()));
     }
+
+    Ok(HttpResponse::Ok().json(ApiResponse::<Empty> {
+        success: true,
+        message: "Reminder marked as completed".to_string(),
+        data: None,
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/reminders/calendar",
+    tag = "reminders",
+    security(("bearer_auth" = [])),
+    params(CalendarQuery),
+    responses(
+        (status = 200, description = "Reminders in the given month", body = ApiResponse<Vec<ReminderResponse>>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn get_calendar_events(
     pool: web::Data<PgPool>,
     user: AuthenticatedUser,
-    query: web::Query<serde_json::Value>,
-) -> Result<HttpResponse> {
+    query: web::Query<CalendarQuery>,
+) -> Result<HttpResponse, AppError> {
     let reminder_service = ReminderService::new(pool.get_ref().clone());
-    let month = query
-        .get("month")
-        .and_then(|v| v.as_i64())
-        .map(|m| m as i32)
-        .unwrap_or_else(|| chrono::Utc::now().month() as i32);
-    
-    let year = query
-        .get("year")
-        .and_then(|v| v.as_i64())
-        .map(|y| y as i32)
-        .unwrap_or_else(|| chrono::Utc::now().year());
+    let month = query.month.unwrap_or_else(|| chrono::Utc::now().month() as i32);
+    let year = query.year.unwrap_or_else(|| chrono::Utc::now().year());
 
-    match reminder_service.get_calendar_events(user.id, month, year).await {
-        Ok(events) => Ok(HttpResponse::Ok().json(json!({
-            "success": true,
-            "data": events
-        }))),
-        Err(err) => {
-            eprintln!("Error getting calendar events: {}", err);
-            Ok(HttpResponse::InternalServerError().json(json!({
-                "success": false,
-                "message": "Failed to get calendar events"
-            })))
-        }
-    }
+    let events = reminder_service.get_calendar_events(user.id, month, year).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Calendar events retrieved successfully".to_string(),
+        data: Some(events),
+    }))
 }
@@ -0,0 +1,37 @@
+use actix_web::{web, HttpResponse, Scope};
+use sqlx::PgPool;
+
+use crate::services::cashflow_service::get_cashflow_report;
+use crate::middleware::auth::AuthenticatedUser;
+use crate::models::{CashflowReport, CashflowReportQuery};
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::response::ApiResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/reports/cashflow",
+    tag = "cashflow",
+    security(("bearer_auth" = [])),
+    params(CashflowReportQuery),
+    responses(
+        (status = 200, description = "Income vs expense totals and savings rate, per month or per category", body = ApiResponse<CashflowReport>),
+        (status = 400, description = "Invalid date range or grouping", body = ErrorBody),
+    )
+)]
+pub async fn get_cashflow_report_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    query: web::Query<CashflowReportQuery>,
+) -> Result<HttpResponse, AppError> {
+    let report = get_cashflow_report(&pool, user.id, &query).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Cash flow report generated successfully".to_string(),
+        data: Some(report),
+    }))
+}
+
+pub fn report_routes() -> Scope {
+    web::scope("/reports")
+        .route("/cashflow", web::get().to(get_cashflow_report_handler))
+}
@@ -1,164 +1,802 @@
-use actix_web::{web, HttpResponse, Result, Scope};
+use actix_web::{http::StatusCode, web, HttpResponse, Scope};
 use sqlx::PgPool;
 use uuid::Uuid;
+use utoipa::ToSchema;
 use validator::Validate;
 
 use crate::services::savings_service::{
     create_savings_target, get_user_savings_targets, get_savings_target_by_id,
-    update_savings_target, delete_savings_target, add_deposit_to_target
+    update_savings_target, delete_savings_target, add_deposit_to_target,
+    withdraw_from_target, transfer_between_targets, DepositResult, TransferResult, WithdrawResult
 };
-use crate::services::activity_service::{
-    create_deposit_activity, create_target_created_activity
+use crate::services::ledger_service::{get_target_transactions, reverse_transaction, reconcile};
+use crate::services::forecast_service::get_target_forecast;
+use crate::services::sharing_service::{
+    create_invitation, get_members, get_my_invitations, get_target_activities, get_target_invitations,
+    remove_member, respond_to_invitation, revoke_invitation, update_member_role, INVITATION_TTL_DAYS
 };
-use crate::services::statistics_service::update_user_statistics_after_deposit;
+use crate::services::user_service::get_user_profile;
+use crate::config::Config;
+use crate::errors::{AppError, ErrorBody};
+use crate::mailer::{self, templates, Mailer};
 use crate::middleware::auth::AuthenticatedUser;
-use crate::models::{CreateSavingsTargetRequest, UpdateSavingsTargetRequest};
-use crate::utils::response::{ErrorResponse, ApiResponse};
+use crate::middleware::idempotency::{begin_idempotent, respond, IdempotencyKey, IdempotentStart};
+use crate::models::{
+    CreateSavingsTargetRequest, UpdateSavingsTargetRequest, WithdrawRequest, TransferRequest,
+    LedgerEntry, ReconciliationReport, SavingsTarget, TargetMember, UpdateMemberRequest,
+    CreateInvitationRequest, TargetInvitation, TargetActivity, ForecastQuery, TargetForecast
+};
+use crate::utils::pagination::{ListQuery, Page, Sort, SortField};
+use crate::utils::money::Money;
+use crate::utils::response::{ApiResponse, Empty};
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/savings/targets",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(("Idempotency-Key" = Option<String>, Header, description = "Optional, replays the first response for 24 hours")),
+    request_body = CreateSavingsTargetRequest,
+    responses(
+        (status = 201, description = "Savings target created", body = ApiResponse<SavingsTarget>),
+        (status = 400, description = "Invalid request", body = ErrorBody),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+        (status = 409, description = "Request with this Idempotency-Key still in progress", body = ErrorBody),
+        (status = 422, description = "Idempotency-Key reused for a different request", body = ErrorBody),
+    )
+)]
 pub async fn create_savings_target_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
+    idempotency: IdempotencyKey,
     req: web::Json<CreateSavingsTargetRequest>,
-) -> Result<HttpResponse> {
-    if let Err(errors) = req.validate() {
-        return Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Validation failed".to_string(),
-            message: format!("{:?}", errors),
-        }));
-    }
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+
+    let request = req.into_inner();
+
+    let mut tx = match begin_idempotent(&pool, user.id, &idempotency, &request).await? {
+        IdempotentStart::Replay(response) => return Ok(response),
+        IdempotentStart::Proceed(tx) => tx,
+    };
 
-    match create_savings_target(&pool, user.id, req.into_inner()).await {
-        Ok(target) => {
-            // Activity creation is already handled in the service layer
-            
-            Ok(HttpResponse::Created().json(ApiResponse {
+    // Activity creation is already handled in the service layer
+    let response = create_savings_target(tx.conn(), user.id, request)
+        .await
+        .map_err(AppError::from)
+        .and_then(|target| {
+            respond(StatusCode::CREATED, ApiResponse {
                 success: true,
                 message: "Savings target created successfully".to_string(),
                 data: Some(target),
-            }))
-        }
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to create savings target".to_string(),
-            message: e.to_string(),
-        })),
-    }
+            })
+        });
+
+    tx.finish(response).await
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/savings/targets",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(ListQuery),
+    responses(
+        (status = 200, description = "Own and shared savings targets of the logged in user", body = ApiResponse<Page<SavingsTarget>>),
+        (status = 400, description = "Invalid pagination parameters", body = ErrorBody),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn get_user_savings_targets_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
-) -> Result<HttpResponse> {
-    match get_user_savings_targets(&pool, user.id).await {
-        Ok(targets) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Savings targets retrieved successfully".to_string(),
-            data: Some(targets),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to retrieve savings targets".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    query: web::Query<ListQuery>,
+) -> Result<HttpResponse, AppError> {
+    let page = query.parse(
+        &[SortField::CreatedAt, SortField::UpdatedAt],
+        Sort::desc(SortField::CreatedAt),
+    )?;
+
+    let targets = get_user_savings_targets(&pool, user.id, &page).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Savings targets retrieved successfully".to_string(),
+        data: Some(targets),
+    }))
+}
+
+fn target_not_found(target_id: Uuid) -> AppError {
+    AppError::NotFound(format!("Savings target with ID {} not found", target_id))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/savings/targets/{id}",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Savings target id")),
+    responses(
+        (status = 200, description = "Savings target detail", body = ApiResponse<SavingsTarget>),
+        (status = 404, description = "Savings target not found", body = ErrorBody),
+    )
+)]
 pub async fn get_savings_target_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
     path: web::Path<Uuid>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     let target_id = path.into_inner();
 
-    match get_savings_target_by_id(&pool, user.id, target_id).await {
-        Ok(target) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Savings target retrieved successfully".to_string(),
-            data: Some(target),
-        })),
-        Err(e) => Ok(HttpResponse::NotFound().json(ErrorResponse {
-            error: "Savings target not found".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    let target = get_savings_target_by_id(&pool, target_id, user.id)
+        .await?
+        .ok_or_else(|| target_not_found(target_id))?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Savings target retrieved successfully".to_string(),
+        data: Some(target),
+    }))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/v1/savings/targets/{id}",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Savings target id")),
+    request_body = UpdateSavingsTargetRequest,
+    responses(
+        (status = 200, description = "Savings target updated, balance changes are recorded as adjustment", body = ApiResponse<SavingsTarget>),
+        (status = 400, description = "Invalid amount", body = ErrorBody),
+        (status = 403, description = "Only owners can update the target", body = ErrorBody),
+        (status = 404, description = "Savings target not found", body = ErrorBody),
+    )
+)]
 pub async fn update_savings_target_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
     path: web::Path<Uuid>,
     req: web::Json<UpdateSavingsTargetRequest>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     let target_id = path.into_inner();
+    req.validate()?;
 
-    match update_savings_target(&pool, user.id, target_id, req.into_inner()).await {
-        Ok(target) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Savings target updated successfully".to_string(),
-            data: Some(target),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to update savings target".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    let target = update_savings_target(&pool, target_id, user.id, req.into_inner())
+        .await?
+        .ok_or_else(|| target_not_found(target_id))?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Savings target updated successfully".to_string(),
+        data: Some(target),
+    }))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/v1/savings/targets/{id}",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Savings target id")),
+    responses(
+        (status = 200, description = "Savings target deleted", body = ApiResponse<Empty>),
+        (status = 403, description = "Only owners can delete the target", body = ErrorBody),
+        (status = 404, description = "Savings target not found", body = ErrorBody),
+    )
+)]
 pub async fn delete_savings_target_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
     path: web::Path<Uuid>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     let target_id = path.into_inner();
 
-    match delete_savings_target(&pool, user.id, target_id).await {
-        Ok(_) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Savings target deleted successfully".to_string(),
-            data: Some(()),
-        })),
-        Err(e) => Ok(HttpResponse::NotFound().json(ErrorResponse {
-            error: "Failed to delete savings target".to_string(),
-            message: e.to_string(),
-        })),
+    if !delete_savings_target(&pool, target_id, user.id).await? {
+        return Err(target_not_found(target_id));
     }
+
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Savings target deleted successfully".to_string(),
+        data: None::<Empty>,
+    }))
 }
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct DepositRequest {
-    pub amount: f64,
+    pub amount: Money,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/savings/targets/{id}/deposit",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Savings target id"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Optional, replays the first response for 24 hours"),
+    ),
+    request_body = DepositRequest,
+    responses(
+        (status = 200, description = "Deposit recorded", body = ApiResponse<DepositResult>),
+        (status = 400, description = "Amount not greater than 0 or finer than the currency's minor units", body = ErrorBody),
+        (status = 403, description = "Member of the target with viewer role", body = ErrorBody),
+        (status = 404, description = "Savings target does not exist or user is not a member", body = ErrorBody),
+        (status = 409, description = "Request with this Idempotency-Key still in progress", body = ErrorBody),
+        (status = 422, description = "Idempotency-Key reused for a different request", body = ErrorBody),
+    )
+)]
 pub async fn add_deposit_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
+    mailer: web::Data<dyn Mailer>,
+    config: web::Data<Config>,
+    idempotency: IdempotencyKey,
     path: web::Path<Uuid>,
     req: web::Json<DepositRequest>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     let target_id = path.into_inner();
-    let amount = req.amount;
+    let amount = req.amount.clone();
 
-    if amount <= 0.0 {
-        return Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Invalid amount".to_string(),
-            message: "Deposit amount must be greater than 0".to_string(),
-        }));
+    if !amount.is_positive() {
+        return Err(AppError::BadRequest("Deposit amount must be greater than 0".to_string()));
     }
 
-    match add_deposit_to_target(&pool, user.id, target_id, amount).await {
-        Ok(target) => {
-            // Activity and statistics are already handled in the service layer
-            // No need to duplicate them here
-            
-            Ok(HttpResponse::Ok().json(ApiResponse {
+    let mut tx = match begin_idempotent(&pool, user.id, &idempotency, &req.into_inner()).await? {
+        IdempotentStart::Replay(response) => return Ok(response),
+        IdempotentStart::Proceed(tx) => tx,
+    };
+
+    let result = add_deposit_to_target(tx.conn(), user.id, target_id, amount)
+        .await
+        .map_err(AppError::from);
+    let completed_target = result
+        .as_ref()
+        .ok()
+        .filter(|result| result.completed_activity.is_some())
+        .map(|result| result.target.clone());
+
+    let response = tx
+        .finish(result.and_then(|result| {
+            respond(StatusCode::OK, ApiResponse {
                 success: true,
                 message: "Deposit added successfully".to_string(),
-                data: Some(target),
-            }))
+                data: Some(result),
+            })
+        }))
+        .await?;
+
+    // Email selamat dikirim setelah commit, hanya kalau deposit ini yang membuat target tercapai
+    if let Some(target) = completed_target {
+        if let Ok(profile) = get_user_profile(&pool, user.id).await {
+            let message = templates::target_completed(
+                config.mail.default_locale,
+                &profile.email,
+                &profile.full_name,
+                &target.name,
+                &target.target_amount,
+                &target.currency,
+            );
+            mailer::send_in_background(mailer.into_inner(), message);
         }
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to add deposit".to_string(),
-            message: e.to_string(),
-        })),
     }
+
+    Ok(response)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/savings/targets/{id}/withdraw",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Savings target id"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Optional, replays the first response for 24 hours"),
+    ),
+    request_body = WithdrawRequest,
+    responses(
+        (status = 200, description = "Withdrawal recorded", body = ApiResponse<WithdrawResult>),
+        (status = 400, description = "Invalid request", body = ErrorBody),
+        (status = 403, description = "Member of the target but not an owner", body = ErrorBody),
+        (status = 404, description = "Savings target does not exist or user is not a member", body = ErrorBody),
+        (status = 409, description = "Request with this Idempotency-Key still in progress", body = ErrorBody),
+        (status = 422, description = "Insufficient funds, or Idempotency-Key reused for a different request", body = ErrorBody),
+    )
+)]
+pub async fn withdraw_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    idempotency: IdempotencyKey,
+    path: web::Path<Uuid>,
+    req: web::Json<WithdrawRequest>,
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+
+    let target_id = path.into_inner();
+    let request = req.into_inner();
+
+    let mut tx = match begin_idempotent(&pool, user.id, &idempotency, &request).await? {
+        IdempotentStart::Replay(response) => return Ok(response),
+        IdempotentStart::Proceed(tx) => tx,
+    };
+
+    // Saldo tidak cukup otomatis jadi 422 lewat AppError
+    let response = withdraw_from_target(tx.conn(), target_id, user.id, &request)
+        .await
+        .map_err(AppError::from)
+        .and_then(|result| result.ok_or_else(|| target_not_found(target_id)))
+        .and_then(|result| {
+            respond(StatusCode::OK, ApiResponse {
+                success: true,
+                message: "Withdrawal successful".to_string(),
+                data: Some(result),
+            })
+        });
+
+    tx.finish(response).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/savings/transfers",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(("Idempotency-Key" = Option<String>, Header, description = "Optional, replays the first response for 24 hours")),
+    request_body = TransferRequest,
+    responses(
+        (status = 200, description = "Transfer recorded", body = ApiResponse<TransferResult>),
+        (status = 400, description = "Invalid request or same source and destination", body = ErrorBody),
+        (status = 403, description = "Member role too low: owner needed on the source, contributor on the destination", body = ErrorBody),
+        (status = 404, description = "Source or destination target does not exist or user is not a member", body = ErrorBody),
+        (status = 409, description = "Request with this Idempotency-Key still in progress", body = ErrorBody),
+        (status = 422, description = "Insufficient funds, or Idempotency-Key reused for a different request", body = ErrorBody),
+    )
+)]
+pub async fn transfer_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    idempotency: IdempotencyKey,
+    req: web::Json<TransferRequest>,
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+
+    if req.from_target_id == req.to_target_id {
+        return Err(AppError::BadRequest(
+            "Source and destination targets must be different".to_string(),
+        ));
+    }
+
+    let request = req.into_inner();
+
+    let mut tx = match begin_idempotent(&pool, user.id, &idempotency, &request).await? {
+        IdempotentStart::Replay(response) => return Ok(response),
+        IdempotentStart::Proceed(tx) => tx,
+    };
+
+    let response = transfer_between_targets(tx.conn(), user.id, &request)
+        .await
+        .map_err(AppError::from)
+        .and_then(|result| {
+            result.ok_or_else(|| {
+                AppError::NotFound("Source or destination savings target not found".to_string())
+            })
+        })
+        .and_then(|result| {
+            respond(StatusCode::OK, ApiResponse {
+                success: true,
+                message: "Transfer successful".to_string(),
+                data: Some(result),
+            })
+        });
+
+    tx.finish(response).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/savings/targets/{id}/transactions",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Savings target id")),
+    responses(
+        (status = 200, description = "Ledger entries of the target, newest first", body = ApiResponse<Vec<LedgerEntry>>),
+        (status = 404, description = "Savings target not found", body = ErrorBody),
+    )
+)]
+pub async fn get_target_transactions_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let target_id = path.into_inner();
+
+    let transactions = get_target_transactions(&pool, target_id, user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Transactions retrieved successfully".to_string(),
+        data: Some(transactions),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/savings/transactions/{id}/reverse",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Transaction id")),
+    responses(
+        (status = 201, description = "Reversal entries created", body = ApiResponse<Vec<LedgerEntry>>),
+        (status = 403, description = "Role on the target too low: reversing money in requires owner, money out requires contributor", body = ErrorBody),
+        (status = 404, description = "Transaction not found", body = ErrorBody),
+        (status = 409, description = "Transaction already reversed", body = ErrorBody),
+        (status = 422, description = "Reversal cannot be reversed, or balance would become negative", body = ErrorBody),
+    )
+)]
+pub async fn reverse_transaction_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let transaction_id = path.into_inner();
+
+    let reversals = reverse_transaction(&pool, transaction_id, user.id).await?;
+    Ok(HttpResponse::Created().json(ApiResponse {
+        success: true,
+        message: "Transaction reversed successfully".to_string(),
+        data: Some(reversals),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/savings/reconciliation",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Ledger reconciliation of the logged in user", body = ApiResponse<ReconciliationReport>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
+pub async fn reconciliation_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, AppError> {
+    let report = reconcile(&pool, Some(user.id)).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Reconciliation completed".to_string(),
+        data: Some(report),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/savings/targets/{id}/members",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Savings target id")),
+    responses(
+        (status = 200, description = "Members of the target with their contribution", body = ApiResponse<Vec<TargetMember>>),
+        (status = 404, description = "Savings target not found", body = ErrorBody),
+    )
+)]
+pub async fn get_target_members_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let target_id = path.into_inner();
+
+    let members = get_members(&pool, target_id, user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Members retrieved successfully".to_string(),
+        data: Some(members),
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/savings/targets/{id}/members/{user_id}",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Savings target id"),
+        ("user_id" = Uuid, Path, description = "Member user id"),
+    ),
+    request_body = UpdateMemberRequest,
+    responses(
+        (status = 200, description = "Member role changed", body = ApiResponse<TargetMember>),
+        (status = 403, description = "Only owners can change roles", body = ErrorBody),
+        (status = 404, description = "Savings target or member not found", body = ErrorBody),
+        (status = 422, description = "The last owner cannot be demoted", body = ErrorBody),
+    )
+)]
+pub async fn update_target_member_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<(Uuid, Uuid)>,
+    req: web::Json<UpdateMemberRequest>,
+) -> Result<HttpResponse, AppError> {
+    let (target_id, member_id) = path.into_inner();
+
+    let member = update_member_role(&pool, target_id, user.id, member_id, req.role).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Member role updated successfully".to_string(),
+        data: Some(member),
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/savings/targets/{id}/members/{user_id}",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Savings target id"),
+        ("user_id" = Uuid, Path, description = "Member user id, use your own id to leave the target"),
+    ),
+    responses(
+        (status = 200, description = "Member removed, their recurring deposits to the target are paused", body = ApiResponse<Empty>),
+        (status = 403, description = "Only owners can remove other members", body = ErrorBody),
+        (status = 404, description = "Savings target or member not found", body = ErrorBody),
+        (status = 422, description = "The last owner cannot leave", body = ErrorBody),
+    )
+)]
+pub async fn remove_target_member_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> Result<HttpResponse, AppError> {
+    let (target_id, member_id) = path.into_inner();
+
+    remove_member(&pool, target_id, user.id, member_id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse::<Empty> {
+        success: true,
+        message: "Member removed successfully".to_string(),
+        data: None,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/savings/targets/{id}/invitations",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Savings target id")),
+    request_body = CreateInvitationRequest,
+    responses(
+        (status = 201, description = "Invitation created and emailed", body = ApiResponse<TargetInvitation>),
+        (status = 400, description = "Invalid email", body = ErrorBody),
+        (status = 403, description = "Only owners can invite", body = ErrorBody),
+        (status = 404, description = "Savings target not found", body = ErrorBody),
+        (status = 409, description = "Already a member or already invited", body = ErrorBody),
+    )
+)]
+pub async fn create_target_invitation_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    mailer: web::Data<dyn Mailer>,
+    config: web::Data<Config>,
+    path: web::Path<Uuid>,
+    req: web::Json<CreateInvitationRequest>,
+) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+    let target_id = path.into_inner();
+
+    let invitation = create_invitation(&pool, target_id, user.id, &req).await?;
+
+    let invitations_link = format!("{}/invitations", config.frontend_url.trim_end_matches('/'));
+    let message = templates::target_invitation(
+        config.mail.default_locale,
+        &invitation.email,
+        &invitation.invited_by_name,
+        &invitation.target_name,
+        &invitation.role,
+        &invitations_link,
+        INVITATION_TTL_DAYS,
+    );
+    mailer::send_in_background(mailer.into_inner(), message);
+
+    Ok(HttpResponse::Created().json(ApiResponse {
+        success: true,
+        message: "Invitation sent successfully".to_string(),
+        data: Some(invitation),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/savings/targets/{id}/invitations",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Savings target id")),
+    responses(
+        (status = 200, description = "All invitations of the target, newest first", body = ApiResponse<Vec<TargetInvitation>>),
+        (status = 403, description = "Only owners can see invitations", body = ErrorBody),
+        (status = 404, description = "Savings target not found", body = ErrorBody),
+    )
+)]
+pub async fn get_target_invitations_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let target_id = path.into_inner();
+
+    let invitations = get_target_invitations(&pool, target_id, user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Invitations retrieved successfully".to_string(),
+        data: Some(invitations),
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/savings/targets/{id}/invitations/{invitation_id}",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Savings target id"),
+        ("invitation_id" = Uuid, Path, description = "Invitation id"),
+    ),
+    responses(
+        (status = 200, description = "Pending invitation revoked", body = ApiResponse<Empty>),
+        (status = 403, description = "Only owners can revoke invitations", body = ErrorBody),
+        (status = 404, description = "Savings target or pending invitation not found", body = ErrorBody),
+    )
+)]
+pub async fn revoke_target_invitation_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> Result<HttpResponse, AppError> {
+    let (target_id, invitation_id) = path.into_inner();
+
+    if !revoke_invitation(&pool, target_id, invitation_id, user.id).await? {
+        return Err(AppError::NotFound("Pending invitation not found".to_string()));
+    }
+
+    Ok(HttpResponse::Ok().json(ApiResponse::<Empty> {
+        success: true,
+        message: "Invitation revoked successfully".to_string(),
+        data: None,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/savings/invitations",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Pending invitations for the email of the logged in user", body = ApiResponse<Vec<TargetInvitation>>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
+pub async fn get_my_invitations_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, AppError> {
+    let invitations = get_my_invitations(&pool, user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Invitations retrieved successfully".to_string(),
+        data: Some(invitations),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/savings/invitations/{id}/accept",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Invitation id")),
+    responses(
+        (status = 200, description = "Invitation accepted, the user is now a member", body = ApiResponse<TargetInvitation>),
+        (status = 404, description = "Invitation not found for this user", body = ErrorBody),
+        (status = 409, description = "Invitation already answered, or already a member", body = ErrorBody),
+        (status = 422, description = "Invitation expired", body = ErrorBody),
+    )
+)]
+pub async fn accept_invitation_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let invitation = respond_to_invitation(&pool, path.into_inner(), user.id, true).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Invitation accepted successfully".to_string(),
+        data: Some(invitation),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/savings/invitations/{id}/decline",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Invitation id")),
+    responses(
+        (status = 200, description = "Invitation declined", body = ApiResponse<TargetInvitation>),
+        (status = 404, description = "Invitation not found for this user", body = ErrorBody),
+        (status = 409, description = "Invitation already answered", body = ErrorBody),
+        (status = 422, description = "Invitation expired", body = ErrorBody),
+    )
+)]
+pub async fn decline_invitation_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let invitation = respond_to_invitation(&pool, path.into_inner(), user.id, false).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Invitation declined successfully".to_string(),
+        data: Some(invitation),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/savings/targets/{id}/activities",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Savings target id"), ListQuery),
+    responses(
+        (status = 200, description = "Activities of all members on the target", body = ApiResponse<Page<TargetActivity>>),
+        (status = 400, description = "Invalid pagination parameters", body = ErrorBody),
+        (status = 404, description = "Savings target not found", body = ErrorBody),
+    )
+)]
+pub async fn get_target_activities_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+    query: web::Query<ListQuery>,
+) -> Result<HttpResponse, AppError> {
+    let page = query.parse(&[SortField::CreatedAt], Sort::desc(SortField::CreatedAt))?;
+
+    let activities = get_target_activities(&pool, path.into_inner(), user.id, &page).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Target activities retrieved successfully".to_string(),
+        data: Some(activities),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/savings/targets/{id}/forecast",
+    tag = "savings",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Savings target id"), ForecastQuery),
+    responses(
+        (status = 200, description = "Projected completion date, required contribution and status of the target", body = ApiResponse<TargetForecast>),
+        (status = 400, description = "Invalid what-if amount or frequency", body = ErrorBody),
+        (status = 404, description = "Savings target not found", body = ErrorBody),
+    )
+)]
+pub async fn get_target_forecast_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+    query: web::Query<ForecastQuery>,
+) -> Result<HttpResponse, AppError> {
+    let target_id = path.into_inner();
+
+    let forecast = get_target_forecast(&pool, target_id, user.id, &query)
+        .await?
+        .ok_or_else(|| target_not_found(target_id))?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Forecast generated successfully".to_string(),
+        data: Some(forecast),
+    }))
 }
 
 pub fn savings_routes() -> Scope {
@@ -169,4 +807,20 @@ pub fn savings_routes() -> Scope {
         .route("/targets/{id}", web::put().to(update_savings_target_handler))
         .route("/targets/{id}", web::delete().to(delete_savings_target_handler))
         .route("/targets/{id}/deposit", web::post().to(add_deposit_handler))
+        .route("/targets/{id}/withdraw", web::post().to(withdraw_handler))
+        .route("/targets/{id}/transactions", web::get().to(get_target_transactions_handler))
+        .route("/targets/{id}/activities", web::get().to(get_target_activities_handler))
+        .route("/targets/{id}/forecast", web::get().to(get_target_forecast_handler))
+        .route("/targets/{id}/members", web::get().to(get_target_members_handler))
+        .route("/targets/{id}/members/{user_id}", web::put().to(update_target_member_handler))
+        .route("/targets/{id}/members/{user_id}", web::delete().to(remove_target_member_handler))
+        .route("/targets/{id}/invitations", web::post().to(create_target_invitation_handler))
+        .route("/targets/{id}/invitations", web::get().to(get_target_invitations_handler))
+        .route("/targets/{id}/invitations/{invitation_id}", web::delete().to(revoke_target_invitation_handler))
+        .route("/invitations", web::get().to(get_my_invitations_handler))
+        .route("/invitations/{id}/accept", web::post().to(accept_invitation_handler))
+        .route("/invitations/{id}/decline", web::post().to(decline_invitation_handler))
+        .route("/transfers", web::post().to(transfer_handler))
+        .route("/transactions/{id}/reverse", web::post().to(reverse_transaction_handler))
+        .route("/reconciliation", web::get().to(reconciliation_handler))
 }
@@ -1,22 +1,58 @@
-use actix_web::{get, web, HttpResponse, Responder};
-use serde::{Deserialize, Serialize};
+use actix_web::{web, HttpResponse, Scope};
+use sqlx::PgPool;
+use validator::Validate;
 
-#[derive(Serialize, Deserialize)]
-pub struct SearchQuery {
-    pub q: String,
-}
+use crate::services::search_service::{search, DEFAULT_PER_PAGE};
+use crate::middleware::auth::AuthenticatedUser;
+use crate::models::{SearchQuery, SearchResponse, SearchResultType};
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::response::ApiResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/search",
+    tag = "search",
+    security(("bearer_auth" = [])),
+    params(SearchQuery),
+    responses(
+        (status = 200, description = "Results ranked by full-text and trigram score", body = ApiResponse<SearchResponse>),
+        (status = 400, description = "Invalid query or unknown type", body = ErrorBody),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
+pub async fn search_handler(
+    user: AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    query: web::Query<SearchQuery>,
+) -> Result<HttpResponse, AppError> {
+    query.validate()?;
+
+    let types = match &query.types {
+        None => None,
+        Some(types) => {
+            let mut parsed = Vec::new();
+            for name in types.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+                let result_type = SearchResultType::from_name(name).ok_or_else(|| {
+                    AppError::BadRequest(format!("Unknown search type '{}'", name))
+                })?;
+                parsed.push(result_type);
+            }
+            Some(parsed)
+        }
+    };
+
+    let page = query.page.unwrap_or(1);
+    let per_page = query.per_page.unwrap_or(DEFAULT_PER_PAGE);
 
-#[get("/search")]
-pub async fn search(query: web::Query<SearchQuery>) -> impl Responder {
-    let q = query.q.to_lowercase();
-    // TODO: Query ke DB (user, transaksi, dsb) pakai LIKE/ILIKE
-    // Dummy response
-    let results = vec![
-        format!("Hasil pencarian untuk: {}", q)
-    ];
-    HttpResponse::Ok().json(results)
+    let results = search(&pool, user.id, user.role.is_admin(), &query.q, types.as_deref(), page, per_page).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Search completed successfully".to_string(),
+        data: Some(results),
+    }))
 }
 
-pub fn config(cfg: &mut web::ServiceConfig) {
-    cfg.service(search);
+pub fn search_routes() -> Scope {
+    web::scope("/search")
+        .route("", web::get().to(search_handler))
 }
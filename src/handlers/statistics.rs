@@ -1,69 +1,89 @@
-use actix_web::{web, HttpResponse, Result, Scope};
+use actix_web::{web, HttpResponse, Scope};
 use sqlx::PgPool;
-use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use serde::Deserialize;
 
 use crate::services::statistics_service::{
-    get_user_statistics, get_user_achievements, get_user_streak_data
+    get_user_statistics, get_user_achievements, get_user_streak_data,
+    AchievementResponse, StreakDataResponse, UserStatisticsResponse
 };
 use crate::middleware::auth::AuthenticatedUser;
-use crate::utils::response::{ErrorResponse, ApiResponse};
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::response::ApiResponse;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct StreakQuery {
     days: Option<i32>,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/statistics",
+    tag = "statistics",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Savings statistics of the logged in user", body = ApiResponse<UserStatisticsResponse>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn get_user_statistics_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
-) -> Result<HttpResponse> {
-    match get_user_statistics(&pool, user.id).await {
-        Ok(stats) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "User statistics retrieved successfully".to_string(),
-            data: Some(stats),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to retrieve user statistics".to_string(),
-            message: e.to_string(),
-        })),
-    }
+) -> Result<HttpResponse, AppError> {
+    let stats = get_user_statistics(&pool, user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "User statistics retrieved successfully".to_string(),
+        data: Some(stats),
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/statistics/achievements",
+    tag = "statistics",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Achievements earned", body = ApiResponse<Vec<AchievementResponse>>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn get_user_achievements_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
-) -> Result<HttpResponse> {
-    match get_user_achievements(&pool, user.id).await {
-        Ok(achievements) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "User achievements retrieved successfully".to_string(),
-            data: Some(achievements),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to retrieve user achievements".to_string(),
-            message: e.to_string(),
-        })),
-    }
+) -> Result<HttpResponse, AppError> {
+    let achievements = get_user_achievements(&pool, user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "User achievements retrieved successfully".to_string(),
+        data: Some(achievements),
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/statistics/streak",
+    tag = "statistics",
+    security(("bearer_auth" = [])),
+    params(StreakQuery),
+    responses(
+        (status = 200, description = "Deposit streak per day", body = ApiResponse<StreakDataResponse>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn get_user_streak_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
     query: web::Query<StreakQuery>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     let days = query.days.unwrap_or(16);
-    match get_user_streak_data(&pool, user.id, days).await {
-        Ok(streak_data) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "User streak data retrieved successfully".to_string(),
-            data: Some(streak_data),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to retrieve user streak data".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    let streak_data = get_user_streak_data(&pool, user.id, days).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "User streak data retrieved successfully".to_string(),
+        data: Some(streak_data),
+    }))
 }
 
 pub fn statistics_routes() -> Scope {
@@ -1,150 +1,191 @@
-use crate::models::TestimoniWithUser;
 use crate::services::testimoni_service::get_testimoni_by_id;
+#[utoipa::path(
+    get,
+    path = "/api/v1/testimoni/{id}",
+    tag = "testimoni",
+    params(("id" = Uuid, Path, description = "Testimoni id")),
+    responses(
+        (status = 200, description = "Testimoni detail", body = ApiResponse<TestimoniWithUser>),
+        (status = 404, description = "Testimoni not found", body = ErrorBody),
+    )
+)]
 pub async fn get_testimoni_by_id_handler(
     pool: web::Data<PgPool>,
     path: web::Path<Uuid>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     let testimoni_id = path.into_inner();
-    match get_testimoni_by_id(&pool, testimoni_id).await {
-        Ok(testimoni) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Testimoni detail retrieved successfully".to_string(),
-            data: Some(testimoni),
-        })),
-        Err(e) => Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Failed to get testimoni detail".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    let testimoni = get_testimoni_by_id(&pool, testimoni_id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Testimoni detail retrieved successfully".to_string(),
+        data: Some(testimoni),
+    }))
 }
-use actix_web::{web, HttpResponse, Result, Scope};
+use actix_web::{web, HttpResponse, Scope};
 use sqlx::PgPool;
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::models::{CreateTestimoniRequest, UpdateTestimoniRequest};
+use crate::models::{CreateTestimoniRequest, UpdateTestimoniRequest, Testimoni, TestimoniWithUser};
 use crate::services::testimoni_service::{
     create_testimoni, get_all_testimoni, get_user_testimoni, 
     update_testimoni, delete_testimoni, get_approved_testimoni
 };
-use crate::middleware::auth::AuthenticatedUser;
-use crate::utils::response::{ErrorResponse, ApiResponse};
+use crate::middleware::auth::{AuthenticatedUser, ModeratorUser};
+use crate::utils::pagination::{ListQuery, Page, Sort, SortField};
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::response::{ApiResponse, Empty};
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/testimoni",
+    tag = "testimoni",
+    security(("bearer_auth" = [])),
+    request_body = CreateTestimoniRequest,
+    responses(
+        (status = 201, description = "Testimoni created, waiting for approval", body = ApiResponse<Testimoni>),
+        (status = 400, description = "Invalid request", body = ErrorBody),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn create_testimoni_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
     form: web::Json<CreateTestimoniRequest>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     // Validate request
-    if let Err(errors) = form.validate() {
-        return Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Validation failed".to_string(),
-            message: format!("{:?}", errors),
-        }));
-    }
+    form.validate()?;
 
-    match create_testimoni(&pool, user.id, &form).await {
-        Ok(testimoni) => Ok(HttpResponse::Created().json(ApiResponse {
-            success: true,
-            message: "Testimoni created successfully".to_string(),
-            data: Some(testimoni),
-        })),
-        Err(e) => Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Failed to create testimoni".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    let testimoni = create_testimoni(&pool, user.id, &form).await?;
+    Ok(HttpResponse::Created().json(ApiResponse {
+        success: true,
+        message: "Testimoni created successfully".to_string(),
+        data: Some(testimoni),
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/testimoni/all",
+    tag = "testimoni",
+    security(("bearer_auth" = [])),
+    params(ListQuery),
+    responses(
+        (status = 200, description = "All testimoni (admin/moderator)", body = ApiResponse<Page<TestimoniWithUser>>),
+        (status = 400, description = "Invalid pagination parameters", body = ErrorBody),
+        (status = 403, description = "Moderator privileges required", body = ErrorBody),
+    )
+)]
 pub async fn get_all_testimoni_handler(
-    _user: AuthenticatedUser, // Admin access
+    _user: ModeratorUser,
     pool: web::Data<PgPool>,
-) -> Result<HttpResponse> {
-    match get_all_testimoni(&pool).await {
-        Ok(testimoni) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Testimoni retrieved successfully".to_string(),
-            data: Some(testimoni),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to retrieve testimoni".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    query: web::Query<ListQuery>,
+) -> Result<HttpResponse, AppError> {
+    let page = query.parse(&[SortField::CreatedAt], Sort::desc(SortField::CreatedAt))?;
+
+    let testimoni = get_all_testimoni(&pool, &page).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Testimoni retrieved successfully".to_string(),
+        data: Some(testimoni),
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/testimoni/approved",
+    tag = "testimoni",
+    responses(
+        (status = 200, description = "Approved testimoni (public)", body = ApiResponse<Vec<TestimoniWithUser>>),
+    )
+)]
 pub async fn get_approved_testimoni_handler(
     pool: web::Data<PgPool>,
-) -> Result<HttpResponse> {
-    match get_approved_testimoni(&pool).await {
-        Ok(testimoni) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Approved testimoni retrieved successfully".to_string(),
-            data: Some(testimoni),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to retrieve testimoni".to_string(),
-            message: e.to_string(),
-        })),
-    }
+) -> Result<HttpResponse, AppError> {
+    let testimoni = get_approved_testimoni(&pool).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Approved testimoni retrieved successfully".to_string(),
+        data: Some(testimoni),
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/testimoni/my",
+    tag = "testimoni",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Testimoni of the logged in user", body = ApiResponse<Vec<Testimoni>>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn get_user_testimoni_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
-) -> Result<HttpResponse> {
-    match get_user_testimoni(&pool, user.id).await {
-        Ok(testimoni) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "User testimoni retrieved successfully".to_string(),
-            data: Some(testimoni),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to retrieve testimoni".to_string(),
-            message: e.to_string(),
-        })),
-    }
+) -> Result<HttpResponse, AppError> {
+    let testimoni = get_user_testimoni(&pool, user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "User testimoni retrieved successfully".to_string(),
+        data: Some(testimoni),
+    }))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/v1/testimoni/{id}",
+    tag = "testimoni",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Testimoni id")),
+    request_body = UpdateTestimoniRequest,
+    responses(
+        (status = 200, description = "Testimoni updated", body = ApiResponse<Testimoni>),
+        (status = 403, description = "Not the owner, or approval without moderator role", body = ErrorBody),
+        (status = 404, description = "Testimoni not found", body = ErrorBody),
+    )
+)]
 pub async fn update_testimoni_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
     path: web::Path<Uuid>,
     form: web::Json<UpdateTestimoniRequest>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     let testimoni_id = path.into_inner();
 
-    match update_testimoni(&pool, testimoni_id, user.id, user.is_admin, &form).await {
-        Ok(testimoni) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Testimoni updated successfully".to_string(),
-            data: Some(testimoni),
-        })),
-        Err(e) => Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Failed to update testimoni".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    let testimoni = update_testimoni(&pool, testimoni_id, user.id, user.role, &form).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Testimoni updated successfully".to_string(),
+        data: Some(testimoni),
+    }))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/v1/testimoni/{id}",
+    tag = "testimoni",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "Testimoni id")),
+    responses(
+        (status = 200, description = "Testimoni deleted", body = ApiResponse<Empty>),
+        (status = 403, description = "Not the owner", body = ErrorBody),
+        (status = 404, description = "Testimoni not found", body = ErrorBody),
+    )
+)]
 pub async fn delete_testimoni_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
     path: web::Path<Uuid>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     let testimoni_id = path.into_inner();
 
-    match delete_testimoni(&pool, testimoni_id, user.id, user.is_admin).await {
-        Ok(_) => Ok(HttpResponse::Ok().json(ApiResponse::<()> {
-            success: true,
-            message: "Testimoni deleted successfully".to_string(),
-            data: None,
-        })),
-        Err(e) => Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Failed to delete testimoni".to_string(),
-            message: e.to_string(),
-        })),
-    }
+    delete_testimoni(&pool, testimoni_id, user.id, user.role).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse::<Empty> {
+        success: true,
+        message: "Testimoni deleted successfully".to_string(),
+        data: None,
+    }))
 }
 
 pub fn testimoni_routes() -> Scope {
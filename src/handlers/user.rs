@@ -1,60 +1,126 @@
-use actix_web::{web, HttpResponse, Result, Scope};
+use actix_web::{web, HttpResponse, Scope};
 use sqlx::PgPool;
-use crate::models::UpdateUserRequest;
-use crate::services::user_service::{get_user_profile, update_user_profile, get_all_users};
-use crate::middleware::auth::AuthenticatedUser;
-use crate::utils::response::{ErrorResponse, ApiResponse};
+use uuid::Uuid;
+use validator::Validate;
+use crate::models::{UpdateUserRequest, UpdateUserRoleRequest, UserResponse};
+use crate::services::user_service::{get_user_profile, update_user_profile, get_all_users, update_user_role};
+use crate::middleware::auth::{AuthenticatedUser, AdminUser};
+use crate::utils::pagination::{ListQuery, Page, Sort, SortField};
+use crate::errors::{AppError, ErrorBody};
+use crate::utils::response::ApiResponse;
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/profile",
+    tag = "users",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Profile of the logged in user", body = ApiResponse<UserResponse>),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn get_profile_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
-) -> Result<HttpResponse> {
-    match get_user_profile(&pool, user.id).await {
-        Ok(user_profile) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Profile retrieved successfully".to_string(),
-            data: Some(user_profile),
-        })),
-        Err(e) => Ok(HttpResponse::NotFound().json(ErrorResponse {
-            error: "User not found".to_string(),
-            message: e.to_string(),
-        })),
-    }
+) -> Result<HttpResponse, AppError> {
+    let user_profile = get_user_profile(&pool, user.id).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Profile retrieved successfully".to_string(),
+        data: Some(user_profile),
+    }))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/v1/users/profile",
+    tag = "users",
+    security(("bearer_auth" = [])),
+    request_body = UpdateUserRequest,
+    responses(
+        (status = 200, description = "Profile updated", body = ApiResponse<UserResponse>),
+        (status = 400, description = "Unsupported currency", body = ErrorBody),
+        (status = 401, description = "Not authenticated", body = ErrorBody),
+    )
+)]
 pub async fn update_profile_handler(
     user: AuthenticatedUser,
     pool: web::Data<PgPool>,
     form: web::Json<UpdateUserRequest>,
-) -> Result<HttpResponse> {
-    match update_user_profile(&pool, user.id, &form).await {
-        Ok(updated_user) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Profile updated successfully".to_string(),
-            data: Some(updated_user),
-        })),
-        Err(e) => Ok(HttpResponse::BadRequest().json(ErrorResponse {
-            error: "Update failed".to_string(),
-            message: e.to_string(),
-        })),
-    }
+) -> Result<HttpResponse, AppError> {
+    form.validate()?;
+
+    let updated_user = update_user_profile(&pool, user.id, &form).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Profile updated successfully".to_string(),
+        data: Some(updated_user),
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/users",
+    tag = "users",
+    security(("bearer_auth" = [])),
+    params(ListQuery),
+    responses(
+        (status = 200, description = "All users (admin only)", body = ApiResponse<Page<UserResponse>>),
+        (status = 400, description = "Invalid pagination parameters", body = ErrorBody),
+        (status = 403, description = "Admin privileges required", body = ErrorBody),
+    )
+)]
 pub async fn get_users_handler(
-    _user: AuthenticatedUser, // Admin middleware will be added later
+    _user: AdminUser,
     pool: web::Data<PgPool>,
-) -> Result<HttpResponse> {
-    match get_all_users(&pool).await {
-        Ok(users) => Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            message: "Users retrieved successfully".to_string(),
-            data: Some(users),
-        })),
-        Err(e) => Ok(HttpResponse::InternalServerError().json(ErrorResponse {
-            error: "Failed to retrieve users".to_string(),
-            message: e.to_string(),
-        })),
+    query: web::Query<ListQuery>,
+) -> Result<HttpResponse, AppError> {
+    let page = query.parse(
+        &[SortField::CreatedAt, SortField::UpdatedAt],
+        Sort::desc(SortField::CreatedAt),
+    )?;
+
+    let users = get_all_users(&pool, &page).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "Users retrieved successfully".to_string(),
+        data: Some(users),
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/users/{id}/role",
+    tag = "users",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "User id")),
+    request_body = UpdateUserRoleRequest,
+    responses(
+        (status = 200, description = "Role updated", body = ApiResponse<UserResponse>),
+        (status = 400, description = "Admin cannot change their own role", body = ErrorBody),
+        (status = 403, description = "Admin privileges required", body = ErrorBody),
+        (status = 404, description = "User not found", body = ErrorBody),
+    )
+)]
+pub async fn update_user_role_handler(
+    admin: AdminUser,
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+    form: web::Json<UpdateUserRoleRequest>,
+) -> Result<HttpResponse, AppError> {
+    let user_id = path.into_inner();
+
+    // Cegah admin menurunkan role dirinya sendiri (bisa terkunci dari panel admin)
+    if user_id == admin.id {
+        return Err(AppError::BadRequest("You cannot change your own role".to_string()));
     }
+
+    let updated_user = update_user_role(&pool, user_id, form.role).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        message: "User role updated successfully".to_string(),
+        data: Some(updated_user),
+    }))
 }
 
 pub fn user_routes() -> Scope {
@@ -62,4 +128,5 @@ pub fn user_routes() -> Scope {
         .route("/profile", web::get().to(get_profile_handler))
         .route("/profile", web::put().to(update_profile_handler))
         .route("", web::get().to(get_users_handler))
+        .route("/{id}/role", web::put().to(update_user_role_handler))
 }
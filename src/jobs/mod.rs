@@ -0,0 +1,27 @@
+mod recurring_deposits;
+mod reminders;
+
+use std::sync::Arc;
+use sqlx::PgPool;
+
+use crate::config::Config;
+use crate::mailer::Mailer;
+
+/// Jalankan semua background job. Setiap job aman dijalankan di beberapa instance sekaligus.
+pub fn spawn_all(pool: PgPool, mailer: Arc<dyn Mailer>, config: &Config) {
+    let jobs = &config.jobs;
+    if !jobs.enabled {
+        println!("⏸️  Background jobs dimatikan (JOBS_ENABLED=false)");
+        return;
+    }
+
+    recurring_deposits::spawn(pool.clone(), jobs.recurring_deposit_interval_secs);
+
+    let reminder_mailer = jobs.reminder_email_enabled.then_some(mailer);
+    reminders::spawn(
+        pool,
+        reminder_mailer,
+        config.mail.default_locale,
+        jobs.reminder_dispatch_interval_secs,
+    );
+}
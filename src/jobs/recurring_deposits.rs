@@ -0,0 +1,22 @@
+use std::time::Duration;
+use sqlx::PgPool;
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::services::recurring_deposit_service::process_due_recurring_deposits;
+
+pub fn spawn(pool: PgPool, interval_secs: u64) {
+    tokio::spawn(async move {
+        let mut ticker = interval(Duration::from_secs(interval_secs.max(1)));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match process_due_recurring_deposits(&pool).await {
+                Ok(0) => {}
+                Ok(count) => println!("🔁 {} setoran otomatis diproses", count),
+                Err(e) => eprintln!("❌ Worker setoran otomatis gagal: {}", e),
+            }
+        }
+    });
+}
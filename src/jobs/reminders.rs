@@ -0,0 +1,57 @@
+use std::sync::Arc;
+use std::time::Duration;
+use sqlx::PgPool;
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::mailer::{templates::{self, Locale}, Mailer};
+use crate::services::reminder_service::ReminderService;
+
+/// Jumlah reminder maksimal per putaran, sisanya diambil di putaran berikutnya
+const DISPATCH_BATCH_SIZE: i64 = 100;
+
+/// mailer None = hanya notifikasi in-app
+pub fn spawn(pool: PgPool, mailer: Option<Arc<dyn Mailer>>, locale: Locale, interval_secs: u64) {
+    tokio::spawn(async move {
+        let reminder_service = ReminderService::new(pool);
+        let mut ticker = interval(Duration::from_secs(interval_secs.max(1)));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            let due = match reminder_service.dispatch_due_reminders(DISPATCH_BATCH_SIZE).await {
+                Ok(due) => due,
+                Err(e) => {
+                    eprintln!("❌ Worker reminder gagal: {}", e);
+                    continue;
+                }
+            };
+
+            if due.is_empty() {
+                continue;
+            }
+            println!("🔔 {} reminder dikirim", due.len());
+
+            // Email dikirim setelah commit supaya tidak ada email untuk reminder yang batal diproses
+            if let Some(mailer) = &mailer {
+                for reminder in due {
+                    let message = templates::target_deadline_reminder(
+                        locale,
+                        &reminder.email,
+                        &reminder.full_name,
+                        &reminder.target_name,
+                        reminder.target_date.unwrap_or(reminder.reminder_date),
+                        templates::TargetProgress {
+                            current_amount: &reminder.current_amount.unwrap_or_default(),
+                            target_amount: &reminder.target_amount,
+                            currency: &reminder.currency,
+                        },
+                    );
+                    if let Err(e) = mailer.send(message).await {
+                        eprintln!("❌ Gagal mengirim email reminder ke {}: {}", reminder.email, e);
+                    }
+                }
+            }
+        }
+    });
+}
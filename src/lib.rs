@@ -0,0 +1,12 @@
+pub mod app;
+pub mod config;
+pub mod db;
+pub mod errors;
+pub mod handlers;
+pub mod jobs;
+pub mod mailer;
+pub mod models;
+pub mod openapi;
+pub mod services;
+pub mod middleware;
+pub mod utils;
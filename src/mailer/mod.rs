@@ -0,0 +1,65 @@
+mod smtp;
+mod spool;
+pub mod templates;
+
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use lettre::message::{header::ContentType, Mailbox, Message};
+
+use crate::config::{MailBackend, MailConfig};
+
+pub use smtp::SmtpMailer;
+pub use spool::SpoolMailer;
+
+#[derive(Debug, Clone)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Backend pengiriman email (SMTP atau file spool untuk lokal dev)
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, message: EmailMessage) -> Result<()>;
+}
+
+pub fn build_mailer(config: &MailConfig) -> Result<Arc<dyn Mailer>> {
+    let mailer: Arc<dyn Mailer> = match config.backend {
+        MailBackend::Smtp => Arc::new(SmtpMailer::new(config)?),
+        MailBackend::File => Arc::new(SpoolMailer::new(config)?),
+    };
+
+    Ok(mailer)
+}
+
+/// Kirim email tanpa menahan response, error cukup di-log
+pub fn send_in_background(mailer: Arc<dyn Mailer>, message: EmailMessage) {
+    tokio::spawn(async move {
+        let to = message.to.clone();
+        if let Err(e) = mailer.send(message).await {
+            eprintln!("❌ Gagal mengirim email ke {}: {}", to, e);
+        }
+    });
+}
+
+fn build_message(from: &Mailbox, message: EmailMessage) -> Result<Message> {
+    let to: Mailbox = message.to
+        .parse()
+        .map_err(|e| anyhow!("Invalid recipient address '{}': {}", message.to, e))?;
+
+    Message::builder()
+        .from(from.clone())
+        .to(to)
+        .subject(message.subject)
+        .header(ContentType::TEXT_PLAIN)
+        .body(message.body)
+        .map_err(|e| anyhow!("Failed to build email: {}", e))
+}
+
+fn parse_from(config: &MailConfig) -> Result<Mailbox> {
+    config.from
+        .parse()
+        .map_err(|e| anyhow!("Invalid MAIL_FROM '{}': {}", config.from, e))
+}
@@ -0,0 +1,51 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
+
+use super::{build_message, parse_from, EmailMessage, Mailer};
+use crate::config::{MailConfig, SmtpTls};
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(config: &MailConfig) -> Result<Self> {
+        let builder = match config.smtp_tls {
+            // Tanpa TLS, cocok untuk SMTP server lokal (MailHog, Mailpit, dsb)
+            SmtpTls::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.smtp_host),
+            SmtpTls::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.smtp_host)
+                .map_err(|e| anyhow!("Invalid SMTP relay: {}", e))?,
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.smtp_host)
+                .map_err(|e| anyhow!("Invalid SMTP relay: {}", e))?,
+        };
+
+        let mut builder = builder.port(config.smtp_port);
+
+        if let (Some(username), Some(password)) = (&config.smtp_username, &config.smtp_password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from: parse_from(config)?,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, message: EmailMessage) -> Result<()> {
+        let email = build_message(&self.from, message)?;
+
+        self.transport
+            .send(email)
+            .await
+            .map_err(|e| anyhow!("SMTP send failed: {}", e))?;
+
+        Ok(())
+    }
+}
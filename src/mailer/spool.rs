@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use uuid::Uuid;
+
+use super::{build_message, parse_from, EmailMessage, Mailer};
+use crate::config::MailConfig;
+
+/// Menulis setiap email sebagai file .eml di folder spool (untuk lokal dev/testing)
+pub struct SpoolMailer {
+    dir: PathBuf,
+    from: Mailbox,
+}
+
+impl SpoolMailer {
+    pub fn new(config: &MailConfig) -> Result<Self> {
+        let dir = PathBuf::from(&config.spool_dir);
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| anyhow!("Failed to create mail spool dir {}: {}", dir.display(), e))?;
+
+        Ok(Self {
+            dir,
+            from: parse_from(config)?,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SpoolMailer {
+    async fn send(&self, message: EmailMessage) -> Result<()> {
+        let email = build_message(&self.from, message)?;
+
+        let file_name = format!(
+            "{}-{}.eml",
+            chrono::Utc::now().format("%Y%m%d%H%M%S"),
+            Uuid::new_v4()
+        );
+        let path = self.dir.join(file_name);
+
+        tokio::fs::write(&path, email.formatted())
+            .await
+            .map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+
+        println!("📧 Email ditulis ke {}", path.display());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+use chrono::NaiveDate;
+
+use super::EmailMessage;
+use crate::utils::currency::format_money;
+use crate::utils::money::Money;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Locale {
+    Id,
+    En,
+}
+
+impl Locale {
+    pub fn from_code(code: &str) -> Self {
+        match code.trim().to_lowercase().as_str() {
+            "en" | "en-us" | "en-gb" => Locale::En,
+            _ => Locale::Id,
+        }
+    }
+}
+
+pub fn password_reset(
+    locale: Locale,
+    to: &str,
+    full_name: &str,
+    reset_link: &str,
+    ttl_minutes: i64,
+) -> EmailMessage {
+    let (subject, body) = match locale {
+        Locale::Id => (
+            "Reset password akun Tabungin".to_string(),
+            format!(
+                "Halo {},\n\n\
+                 Kami menerima permintaan untuk mereset password akun Tabungin kamu.\n\
+                 Klik link berikut untuk membuat password baru:\n\n\
+                 {}\n\n\
+                 Link ini hanya bisa dipakai sekali dan berlaku selama {} menit.\n\
+                 Kalau kamu tidak merasa meminta reset password, abaikan email ini.\n\n\
+                 Salam,\nTim Tabungin",
+                full_name, reset_link, ttl_minutes
+            ),
+        ),
+        Locale::En => (
+            "Reset your Tabungin password".to_string(),
+            format!(
+                "Hi {},\n\n\
+                 We received a request to reset the password for your Tabungin account.\n\
+                 Open the link below to choose a new password:\n\n\
+                 {}\n\n\
+                 This link can only be used once and expires in {} minutes.\n\
+                 If you did not request a password reset, you can ignore this email.\n\n\
+                 Regards,\nThe Tabungin Team",
+                full_name, reset_link, ttl_minutes
+            ),
+        ),
+    };
+
+    EmailMessage { to: to.to_string(), subject, body }
+}
+
+/// Saldo dan nominal target, keduanya dalam mata uang target
+pub struct TargetProgress<'a> {
+    pub current_amount: &'a Money,
+    pub target_amount: &'a Money,
+    pub currency: &'a str,
+}
+
+pub fn target_deadline_reminder(
+    locale: Locale,
+    to: &str,
+    full_name: &str,
+    target_name: &str,
+    target_date: NaiveDate,
+    progress: TargetProgress,
+) -> EmailMessage {
+    let current = format_money(progress.current_amount.as_decimal(), progress.currency);
+    let target = format_money(progress.target_amount.as_decimal(), progress.currency);
+
+    let (subject, body) = match locale {
+        Locale::Id => (
+            format!("Pengingat target \"{}\"", target_name),
+            format!(
+                "Halo {},\n\n\
+                 Target tabungan \"{}\" jatuh tempo pada {}.\n\
+                 Progres kamu saat ini {} dari {}.\n\n\
+                 Yuk sisihkan sedikit hari ini supaya targetmu tercapai tepat waktu!\n\n\
+                 Salam,\nTim Tabungin",
+                full_name, target_name, target_date.format("%d-%m-%Y"), current, target
+            ),
+        ),
+        Locale::En => (
+            format!("Reminder for your \"{}\" goal", target_name),
+            format!(
+                "Hi {},\n\n\
+                 Your savings goal \"{}\" is due on {}.\n\
+                 You have saved {} of {} so far.\n\n\
+                 Put a little aside today to reach your goal on time!\n\n\
+                 Regards,\nThe Tabungin Team",
+                full_name, target_name, target_date.format("%Y-%m-%d"), current, target
+            ),
+        ),
+    };
+
+    EmailMessage { to: to.to_string(), subject, body }
+}
+
+pub fn target_completed(
+    locale: Locale,
+    to: &str,
+    full_name: &str,
+    target_name: &str,
+    target_amount: &Money,
+    currency: &str,
+) -> EmailMessage {
+    let target = format_money(target_amount.as_decimal(), currency);
+
+    let (subject, body) = match locale {
+        Locale::Id => (
+            format!("Selamat! Target \"{}\" tercapai 🎉", target_name),
+            format!(
+                "Halo {},\n\n\
+                 Selamat! Kamu berhasil mengumpulkan {} untuk target \"{}\".\n\
+                 Terus pertahankan kebiasaan menabungmu.\n\n\
+                 Salam,\nTim Tabungin",
+                full_name, target, target_name
+            ),
+        ),
+        Locale::En => (
+            format!("Congratulations! You reached \"{}\" 🎉", target_name),
+            format!(
+                "Hi {},\n\n\
+                 Congratulations! You have saved {} for your \"{}\" goal.\n\
+                 Keep up the great saving habit.\n\n\
+                 Regards,\nThe Tabungin Team",
+                full_name, target, target_name
+            ),
+        ),
+    };
+
+    EmailMessage { to: to.to_string(), subject, body }
+}
+
+pub fn target_invitation(
+    locale: Locale,
+    to: &str,
+    inviter_name: &str,
+    target_name: &str,
+    role: &str,
+    invitations_link: &str,
+    ttl_days: i64,
+) -> EmailMessage {
+    let (subject, body) = match locale {
+        Locale::Id => (
+            format!("{} mengundang kamu ke target \"{}\"", inviter_name, target_name),
+            format!(
+                "Halo,\n\n\
+                 {} mengundang kamu menabung bersama untuk target \"{}\" sebagai {}.\n\
+                 Masuk atau daftar dengan email ini, lalu buka link berikut untuk menerima undangan:\n\n\
+                 {}\n\n\
+                 Undangan berlaku selama {} hari.\n\n\
+                 Salam,\nTim Tabungin",
+                inviter_name, target_name, role, invitations_link, ttl_days
+            ),
+        ),
+        Locale::En => (
+            format!("{} invited you to \"{}\"", inviter_name, target_name),
+            format!(
+                "Hi,\n\n\
+                 {} invited you to save together for the \"{}\" goal as {}.\n\
+                 Sign in or register with this email, then open the link below to accept:\n\n\
+                 {}\n\n\
+                 This invitation expires in {} days.\n\n\
+                 Regards,\nThe Tabungin Team",
+                inviter_name, target_name, role, invitations_link, ttl_days
+            ),
+        ),
+    };
+
+    EmailMessage { to: to.to_string(), subject, body }
+}
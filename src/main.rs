@@ -1,16 +1,10 @@
-mod config;
-mod handlers;
-mod models;
-mod services;
-mod middleware;
-mod utils;
-
 use actix_cors::Cors;
-use actix_web::{web, App, HttpServer, middleware::Logger};
-use config::Config;
+use actix_web::{App, HttpServer, middleware::Logger};
+use tabungin_api::app::AppState;
+use tabungin_api::config::Config;
+use tabungin_api::{db, jobs, mailer, services};
 use sqlx::PgPool;
 use std::env;
-use actix_web::http::header::{AUTHORIZATION, CONTENT_TYPE, ACCEPT};
 
 use bcrypt::verify; // tambah ini untuk verify password
 
@@ -41,14 +35,31 @@ async fn main() -> std::io::Result<()> {
         .expect("❌ Gagal connect ke database, cek DATABASE_URL di Railway Variables");
 
     // Jalankan migrasi SQLx
-    if let Err(e) = sqlx::migrate!("./migrations").run(&pool).await {
+    if let Err(e) = db::run_migrations(&pool).await {
         eprintln!("❌ Gagal menjalankan migrasi: {}", e);
         std::process::exit(1);
     }
 
+    // Setup mailer (SMTP atau file spool, tergantung MAIL_BACKEND)
+    let mailer = mailer::build_mailer(&config.mail)
+        .expect("❌ Gagal setup mailer, cek konfigurasi MAIL_BACKEND/SMTP_*");
+
+    // Listener LISTEN/NOTIFY untuk stream notifikasi (SSE)
+    let notification_hub = services::notification_hub::NotificationHub::start(pool.clone());
+
+    // Worker setoran otomatis, pengingat target, dsb
+    jobs::spawn_all(pool.clone(), mailer.clone(), &config);
+
     // TESTING password verify (manual check)
     test_password_verify();
 
+    let state = AppState {
+        pool,
+        config,
+        mailer,
+        notification_hub,
+    };
+
     println!("🚀 Server starting on {}:{}", host, port);
 
     HttpServer::new(move || {
@@ -59,25 +70,9 @@ async fn main() -> std::io::Result<()> {
             .supports_credentials();
 
         App::new()
-            .app_data(web::Data::new(pool.clone()))
-            .app_data(web::Data::new(config.clone()))
+            .configure(|cfg| state.configure(cfg))
             .wrap(cors)
             .wrap(Logger::default())
-            .service(
-                web::scope("/api/v1")
-                    .service(handlers::auth::auth_routes())
-                    .service(handlers::user::user_routes())
-                    .service(handlers::testimoni::testimoni_routes())
-                    .service(handlers::dashboard::dashboard_routes())
-                    .service(handlers::password::password_routes())
-                    .service(handlers::savings::savings_routes())
-                    .service(handlers::activity::activity_routes())
-                    .service(handlers::statistics::statistics_routes())
-                    .service(handlers::reminder::reminder_routes())
-                    .configure(handlers::notification::config)
-                    .configure(handlers::search::config)
-            )
-            .service(handlers::health::health_check)
     })
     .bind(format!("{}:{}", host, port))?
     .run()
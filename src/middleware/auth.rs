@@ -1,46 +1,185 @@
-use actix_web::{error::ErrorUnauthorized, Error, FromRequest, HttpRequest};
+use actix_web::{web, Error, FromRequest, HttpRequest};
 use actix_web::dev::Payload;
-use futures::future::{Ready, ready};
+use futures::future::LocalBoxFuture;
+use serde::Deserialize;
+use sqlx::PgPool;
+use std::ops::Deref;
 use uuid::Uuid;
 
+use crate::errors::AppError;
+use crate::models::Role;
+use crate::services::session_service::{redeem_stream_ticket, validate_session};
 use crate::utils::jwt::validate_jwt_token;
 
 #[derive(Debug, Clone)]
 pub struct AuthenticatedUser {
     pub id: Uuid,
     pub email: String,
-    pub is_admin: bool,
+    pub role: Role,
+    pub session_id: Uuid,
+}
+
+fn bearer_token(req: &HttpRequest) -> Option<String> {
+    req.headers()
+        .get("Authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(|token| token.to_string())
+}
+
+async fn authenticate(
+    token: Option<String>,
+    pool: Option<web::Data<PgPool>>,
+) -> Result<AuthenticatedUser, Error> {
+    let token = token
+        .ok_or_else(|| AppError::Unauthorized("Missing or invalid authorization header".to_string()))?;
+    let claims = validate_jwt_token(&token)
+        .map_err(|_| AppError::Unauthorized("Invalid token".to_string()))?;
+
+    // Cek session di database: sudah logout/revoke atau password sudah diganti
+    let pool = pool.ok_or_else(|| AppError::Internal(anyhow::anyhow!("Database pool not configured")))?;
+    let role = validate_session(&pool, &claims)
+        .await
+        .map_err(|e| match AppError::from(e) {
+            AppError::Internal(e) => AppError::Internal(e),
+            e => AppError::Unauthorized(e.to_string()),
+        })?;
+
+    Ok(AuthenticatedUser {
+        id: claims.user_id,
+        email: claims.email,
+        role,
+        session_id: claims.sid,
+    })
 }
 
 impl FromRequest for AuthenticatedUser {
     type Error = Error;
-    type Future = Ready<Result<Self, Self::Error>>;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
 
     fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
-        let auth_header = req.headers().get("Authorization");
-        
-        if let Some(auth_value) = auth_header {
-            if let Ok(auth_str) = auth_value.to_str() {
-                if auth_str.starts_with("Bearer ") {
-                    let token = &auth_str[7..];
-                    
-                    match validate_jwt_token(token) {
-                        Ok(claims) => {
-                            let user = AuthenticatedUser {
-                                id: claims.user_id,
-                                email: claims.email,
-                                is_admin: claims.is_admin,
-                            };
-                            return ready(Ok(user));
-                        }
-                        Err(_) => {
-                            return ready(Err(ErrorUnauthorized("Invalid token")));
-                        }
-                    }
-                }
+        let token = bearer_token(req);
+        let pool = req.app_data::<web::Data<PgPool>>().cloned();
+
+        Box::pin(authenticate(token, pool))
+    }
+}
+
+/// Auth lewat tiket stream sekali pakai (lihat `EventStreamUser`)
+async fn authenticate_ticket(
+    ticket: &str,
+    pool: Option<web::Data<PgPool>>,
+) -> Result<AuthenticatedUser, Error> {
+    let pool = pool.ok_or_else(|| AppError::Internal(anyhow::anyhow!("Database pool not configured")))?;
+    let owner = redeem_stream_ticket(&pool, ticket)
+        .await
+        .map_err(|e| match AppError::from(e) {
+            AppError::Internal(e) => AppError::Internal(e),
+            e => AppError::Unauthorized(e.to_string()),
+        })?;
+
+    Ok(AuthenticatedUser {
+        id: owner.user_id,
+        email: owner.email,
+        role: owner.role,
+        session_id: owner.session_id,
+    })
+}
+
+#[derive(Deserialize)]
+struct StreamTicketQuery {
+    ticket: Option<String>,
+}
+
+/// Extractor untuk endpoint SSE. EventSource di browser tidak bisa mengirim header
+/// Authorization, jadi selain header diterima juga tiket sekali pakai dari query `?ticket=`
+/// (access token tidak pernah ditaruh di URL supaya tidak tercatat di access log).
+#[derive(Debug, Clone)]
+pub struct EventStreamUser(pub AuthenticatedUser);
+
+impl FromRequest for EventStreamUser {
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let token = bearer_token(req);
+        let ticket = web::Query::<StreamTicketQuery>::from_query(req.query_string())
+            .ok()
+            .and_then(|query| query.into_inner().ticket);
+        let pool = req.app_data::<web::Data<PgPool>>().cloned();
+
+        Box::pin(async move {
+            let user = match (token, ticket) {
+                (None, Some(ticket)) => authenticate_ticket(&ticket, pool).await?,
+                (token, _) => authenticate(token, pool).await?,
+            };
+            Ok(EventStreamUser(user))
+        })
+    }
+}
+
+impl Deref for EventStreamUser {
+    type Target = AuthenticatedUser;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Extractor untuk endpoint khusus admin, user lain dapat 403
+#[derive(Debug, Clone)]
+pub struct AdminUser(pub AuthenticatedUser);
+
+impl FromRequest for AdminUser {
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let user = AuthenticatedUser::from_request(req, payload);
+
+        Box::pin(async move {
+            let user = user.await?;
+            if !user.role.is_admin() {
+                return Err(AppError::Forbidden("Access denied: Admin privileges required".to_string()).into());
             }
-        }
-        
-        ready(Err(ErrorUnauthorized("Missing or invalid authorization header")))
+            Ok(AdminUser(user))
+        })
+    }
+}
+
+impl Deref for AdminUser {
+    type Target = AuthenticatedUser;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Extractor untuk endpoint moderasi testimoni (admin atau moderator)
+#[derive(Debug, Clone)]
+pub struct ModeratorUser(pub AuthenticatedUser);
+
+impl FromRequest for ModeratorUser {
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let user = AuthenticatedUser::from_request(req, payload);
+
+        Box::pin(async move {
+            let user = user.await?;
+            if !user.role.can_moderate_testimoni() {
+                return Err(AppError::Forbidden("Access denied: Moderator privileges required".to_string()).into());
+            }
+            Ok(ModeratorUser(user))
+        })
+    }
+}
+
+impl Deref for ModeratorUser {
+    type Target = AuthenticatedUser;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
     }
 }
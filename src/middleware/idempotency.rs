@@ -0,0 +1,159 @@
+use actix_web::{http::StatusCode, dev::Payload, Error, FromRequest, HttpRequest, HttpResponse, ResponseError};
+use futures::future::{ready, Ready};
+use serde::Serialize;
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::services::idempotency_service::{start_request, complete_request, IdempotencyStatus};
+use crate::errors::AppError;
+use crate::utils::token::hash_token;
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+const MAX_KEY_LENGTH: usize = 255;
+
+/// Header Idempotency-Key (opsional) beserta method dan path request
+#[derive(Debug, Clone)]
+pub struct IdempotencyKey {
+    pub key: Option<String>,
+    pub method: String,
+    pub path: String,
+}
+
+impl FromRequest for IdempotencyKey {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let key = match req.headers().get(IDEMPOTENCY_KEY_HEADER) {
+            None => None,
+            Some(value) => {
+                let key = value.to_str().map(str::trim).unwrap_or_default();
+                if key.is_empty() || key.len() > MAX_KEY_LENGTH {
+                    return ready(Err(AppError::BadRequest(format!(
+                        "{} must be between 1 and {} characters",
+                        IDEMPOTENCY_KEY_HEADER, MAX_KEY_LENGTH
+                    )).into()));
+                }
+                Some(key.to_string())
+            }
+        };
+
+        ready(Ok(IdempotencyKey {
+            key,
+            method: req.method().to_string(),
+            path: req.path().to_string(),
+        }))
+    }
+}
+
+/// Status + body JSON dari handler yang diproses lewat IdempotentTransaction
+pub type IdempotentResponse = Result<(StatusCode, serde_json::Value), AppError>;
+
+pub fn respond<T: Serialize>(status: StatusCode, body: T) -> IdempotentResponse {
+    Ok((status, serde_json::to_value(body).unwrap_or_default()))
+}
+
+/// Error dari handler juga disimpan (kecuali 5xx) supaya retry mendapat response yang sama
+fn into_parts(response: IdempotentResponse) -> (StatusCode, serde_json::Value) {
+    response.unwrap_or_else(|e| {
+        let status = e.status_code();
+        if let AppError::Internal(error) = &e {
+            eprintln!("❌ Internal error: {:#}", error);
+        }
+        (status, serde_json::to_value(e.body()).unwrap_or_default())
+    })
+}
+
+/// Hasil begin_idempotent: response tersimpan untuk request ulang, atau transaksi untuk memproses request
+pub enum IdempotentStart {
+    Replay(HttpResponse),
+    Proceed(IdempotentTransaction),
+}
+
+/// Transaksi database satu request. Handler menjalankan perubahan saldo di `conn()`, lalu `finish`
+/// menyimpan response di transaksi yang sama sebelum commit. Kalau proses mati di tengah jalan,
+/// key ikut ter-rollback bersama perubahan saldonya sehingga retry diproses ulang dari awal.
+pub struct IdempotentTransaction {
+    tx: Transaction<'static, Postgres>,
+    user_id: Uuid,
+    key: Option<String>,
+}
+
+/// Mulai request yang (opsional) memakai Idempotency-Key. Request ulang dengan key dan body yang sama
+/// mendapat response yang tersimpan, key yang dipakai untuk request berbeda ditolak 422. Request kedua
+/// yang datang saat request pertama masih diproses menunggu sampai request pertama selesai.
+pub async fn begin_idempotent<T: Serialize>(
+    pool: &PgPool,
+    user_id: Uuid,
+    idempotency: &IdempotencyKey,
+    request: &T,
+) -> Result<IdempotentStart, AppError> {
+    let mut tx = pool.begin().await?;
+
+    let Some(key) = &idempotency.key else {
+        return Ok(IdempotentStart::Proceed(IdempotentTransaction { tx, user_id, key: None }));
+    };
+
+    let request_hash = hash_token(&serde_json::to_string(request).unwrap_or_default());
+
+    let state = start_request(
+        &mut tx,
+        user_id,
+        key,
+        &idempotency.method,
+        &idempotency.path,
+        &request_hash,
+    )
+    .await?;
+
+    match state {
+        IdempotencyStatus::Started => Ok(IdempotentStart::Proceed(IdempotentTransaction {
+            tx,
+            user_id,
+            key: Some(key.clone()),
+        })),
+        IdempotencyStatus::Completed { status, body } => {
+            let status = StatusCode::from_u16(status).unwrap_or(StatusCode::OK);
+            Ok(IdempotentStart::Replay(
+                HttpResponse::build(status)
+                    .insert_header(("Idempotent-Replayed", "true"))
+                    .json(body),
+            ))
+        }
+        IdempotencyStatus::InProgress => Err(AppError::Conflict(format!(
+            "A request with this {} is still being processed",
+            IDEMPOTENCY_KEY_HEADER
+        ))),
+        IdempotencyStatus::Mismatch => Err(AppError::Unprocessable(format!(
+            "{} has already been used for a different request",
+            IDEMPOTENCY_KEY_HEADER
+        ))),
+    }
+}
+
+impl IdempotentTransaction {
+    pub fn conn(&mut self) -> &mut PgConnection {
+        &mut self.tx
+    }
+
+    /// Simpan response (kecuali 5xx) lalu commit. Error 5xx me-rollback semuanya termasuk key,
+    /// supaya client bisa retry dengan key yang sama.
+    pub async fn finish(mut self, response: IdempotentResponse) -> Result<HttpResponse, AppError> {
+        let Some(key) = self.key.take() else {
+            let (status, body) = response?;
+            self.tx.commit().await?;
+            return Ok(HttpResponse::build(status).json(body));
+        };
+
+        let (status, body) = into_parts(response);
+        if status.is_server_error() {
+            self.tx.rollback().await?;
+            return Ok(HttpResponse::build(status).json(body));
+        }
+
+        complete_request(&mut self.tx, self.user_id, &key, status.as_u16(), &body).await?;
+        self.tx.commit().await?;
+
+        Ok(HttpResponse::build(status).json(body))
+    }
+}
@@ -4,6 +4,47 @@ use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use validator::Validate;
 use bigdecimal::BigDecimal;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::utils::currency::validate_currency;
+use crate::utils::money::{validate_non_negative, validate_positive, Money};
+use crate::utils::pagination::validate_page;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Admin,
+    Moderator,
+    User,
+}
+
+impl Role {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "admin" => Some(Role::Admin),
+            "moderator" => Some(Role::Moderator),
+            "user" => Some(Role::User),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+            Role::Moderator => "moderator",
+            Role::User => "user",
+        }
+    }
+
+    pub fn is_admin(&self) -> bool {
+        *self == Role::Admin
+    }
+
+    /// Admin dan moderator boleh approve/reject testimoni
+    pub fn can_moderate_testimoni(&self) -> bool {
+        matches!(self, Role::Admin | Role::Moderator)
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize, FromRow)]
 pub struct User {
@@ -12,28 +53,32 @@ pub struct User {
     pub email: String,
     pub password_hash: String,
     pub avatar: Option<String>,
-    pub is_admin: bool,
+    pub role: String,
     pub nomor_telepon: Option<String>,
     pub alamat: Option<String>,
     pub posisi_jabatan: Option<String>,
+    pub currency: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UserResponse {
     pub id: Uuid,
     pub full_name: String,
     pub email: String,
     pub avatar: Option<String>,
+    pub role: String,
     pub is_admin: bool,
     pub nomor_telepon: Option<String>,
     pub alamat: Option<String>,
     pub posisi_jabatan: Option<String>,
+    /// Mata uang utama, dipakai untuk total di statistik dan dashboard
+    pub currency: String,
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct Testimoni {
     pub id: Uuid,
     pub user_id: Uuid,
@@ -44,7 +89,7 @@ pub struct Testimoni {
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct TestimoniWithUser {
     pub id: Uuid,
     pub content: String,
@@ -54,7 +99,7 @@ pub struct TestimoniWithUser {
     pub user: UserResponse,
 }
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct RegisterRequest {
     #[validate(length(min = 2, max = 100, message = "Full name must be between 2 and 100 characters"))]
     pub full_name: String,
@@ -68,7 +113,7 @@ pub struct RegisterRequest {
     pub confirm_password: String,
 }
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct LoginRequest {
     #[validate(email(message = "Invalid email format"))]
     pub email: String,
@@ -79,13 +124,26 @@ pub struct LoginRequest {
     pub is_admin: Option<bool>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct AuthResponse {
     pub token: String,
+    pub refresh_token: String,
+    pub expires_in: i64,
     pub user: UserResponse,
 }
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RevokedSessions {
+    pub revoked_sessions: u64,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RefreshTokenRequest {
+    #[validate(length(min = 1, message = "Refresh token is required"))]
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateTestimoniRequest {
     #[validate(length(min = 10, max = 1000, message = "Content must be between 10 and 1000 characters"))]
     pub content: String,
@@ -94,29 +152,37 @@ pub struct CreateTestimoniRequest {
     pub rating: i32,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateTestimoniRequest {
     pub content: Option<String>,
     pub rating: Option<i32>,
     pub is_approved: Option<bool>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateUserRoleRequest {
+    pub role: Role,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdateUserRequest {
     pub full_name: Option<String>,
     pub avatar: Option<String>,
     pub nomor_telepon: Option<String>,
     pub alamat: Option<String>,
     pub posisi_jabatan: Option<String>,
+    /// Kode ISO 4217, lihat GET /currencies
+    #[validate(custom(function = "validate_currency"))]
+    pub currency: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct ForgotPasswordRequest {
     #[validate(email(message = "Invalid email format"))]
     pub email: String,
 }
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct ResetPasswordRequest {
     #[validate(length(min = 6, message = "Password must be at least 6 characters"))]
     pub new_password: String,
@@ -125,13 +191,14 @@ pub struct ResetPasswordRequest {
     pub reset_token: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct SavingsTarget {
     pub id: Uuid,
     pub user_id: Uuid,
     pub name: String,
-    pub target_amount: BigDecimal,
-    pub current_amount: Option<BigDecimal>,
+    pub target_amount: Money,
+    pub current_amount: Option<Money>,
+    pub currency: String,
     pub icon: Option<String>,
     pub icon_color: Option<String>,
     pub target_date: Option<chrono::NaiveDate>,
@@ -140,7 +207,60 @@ pub struct SavingsTarget {
     pub updated_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+/// Jenis entry di ledger transaksi
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionType {
+    Deposit,
+    Withdrawal,
+    Transfer,
+    Adjustment,
+    Reversal,
+}
+
+impl TransactionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionType::Deposit => "deposit",
+            TransactionType::Withdrawal => "withdrawal",
+            TransactionType::Transfer => "transfer",
+            TransactionType::Adjustment => "adjustment",
+            TransactionType::Reversal => "reversal",
+        }
+    }
+}
+
+/// Satu baris ledger (append-only). amount positif = masuk, negatif = keluar
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct LedgerEntry {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub savings_target_id: Uuid,
+    pub transaction_type: String,
+    pub amount: Money,
+    pub description: Option<String>,
+    pub group_id: Option<Uuid>,
+    pub reverses_transaction_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TargetBalanceMismatch {
+    pub savings_target_id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub cached_balance: Money,
+    pub ledger_balance: Money,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReconciliationReport {
+    pub checked_targets: usize,
+    pub is_consistent: bool,
+    pub target_mismatches: Vec<TargetBalanceMismatch>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct Activity {
     pub id: Uuid,
     pub user_id: Uuid,
@@ -148,7 +268,7 @@ pub struct Activity {
     pub activity_type: String,
     pub title: String,
     pub description: Option<String>,
-    pub amount: Option<BigDecimal>,
+    pub amount: Option<Money>,
     pub icon: Option<String>,
     pub icon_color: Option<String>,
     pub created_at: Option<DateTime<Utc>>,
@@ -158,9 +278,7 @@ pub struct Activity {
 pub struct UserStatistics {
     pub id: Uuid,
     pub user_id: Uuid,
-    pub total_saved: Option<BigDecimal>,
     pub streak_days: Option<i32>,
-    pub daily_average: Option<BigDecimal>,
     pub achievements_count: Option<i32>,
     pub last_deposit_date: Option<chrono::NaiveDate>,
     pub created_at: Option<DateTime<Utc>>,
@@ -178,31 +296,57 @@ pub struct Achievement {
     pub earned_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateSavingsTargetRequest {
     #[validate(length(min = 1, max = 255, message = "Target name must be between 1 and 255 characters"))]
     pub name: String,
     
-    #[validate(range(min = 1.0, message = "Target amount must be greater than 0"))]
-    pub target_amount: f64,
+    #[validate(custom(function = "validate_positive"))]
+    pub target_amount: Money,
     
     pub icon: Option<String>,
     pub icon_color: Option<String>,
     pub target_date: Option<chrono::NaiveDate>,
+
+    /// Kode ISO 4217, default mata uang utama user. Tidak bisa diubah setelah target dibuat.
+    #[validate(custom(function = "validate_currency"))]
+    pub currency: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct WithdrawRequest {
+    #[validate(custom(function = "validate_positive"))]
+    pub amount: Money,
+
+    #[validate(length(max = 255, message = "Reason must be at most 255 characters"))]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct TransferRequest {
+    pub from_target_id: Uuid,
+    pub to_target_id: Uuid,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub amount: Money,
+
+    #[validate(length(max = 255, message = "Note must be at most 255 characters"))]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateSavingsTargetRequest {
     pub name: Option<String>,
-    pub target_amount: Option<f64>,
-    pub current_amount: Option<f64>,
+    #[validate(custom(function = "validate_positive"))]
+    pub target_amount: Option<Money>,
+    #[validate(custom(function = "validate_non_negative"))]
+    pub current_amount: Option<Money>,
     pub icon: Option<String>,
     pub icon_color: Option<String>,
     pub target_date: Option<chrono::NaiveDate>,
-    pub is_completed: Option<bool>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Validate)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateActivityRequest {
     pub savings_target_id: Option<Uuid>,
     
@@ -213,17 +357,33 @@ pub struct CreateActivityRequest {
     pub title: String,
     
     pub description: Option<String>,
-    pub amount: Option<f64>,
+    pub amount: Option<Money>,
     pub icon: Option<String>,
     pub icon_color: Option<String>,
 }
 
+/// Filter GET /activities, semua opsional. Rentang tanggal inklusif.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ActivityFilter {
+    #[serde(rename = "type")]
+    pub activity_type: Option<String>,
+    pub target_id: Option<Uuid>,
+    #[param(value_type = Option<String>)]
+    pub min_amount: Option<Money>,
+    #[param(value_type = Option<String>)]
+    pub max_amount: Option<Money>,
+    pub from: Option<chrono::NaiveDate>,
+    pub to: Option<chrono::NaiveDate>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SavingsTargetResponse {
     pub id: Uuid,
     pub name: String,
-    pub target_amount: f64,
-    pub current_amount: f64,
+    pub target_amount: Money,
+    pub current_amount: Money,
+    pub currency: String,
     pub percentage: f64,
     pub icon: String,
     pub icon_color: String,
@@ -233,18 +393,81 @@ pub struct SavingsTargetResponse {
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ActivityResponse {
     pub id: Uuid,
     pub activity_type: String,
     pub title: String,
     pub description: Option<String>,
-    pub amount: f64,
+    pub amount: Money,
     pub icon: String,
     pub icon_color: String,
     pub created_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RecurringFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl RecurringFrequency {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "daily" => Some(RecurringFrequency::Daily),
+            "weekly" => Some(RecurringFrequency::Weekly),
+            "monthly" => Some(RecurringFrequency::Monthly),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecurringFrequency::Daily => "daily",
+            RecurringFrequency::Weekly => "weekly",
+            RecurringFrequency::Monthly => "monthly",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct RecurringDeposit {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub savings_target_id: Uuid,
+    pub amount: Money,
+    pub frequency: String,
+    pub day_of_month: Option<i16>,
+    pub start_date: chrono::NaiveDate,
+    pub end_date: Option<chrono::NaiveDate>,
+    pub next_run_date: chrono::NaiveDate,
+    pub is_paused: bool,
+    /// Jumlah kegagalan berturut-turut saat diproses worker, kembali 0 setelah berhasil
+    pub failure_count: i32,
+    pub last_error: Option<String>,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateRecurringDepositRequest {
+    pub savings_target_id: Uuid,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub amount: Money,
+
+    pub frequency: RecurringFrequency,
+
+    #[validate(range(min = 1, max = 31, message = "Day of month must be between 1 and 31"))]
+    pub day_of_month: Option<i16>,
+
+    pub start_date: chrono::NaiveDate,
+    pub end_date: Option<chrono::NaiveDate>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Reminder {
     pub id: Uuid,
@@ -260,7 +483,7 @@ pub struct Reminder {
     pub updated_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ReminderResponse {
     pub id: Uuid,
     pub reminder_date: chrono::NaiveDate,
@@ -285,6 +508,534 @@ pub struct Notification {
     pub timestamp: chrono::NaiveDateTime,
 }
 
+/// Nilai kolom notifications.type
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationType {
+    Info,
+    Success,
+    Error,
+    Reminder,
+    Deposit,
+    Achievement,
+    TargetCompleted,
+    BudgetExceeded,
+    Invitation,
+}
+
+impl NotificationType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationType::Info => "info",
+            NotificationType::Success => "success",
+            NotificationType::Error => "error",
+            NotificationType::Reminder => "reminder",
+            NotificationType::Deposit => "deposit",
+            NotificationType::Achievement => "achievement",
+            NotificationType::TargetCompleted => "target_completed",
+            NotificationType::BudgetExceeded => "budget_exceeded",
+            NotificationType::Invitation => "invitation",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationResponse {
+    pub id: i32,
+    pub r#type: String,
+    pub message: String,
+    pub read: bool,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl From<Notification> for NotificationResponse {
+    fn from(notification: Notification) -> Self {
+        NotificationResponse {
+            id: notification.id,
+            r#type: notification.r#type,
+            message: notification.message,
+            read: notification.read,
+            timestamp: notification.timestamp.and_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct NotificationListQuery {
+    #[validate(custom(function = "validate_page"))]
+    pub page: Option<i64>,
+    #[validate(range(min = 1, max = 100, message = "Per page must be between 1 and 100"))]
+    pub per_page: Option<i64>,
+    pub unread_only: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationPage {
+    pub notifications: Vec<NotificationResponse>,
+    pub page: i64,
+    pub per_page: i64,
+    pub total: i64,
+    pub unread_count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UnreadCount {
+    pub unread_count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MarkedAsRead {
+    pub updated: u64,
+}
+
+/// Tiket sekali pakai untuk `GET /notifications/stream?ticket=`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct StreamTicket {
+    pub ticket: String,
+    /// Detik sampai tiket kedaluwarsa
+    pub expires_in: i64,
+}
+
+/// Kurs: 1 `base_currency` = `rate` `quote_currency`, berlaku mulai `effective_date`
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ExchangeRate {
+    pub id: Uuid,
+    pub base_currency: String,
+    pub quote_currency: String,
+    #[schema(value_type = String)]
+    pub rate: BigDecimal,
+    pub effective_date: chrono::NaiveDate,
+    /// `manual` atau `import`
+    pub source: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpsertExchangeRateRequest {
+    #[validate(custom(function = "validate_currency"))]
+    pub base_currency: String,
+    #[validate(custom(function = "validate_currency"))]
+    pub quote_currency: String,
+    /// Angka desimal atau string, harus lebih dari 0
+    #[schema(value_type = String, example = "16250.50")]
+    pub rate: BigDecimal,
+    /// Default hari ini
+    pub effective_date: Option<chrono::NaiveDate>,
+}
+
+/// Filter GET /currencies/rates. Tanpa `date` yang dipakai hari ini.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExchangeRateQuery {
+    pub base: Option<String>,
+    pub quote: Option<String>,
+    pub date: Option<chrono::NaiveDate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExchangeRateImportResult {
+    pub imported: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CategoryKind {
+    Income,
+    Expense,
+}
+
+impl CategoryKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CategoryKind::Income => "income",
+            CategoryKind::Expense => "expense",
+        }
+    }
+}
+
+/// Kategori pemasukan atau pengeluaran (`kind`). Budget hanya untuk kategori pengeluaran.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ExpenseCategory {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    /// `income` atau `expense`
+    pub kind: String,
+    pub icon: Option<String>,
+    pub icon_color: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateExpenseCategoryRequest {
+    #[validate(length(min = 1, max = 100, message = "Name must be between 1 and 100 characters"))]
+    pub name: String,
+    /// Default `expense`
+    pub kind: Option<CategoryKind>,
+    #[validate(length(max = 10, message = "Icon must be at most 10 characters"))]
+    pub icon: Option<String>,
+    #[validate(length(max = 50, message = "Icon color must be at most 50 characters"))]
+    pub icon_color: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CategoryFilter {
+    pub kind: Option<CategoryKind>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateExpenseCategoryRequest {
+    #[validate(length(min = 1, max = 100, message = "Name must be between 1 and 100 characters"))]
+    pub name: Option<String>,
+    #[validate(length(max = 10, message = "Icon must be at most 10 characters"))]
+    pub icon: Option<String>,
+    #[validate(length(max = 50, message = "Icon color must be at most 50 characters"))]
+    pub icon_color: Option<String>,
+}
+
+/// Batas pengeluaran satu kategori di satu bulan (`month` = tanggal 1)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Budget {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub category_id: Uuid,
+    pub month: chrono::NaiveDate,
+    pub amount: Money,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SetBudgetRequest {
+    #[validate(custom(function = "validate_positive"))]
+    pub amount: Money,
+}
+
+/// Pengeluaran, nominal dalam mata uang utama user
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Expense {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub category_id: Uuid,
+    pub amount: Money,
+    pub payee: Option<String>,
+    pub description: Option<String>,
+    pub spent_on: chrono::NaiveDate,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateExpenseRequest {
+    pub category_id: Uuid,
+    #[validate(custom(function = "validate_positive"))]
+    pub amount: Money,
+    #[validate(length(max = 100, message = "Payee must be at most 100 characters"))]
+    pub payee: Option<String>,
+    #[validate(length(max = 255, message = "Description must be at most 255 characters"))]
+    pub description: Option<String>,
+    /// Default hari ini
+    pub spent_on: Option<chrono::NaiveDate>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateExpenseRequest {
+    pub category_id: Option<Uuid>,
+    #[validate(custom(function = "validate_positive"))]
+    pub amount: Option<Money>,
+    #[validate(length(max = 100, message = "Payee must be at most 100 characters"))]
+    pub payee: Option<String>,
+    #[validate(length(max = 255, message = "Description must be at most 255 characters"))]
+    pub description: Option<String>,
+    pub spent_on: Option<chrono::NaiveDate>,
+}
+
+/// Pemasukan, nominal dalam mata uang utama user. `payee` adalah pihak pemberi (kantor, klien, ...).
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Income {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub category_id: Uuid,
+    pub amount: Money,
+    pub payee: Option<String>,
+    pub description: Option<String>,
+    pub received_on: chrono::NaiveDate,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateIncomeRequest {
+    pub category_id: Uuid,
+    #[validate(custom(function = "validate_positive"))]
+    pub amount: Money,
+    #[validate(length(max = 100, message = "Payee must be at most 100 characters"))]
+    pub payee: Option<String>,
+    #[validate(length(max = 255, message = "Description must be at most 255 characters"))]
+    pub description: Option<String>,
+    /// Default hari ini
+    pub received_on: Option<chrono::NaiveDate>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateIncomeRequest {
+    pub category_id: Option<Uuid>,
+    #[validate(custom(function = "validate_positive"))]
+    pub amount: Option<Money>,
+    #[validate(length(max = 100, message = "Payee must be at most 100 characters"))]
+    pub payee: Option<String>,
+    #[validate(length(max = 255, message = "Description must be at most 255 characters"))]
+    pub description: Option<String>,
+    pub received_on: Option<chrono::NaiveDate>,
+}
+
+/// Filter GET /expenses dan GET /incomes. `month` berformat `YYYY-MM`.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CashflowFilter {
+    pub month: Option<String>,
+    pub category_id: Option<Uuid>,
+}
+
+/// Rencana vs realisasi satu kategori. `planned`/`remaining` kosong kalau kategori belum
+/// punya budget di bulan tersebut.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CategoryBudgetSummary {
+    pub category_id: Uuid,
+    pub name: String,
+    pub icon: Option<String>,
+    pub icon_color: Option<String>,
+    pub planned: Option<Money>,
+    pub spent: Money,
+    pub remaining: Option<Money>,
+    pub percentage: Option<f64>,
+    pub is_overspent: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BudgetSummary {
+    /// `YYYY-MM`
+    pub month: String,
+    pub currency: String,
+    pub total_planned: Money,
+    pub total_spent: Money,
+    pub total_remaining: Money,
+    pub categories: Vec<CategoryBudgetSummary>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CashflowGroupBy {
+    #[default]
+    Month,
+    Category,
+}
+
+/// Query GET /reports/cashflow. Default 6 bulan terakhir sampai hari ini, dikelompokkan per bulan.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CashflowReportQuery {
+    pub from: Option<chrono::NaiveDate>,
+    pub to: Option<chrono::NaiveDate>,
+    pub group_by: Option<CashflowGroupBy>,
+}
+
+/// Satu baris laporan: satu bulan (`month` = `YYYY-MM`) atau satu kategori
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CashflowGroup {
+    pub month: Option<String>,
+    pub category_id: Option<Uuid>,
+    pub category_name: Option<String>,
+    pub income: Money,
+    pub expense: Money,
+    pub net: Money,
+    /// `net / income * 100`, kosong kalau tidak ada pemasukan
+    pub savings_rate: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CashflowReport {
+    pub from: chrono::NaiveDate,
+    pub to: chrono::NaiveDate,
+    pub group_by: CashflowGroupBy,
+    pub currency: String,
+    pub total_income: Money,
+    pub total_expense: Money,
+    pub net: Money,
+    pub savings_rate: Option<f64>,
+    pub groups: Vec<CashflowGroup>,
+}
+
+/// Role anggota target bersama, urut dari akses paling sedikit.
+/// viewer hanya melihat, contributor boleh setor, owner boleh semuanya.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum MemberRole {
+    Viewer,
+    Contributor,
+    Owner,
+}
+
+impl MemberRole {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "viewer" => Some(MemberRole::Viewer),
+            "contributor" => Some(MemberRole::Contributor),
+            "owner" => Some(MemberRole::Owner),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MemberRole::Viewer => "viewer",
+            MemberRole::Contributor => "contributor",
+            MemberRole::Owner => "owner",
+        }
+    }
+}
+
+/// Anggota target beserta kontribusinya. `contributed` = jumlah bersih semua entry ledger
+/// yang dicatat anggota ini di target (setoran dikurangi penarikan/pembatalan).
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TargetMember {
+    pub user_id: Uuid,
+    pub full_name: String,
+    pub email: String,
+    /// `owner`, `contributor` atau `viewer`
+    pub role: String,
+    pub contributed: Money,
+    pub deposit_count: i64,
+    /// Persentase kontribusi terhadap saldo target saat ini
+    pub share: f64,
+    pub joined_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateMemberRequest {
+    pub role: MemberRole,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TargetInvitation {
+    pub id: Uuid,
+    pub savings_target_id: Uuid,
+    pub target_name: String,
+    pub email: String,
+    /// `owner`, `contributor` atau `viewer`
+    pub role: String,
+    pub invited_by: Uuid,
+    pub invited_by_name: String,
+    /// `pending`, `accepted`, `declined` atau `revoked`
+    pub status: String,
+    pub expires_at: DateTime<Utc>,
+    pub responded_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateInvitationRequest {
+    #[validate(email(message = "Invalid email format"))]
+    pub email: String,
+    /// Default `contributor`
+    pub role: Option<MemberRole>,
+}
+
+/// Activity di satu target dari semua anggota, lengkap dengan siapa pelakunya
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TargetActivity {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub activity_type: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub amount: Money,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ForecastStatus {
+    Completed,
+    /// Perkiraan selesai sebelum atau tepat di target_date
+    OnTrack,
+    /// Perkiraan selesai setelah target_date, tapi paling lambat 25% lebih lama dari sisa waktu
+    AtRisk,
+    Behind,
+    /// Target tanpa target_date
+    NoDeadline,
+}
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ForecastQuery {
+    /// Nominal setoran berulang hipotetis untuk mode what-if
+    #[param(value_type = Option<String>)]
+    pub what_if_amount: Option<Money>,
+    /// Frekuensi setoran what-if, default `monthly`
+    pub what_if_frequency: Option<RecurringFrequency>,
+}
+
+/// Riwayat arus ledger target yang dipakai untuk proyeksi
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DepositHistory {
+    pub window_start: chrono::NaiveDate,
+    pub window_days: i64,
+    /// Jumlah entry uang masuk (setoran, transfer masuk, pembatalan tarik dana)
+    pub deposit_count: i64,
+    pub total_deposited: Money,
+    pub average_deposit: Money,
+    /// Total uang keluar (tarik dana, transfer keluar, pembatalan setoran)
+    pub total_withdrawn: Money,
+    /// total_deposited - total_withdrawn
+    pub net_flow: Money,
+    /// Rata-rata arus bersih per hari selama window, bisa negatif
+    pub daily_rate: Money,
+    pub last_deposit_date: Option<chrono::NaiveDate>,
+}
+
+/// Nominal per setoran supaya target tercapai tepat di target_date, setoran pertama hari ini
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RequiredContribution {
+    pub daily: Money,
+    pub weekly: Money,
+    pub monthly: Money,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WhatIfForecast {
+    pub amount: Money,
+    pub frequency: RecurringFrequency,
+    pub contributions_needed: i64,
+    pub projected_completion_date: Option<chrono::NaiveDate>,
+    pub status: ForecastStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TargetForecast {
+    pub target_id: Uuid,
+    pub currency: String,
+    pub target_amount: Money,
+    pub current_amount: Money,
+    pub remaining: Money,
+    pub percentage: f64,
+    pub target_date: Option<chrono::NaiveDate>,
+    pub days_remaining: Option<i64>,
+    pub history: DepositHistory,
+    /// Kosong kalau belum ada setoran di window
+    pub projected_completion_date: Option<chrono::NaiveDate>,
+    /// Kosong kalau tidak ada target_date, deadline sudah lewat, atau target sudah tercapai
+    pub required: Option<RequiredContribution>,
+    pub status: ForecastStatus,
+    /// Terisi kalau `what_if_amount` dikirim
+    pub what_if: Option<WhatIfForecast>,
+}
+
 impl From<User> for UserResponse {
     fn from(user: User) -> Self {
         Self {
@@ -292,10 +1043,12 @@ impl From<User> for UserResponse {
             full_name: user.full_name,
             email: user.email,
             avatar: user.avatar,
-            is_admin: user.is_admin,
+            is_admin: user.role == Role::Admin.as_str(),
+            role: user.role,
             nomor_telepon: user.nomor_telepon,
             alamat: user.alamat,
             posisi_jabatan: user.posisi_jabatan,
+            currency: user.currency,
             created_at: user.created_at,
         }
     }
@@ -303,18 +1056,9 @@ impl From<User> for UserResponse {
 
 impl From<SavingsTarget> for SavingsTargetResponse {
     fn from(target: SavingsTarget) -> Self {
-        let target_amount_f64 = target.target_amount.to_string().parse().unwrap_or(0.0);
-        let current_amount_f64 = target.current_amount
-            .unwrap_or_else(|| bigdecimal::BigDecimal::from(0))
-            .to_string()
-            .parse()
-            .unwrap_or(0.0);
-        let percentage = if target_amount_f64 > 0.0 {
-            (current_amount_f64 / target_amount_f64) * 100.0
-        } else {
-            0.0
-        };
-        
+        let current_amount = target.current_amount.unwrap_or_default();
+        let percentage = current_amount.percent_of(&target.target_amount);
+
         let days_remaining = target.target_date.map(|date| {
             let today = chrono::Utc::now().date_naive();
             (date - today).num_days()
@@ -323,36 +1067,102 @@ impl From<SavingsTarget> for SavingsTargetResponse {
         Self {
             id: target.id,
             name: target.name,
-            target_amount: target_amount_f64,
-            current_amount: current_amount_f64,
+            target_amount: target.target_amount,
+            current_amount,
+            currency: target.currency,
             percentage,
             icon: target.icon.unwrap_or_else(|| "💰".to_string()),
             icon_color: target.icon_color.unwrap_or_else(|| "bg-blue-500".to_string()),
             target_date: target.target_date,
             is_completed: target.is_completed.unwrap_or(false),
             days_remaining,
-            created_at: target.created_at.unwrap_or_else(|| chrono::Utc::now()),
+            created_at: target.created_at.unwrap_or_else(chrono::Utc::now),
         }
     }
 }
 
 impl From<Activity> for ActivityResponse {
     fn from(activity: Activity) -> Self {
-        let amount_f64 = activity.amount
-            .unwrap_or_else(|| bigdecimal::BigDecimal::from(0))
-            .to_string()
-            .parse()
-            .unwrap_or(0.0);
-        
         Self {
             id: activity.id,
             activity_type: activity.activity_type,
             title: activity.title,
             description: activity.description,
-            amount: amount_f64,
+            amount: activity.amount.unwrap_or_default(),
             icon: activity.icon.unwrap_or_else(|| "💰".to_string()),
             icon_color: activity.icon_color.unwrap_or_else(|| "bg-blue-500".to_string()),
-            created_at: activity.created_at.unwrap_or_else(|| chrono::Utc::now()),
+            created_at: activity.created_at.unwrap_or_else(chrono::Utc::now),
         }
     }
 }
+
+/// Jenis data yang bisa dicari lewat /search. User dan testimoni hanya untuk admin.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchResultType {
+    SavingsTarget,
+    Activity,
+    Reminder,
+    Notification,
+    User,
+    Testimoni,
+}
+
+impl SearchResultType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchResultType::SavingsTarget => "savings_target",
+            SearchResultType::Activity => "activity",
+            SearchResultType::Reminder => "reminder",
+            SearchResultType::Notification => "notification",
+            SearchResultType::User => "user",
+            SearchResultType::Testimoni => "testimoni",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "savings_target" => Some(SearchResultType::SavingsTarget),
+            "activity" => Some(SearchResultType::Activity),
+            "reminder" => Some(SearchResultType::Reminder),
+            "notification" => Some(SearchResultType::Notification),
+            "user" => Some(SearchResultType::User),
+            "testimoni" => Some(SearchResultType::Testimoni),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchQuery {
+    #[validate(length(min = 2, max = 100, message = "Query must be between 2 and 100 characters"))]
+    pub q: String,
+    /// Filter jenis hasil, dipisah koma. Contoh: `savings_target,reminder`
+    pub types: Option<String>,
+    #[validate(custom(function = "validate_page"))]
+    pub page: Option<i64>,
+    #[validate(range(min = 1, max = 50, message = "Per page must be between 1 and 50"))]
+    pub per_page: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SearchResult {
+    pub result_type: String,
+    /// Text karena id notifikasi integer, sisanya UUID
+    pub id: String,
+    pub title: String,
+    /// Potongan teks yang cocok, kata yang cocok diapit <mark></mark>
+    pub snippet: Option<String>,
+    pub rank: f32,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SearchResponse {
+    pub query: String,
+    pub results: Vec<SearchResult>,
+    pub page: i64,
+    pub per_page: i64,
+    pub total: i64,
+}
@@ -0,0 +1,169 @@
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+use crate::handlers::{
+    activity, auth, budget, currency, dashboard, docs, expense, health, income, notification, password,
+    recurring_deposit, reminder, report, savings, search, statistics, testimoni, user,
+};
+
+/// Spesifikasi OpenAPI 3 yang dibangun dari anotasi `#[utoipa::path]` di handler dan
+/// `ToSchema` di model. Setiap route baru wajib didaftarkan di `paths` (dicek oleh test di bawah).
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Tabungin API",
+        description = "REST API aplikasi tabungan Tabungin. Semua response sukses dibungkus `ApiResponse`, \
+                       semua error memakai `ErrorBody`."
+    ),
+    paths(
+        health::health_check,
+        docs::openapi_json_handler,
+        auth::register_handler,
+        auth::login_handler,
+        auth::refresh_handler,
+        auth::logout_handler,
+        auth::logout_all_handler,
+        password::forgot_password_handler,
+        password::reset_password_handler,
+        password::change_password_handler,
+        user::get_profile_handler,
+        user::update_profile_handler,
+        user::get_users_handler,
+        user::update_user_role_handler,
+        testimoni::create_testimoni_handler,
+        testimoni::get_all_testimoni_handler,
+        testimoni::get_approved_testimoni_handler,
+        testimoni::get_user_testimoni_handler,
+        testimoni::get_testimoni_by_id_handler,
+        testimoni::update_testimoni_handler,
+        testimoni::delete_testimoni_handler,
+        dashboard::get_dashboard_stats_handler,
+        dashboard::get_user_analytics_handler,
+        dashboard::get_trend_data_handler,
+        dashboard::get_rating_distribution_handler,
+        dashboard::get_reconciliation_handler,
+        savings::create_savings_target_handler,
+        savings::get_user_savings_targets_handler,
+        savings::get_savings_target_handler,
+        savings::update_savings_target_handler,
+        savings::delete_savings_target_handler,
+        savings::add_deposit_handler,
+        savings::withdraw_handler,
+        savings::get_target_transactions_handler,
+        savings::transfer_handler,
+        savings::reverse_transaction_handler,
+        savings::reconciliation_handler,
+        savings::get_target_members_handler,
+        savings::update_target_member_handler,
+        savings::remove_target_member_handler,
+        savings::create_target_invitation_handler,
+        savings::get_target_invitations_handler,
+        savings::revoke_target_invitation_handler,
+        savings::get_my_invitations_handler,
+        savings::accept_invitation_handler,
+        savings::decline_invitation_handler,
+        savings::get_target_activities_handler,
+        savings::get_target_forecast_handler,
+        activity::create_activity_handler,
+        activity::get_user_activities_handler,
+        activity::get_recent_activities_handler,
+        statistics::get_user_statistics_handler,
+        statistics::get_user_achievements_handler,
+        statistics::get_user_streak_handler,
+        reminder::get_user_reminders,
+        reminder::get_upcoming_reminders,
+        reminder::get_todays_reminders,
+        reminder::get_calendar_events,
+        reminder::mark_reminder_completed,
+        recurring_deposit::create_recurring_deposit_handler,
+        recurring_deposit::get_user_recurring_deposits_handler,
+        recurring_deposit::preview_schedule_handler,
+        recurring_deposit::get_recurring_deposit_handler,
+        recurring_deposit::delete_recurring_deposit_handler,
+        recurring_deposit::pause_recurring_deposit_handler,
+        recurring_deposit::resume_recurring_deposit_handler,
+        recurring_deposit::skip_recurring_deposit_handler,
+        recurring_deposit::preview_recurring_deposit_handler,
+        notification::get_notifications_handler,
+        notification::notification_stream_handler,
+        notification::create_stream_ticket_handler,
+        notification::get_unread_count_handler,
+        notification::mark_all_as_read_handler,
+        notification::mark_as_read_handler,
+        notification::delete_notification_handler,
+        search::search_handler,
+        currency::get_currencies_handler,
+        currency::get_exchange_rates_handler,
+        currency::upsert_exchange_rate_handler,
+        currency::import_exchange_rates_handler,
+        budget::get_categories_handler,
+        budget::create_category_handler,
+        budget::update_category_handler,
+        budget::delete_category_handler,
+        budget::get_budget_summary_handler,
+        budget::set_budget_handler,
+        budget::delete_budget_handler,
+        expense::create_expense_handler,
+        expense::get_expenses_handler,
+        expense::update_expense_handler,
+        expense::delete_expense_handler,
+        income::create_income_handler,
+        income::get_incomes_handler,
+        income::update_income_handler,
+        income::delete_income_handler,
+        report::get_cashflow_report_handler,
+    ),
+    modifiers(&BearerAuth),
+    tags(
+        (name = "auth", description = "Register, login dan session"),
+        (name = "password", description = "Lupa, reset dan ganti password"),
+        (name = "users", description = "Profil user dan manajemen user oleh admin"),
+        (name = "testimoni", description = "Testimoni dan moderasi"),
+        (name = "dashboard", description = "Statistik dashboard"),
+        (name = "savings", description = "Target tabungan, deposit, penarikan, transfer, ledger, target bersama dan forecast"),
+        (name = "activities", description = "Riwayat aktivitas"),
+        (name = "statistics", description = "Statistik, achievement dan streak user"),
+        (name = "reminders", description = "Pengingat target"),
+        (name = "recurring-deposits", description = "Tabungan otomatis"),
+        (name = "notifications", description = "Notifikasi dan stream SSE"),
+        (name = "search", description = "Pencarian global"),
+        (name = "currencies", description = "Mata uang dan kurs"),
+        (name = "budgets", description = "Kategori, budget bulanan dan pengeluaran"),
+        (name = "cashflow", description = "Pemasukan dan laporan arus kas"),
+        (name = "health", description = "Health check"),
+        (name = "docs", description = "Spesifikasi OpenAPI"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Skema auth `bearer_auth` yang dipakai di `security(...)` pada handler
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Sinkronisasi spec dengan router diuji di tests/openapi.rs
+    #[test]
+    fn spec_serializes_with_bearer_auth() {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        assert_eq!(spec["openapi"].as_str().map(|v| &v[..2]), Some("3."));
+        assert!(spec["components"]["securitySchemes"]["bearer_auth"].is_object());
+        assert!(spec["components"]["schemas"]["ErrorBody"].is_object());
+    }
+}
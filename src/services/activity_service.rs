@@ -1,19 +1,20 @@
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 use anyhow::Result;
-use bigdecimal::{BigDecimal, FromPrimitive};
 
 use crate::models::{
-    Activity, CreateActivityRequest, ActivityResponse
+    Activity, CreateActivityRequest, ActivityResponse, ActivityFilter
 };
+use crate::utils::pagination::{Cursor, Page, PageRequest};
+use crate::utils::currency::format_money;
+use crate::utils::money::Money;
 
 pub async fn create_activity(
     pool: &PgPool,
     user_id: Uuid,
     req: CreateActivityRequest,
 ) -> Result<Activity> {
-    let amount = BigDecimal::from_f64(req.amount.unwrap_or(0.0))
-        .ok_or_else(|| anyhow::anyhow!("Invalid amount"))?;
+    let amount = req.amount.unwrap_or_default();
 
     let activity = sqlx::query_as!(
         Activity,
@@ -21,14 +22,14 @@ pub async fn create_activity(
         INSERT INTO activities (user_id, savings_target_id, activity_type, title, description, amount, icon, icon_color)
         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
         RETURNING id, user_id, savings_target_id, activity_type, title, description, 
-                  amount, icon, icon_color, created_at
+                  amount as "amount: Money", icon, icon_color, created_at
         "#,
         user_id,
         req.savings_target_id,
         req.activity_type,
         req.title,
         req.description,
-        amount,
+        amount.as_decimal(),
         req.icon.unwrap_or_else(|| "💰".to_string()),
         req.icon_color.unwrap_or_else(|| "bg-blue-500".to_string())
     )
@@ -38,35 +39,81 @@ pub async fn create_activity(
     Ok(activity)
 }
 
+/// List activity user dengan cursor (created_at, id). Satu-satunya kolom sort: created_at.
 pub async fn get_user_activities(
     pool: &PgPool,
     user_id: Uuid,
-    limit: Option<i32>,
-) -> Result<Vec<ActivityResponse>> {
-    let limit = limit.unwrap_or(50);
-    
+    filter: &ActivityFilter,
+    page: &PageRequest,
+) -> Result<Page<ActivityResponse>> {
     let activities = sqlx::query_as!(
         Activity,
         r#"
         SELECT id, user_id, savings_target_id, activity_type, title, description,
-               amount, icon, icon_color, created_at
-        FROM activities 
-        WHERE user_id = $1 
-        ORDER BY created_at DESC
-        LIMIT $2
+               amount as "amount: Money", icon, icon_color, created_at
+        FROM activities
+        WHERE user_id = $1
+          AND ($2::text IS NULL OR activity_type = $2)
+          AND ($3::uuid IS NULL OR savings_target_id = $3)
+          AND ($4::numeric IS NULL OR amount >= $4)
+          AND ($5::numeric IS NULL OR amount <= $5)
+          AND ($6::date IS NULL OR created_at >= $6)
+          AND ($7::date IS NULL OR created_at < $7 + 1)
+          AND ($8::timestamptz IS NULL OR CASE
+                WHEN $10 THEN (created_at, id) < ($8, $9::uuid)
+                ELSE (created_at, id) > ($8, $9::uuid)
+              END)
+        ORDER BY CASE WHEN $10 THEN created_at END DESC, CASE WHEN $10 THEN id END DESC,
+                 created_at ASC, id ASC
+        LIMIT $11
         "#,
         user_id,
-        limit as i64
+        filter.activity_type,
+        filter.target_id,
+        filter.min_amount.as_ref().map(Money::as_decimal),
+        filter.max_amount.as_ref().map(Money::as_decimal),
+        filter.from,
+        filter.to,
+        page.cursor_key(),
+        page.cursor_id(),
+        page.sort.descending,
+        page.fetch_limit()
     )
     .fetch_all(pool)
     .await?;
 
+    let total = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) as "count!"
+        FROM activities
+        WHERE user_id = $1
+          AND ($2::text IS NULL OR activity_type = $2)
+          AND ($3::uuid IS NULL OR savings_target_id = $3)
+          AND ($4::numeric IS NULL OR amount >= $4)
+          AND ($5::numeric IS NULL OR amount <= $5)
+          AND ($6::date IS NULL OR created_at >= $6)
+          AND ($7::date IS NULL OR created_at < $7 + 1)
+        "#,
+        user_id,
+        filter.activity_type,
+        filter.target_id,
+        filter.min_amount.as_ref().map(Money::as_decimal),
+        filter.max_amount.as_ref().map(Money::as_decimal),
+        filter.from,
+        filter.to
+    )
+    .fetch_one(pool)
+    .await?;
+
     let responses: Vec<ActivityResponse> = activities
         .into_iter()
         .map(ActivityResponse::from)
         .collect();
 
-    Ok(responses)
+    Ok(Page::from_rows(responses, page, Some(total), |activity| Cursor {
+        key: activity.created_at,
+        id: activity.id,
+    }))
 }
 
 pub async fn get_recent_activities_for_dashboard(
@@ -76,7 +123,7 @@ pub async fn get_recent_activities_for_dashboard(
         Activity,
         r#"
         SELECT id, user_id, savings_target_id, activity_type, title, description,
-               amount, icon, icon_color, created_at
+               amount as "amount: Money", icon, icon_color, created_at
         FROM activities 
         ORDER BY created_at DESC
         LIMIT 20
@@ -93,16 +140,14 @@ pub async fn get_recent_activities_for_dashboard(
     Ok(responses)
 }
 
-pub async fn create_deposit_activity(
-    pool: &PgPool,
+pub async fn create_deposit_activity<'e, E: PgExecutor<'e>>(
+    executor: E,
     user_id: Uuid,
     savings_target_id: Option<Uuid>,
-    amount: f64,
+    amount: &Money,
     target_name: Option<String>,
+    currency: &str,
 ) -> Result<Activity> {
-    let decimal_amount = BigDecimal::from_f64(amount)
-        .ok_or_else(|| anyhow::anyhow!("Invalid amount"))?;
-
     let title = match target_name {
         Some(name) => format!("Menabung untuk {}", name),
         None => "Menabung".to_string(),
@@ -114,22 +159,22 @@ pub async fn create_deposit_activity(
         INSERT INTO activities (user_id, savings_target_id, activity_type, title, description, amount, icon, icon_color)
         VALUES ($1, $2, 'deposit', $3, $4, $5, '💰', 'bg-green-500')
         RETURNING id, user_id, savings_target_id, activity_type, title, description, 
-                  amount, icon, icon_color, created_at
+                  amount as "amount: Money", icon, icon_color, created_at
         "#,
         user_id,
         savings_target_id,
         title,
-        format!("Setoran sebesar Rp {}", format_currency(amount)),
-        decimal_amount
+        format!("Setoran sebesar {}", format_money(amount.as_decimal(), currency)),
+        amount.as_decimal()
     )
-    .fetch_one(pool)
+    .fetch_one(executor)
     .await?;
 
     Ok(activity)
 }
 
-pub async fn create_target_created_activity(
-    pool: &PgPool,
+pub async fn create_target_created_activity<'e, E: PgExecutor<'e>>(
+    executor: E,
     user_id: Uuid,
     savings_target_id: Uuid,
     target_name: String,
@@ -140,21 +185,21 @@ pub async fn create_target_created_activity(
         INSERT INTO activities (user_id, savings_target_id, activity_type, title, description, amount, icon, icon_color)
         VALUES ($1, $2, 'target_created', $3, $4, 0, '🎯', 'bg-blue-500')
         RETURNING id, user_id, savings_target_id, activity_type, title, description, 
-                  amount, icon, icon_color, created_at
+                  amount as "amount: Money", icon, icon_color, created_at
         "#,
         user_id,
         savings_target_id,
         "Target baru dibuat",
         format!("Target \"{}\" berhasil dibuat", target_name),
     )
-    .fetch_one(pool)
+    .fetch_one(executor)
     .await?;
 
     Ok(activity)
 }
 
-pub async fn create_target_completed_activity(
-    pool: &PgPool,
+pub async fn create_target_completed_activity<'e, E: PgExecutor<'e>>(
+    executor: E,
     user_id: Uuid,
     savings_target_id: Uuid,
     target_name: String,
@@ -165,37 +210,66 @@ pub async fn create_target_completed_activity(
         INSERT INTO activities (user_id, savings_target_id, activity_type, title, description, amount, icon, icon_color)
         VALUES ($1, $2, 'target_completed', $3, $4, 0, '🎉', 'bg-green-500')
         RETURNING id, user_id, savings_target_id, activity_type, title, description, 
-                  amount, icon, icon_color, created_at
+                  amount as "amount: Money", icon, icon_color, created_at
         "#,
         user_id,
         savings_target_id,
         "Target tercapai!",
         format!("Selamat! Target \"{}\" telah tercapai", target_name),
     )
-    .fetch_one(pool)
+    .fetch_one(executor)
     .await?;
 
     Ok(activity)
 }
 
-fn format_currency(amount: f64) -> String {
-    if amount >= 1_000_000.0 {
-        format!("{:.1}M", amount / 1_000_000.0)
-    } else if amount >= 1_000.0 {
-        format!("{:.0}K", amount / 1_000.0)
-    } else {
-        format!("{:.0}", amount)
-    }
+/// Satu sisi transfer antar target (dipanggil dua kali: target asal dan tujuan)
+pub async fn create_transfer_activity<'e, E: PgExecutor<'e>>(
+    executor: E,
+    user_id: Uuid,
+    savings_target_id: Uuid,
+    title: String,
+    amount: &Money,
+    note: Option<&str>,
+    currency: &str,
+) -> Result<Activity> {
+    let description = match note {
+        Some(note) => format!("Transfer sebesar {} ({})", format_money(amount.as_decimal(), currency), note),
+        None => format!("Transfer sebesar {}", format_money(amount.as_decimal(), currency)),
+    };
+
+    let activity = sqlx::query_as!(
+        Activity,
+        r#"
+        INSERT INTO activities (user_id, savings_target_id, activity_type, title, description, amount, icon, icon_color)
+        VALUES ($1, $2, 'transfer', $3, $4, $5, '🔁', 'bg-purple-500')
+        RETURNING id, user_id, savings_target_id, activity_type, title, description, 
+                  amount as "amount: Money", icon, icon_color, created_at
+        "#,
+        user_id,
+        savings_target_id,
+        title,
+        description,
+        amount.as_decimal()
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(activity)
 }
 
-pub async fn log_withdrawal(
-    pool: &PgPool,
+pub async fn log_withdrawal<'e, E: PgExecutor<'e>>(
+    executor: E,
     user_id: Uuid,
     target_id: Uuid,
-    amount: f64,
+    amount: &Money,
+    reason: Option<&str>,
+    currency: &str,
 ) -> Result<Activity> {
-    let decimal_amount = BigDecimal::from_f64(amount)
-        .ok_or_else(|| anyhow::anyhow!("Invalid amount"))?;
+    let description = match reason {
+        Some(reason) => format!("Penarikan sebesar {} ({})", format_money(amount.as_decimal(), currency), reason),
+        None => format!("Penarikan sebesar {}", format_money(amount.as_decimal(), currency)),
+    };
 
     let activity = sqlx::query_as!(
         Activity,
@@ -203,14 +277,14 @@ pub async fn log_withdrawal(
         INSERT INTO activities (user_id, savings_target_id, activity_type, title, description, amount, icon, icon_color)
         VALUES ($1, $2, 'withdrawal', 'Penarikan', $3, $4, '💸', 'bg-red-500')
         RETURNING id, user_id, savings_target_id, activity_type, title, description, 
-                  amount, icon, icon_color, created_at
+                  amount as "amount: Money", icon, icon_color, created_at
         "#,
         user_id,
         target_id,
-        format!("Penarikan sebesar Rp {}", format_currency(amount)),
-        decimal_amount
+        description,
+        amount.as_decimal()
     )
-    .fetch_one(pool)
+    .fetch_one(executor)
     .await?;
 
     Ok(activity)
@@ -3,8 +3,9 @@ use uuid::Uuid;
 use bcrypt::{hash, verify, DEFAULT_COST};
 use anyhow::{Result, anyhow};
 
-use crate::models::{User, RegisterRequest, LoginRequest, AuthResponse};
-use crate::utils::jwt::generate_jwt_token;
+use crate::models::{User, RegisterRequest, LoginRequest, AuthResponse, Role};
+use crate::services::session_service::create_session;
+use crate::errors::AppError;
 
 pub async fn register_user(
     pool: &PgPool,
@@ -19,7 +20,7 @@ pub async fn register_user(
     .await?;
 
     if existing_user.is_some() {
-        return Err(anyhow!("User with this email already exists"));
+        return Err(AppError::Conflict("User with this email already exists".to_string()).into());
     }
 
     // Hash password dengan bcrypt
@@ -30,7 +31,7 @@ pub async fn register_user(
     let user_id = Uuid::new_v4();
     let user = sqlx::query_as::<_, User>(
         r#"
-        INSERT INTO users (id, full_name, email, password_hash, is_admin, created_at, updated_at)
+        INSERT INTO users (id, full_name, email, password_hash, role, created_at, updated_at)
         VALUES ($1, $2, $3, $4, $5, NOW(), NOW())
         RETURNING *
         "#
@@ -39,17 +40,12 @@ pub async fn register_user(
     .bind(&request.full_name)
     .bind(request.email.trim())
     .bind(&password_hash)
-    .bind(false) // Default to non-admin
+    .bind(Role::User.as_str()) // Default to regular user
     .fetch_one(pool)
     .await?;
 
-    // Generate JWT token
-    let token = generate_jwt_token(user.id, &user.email, user.is_admin)?;
-
-    Ok(AuthResponse {
-        token,
-        user: user.into(),
-    })
+    // Buat session baru (access token + refresh token)
+    create_session(pool, user).await
 }
 
 pub async fn login_user(
@@ -69,7 +65,7 @@ pub async fn login_user(
     .bind(&email)
     .fetch_optional(pool)
     .await?
-    .ok_or_else(|| anyhow!("Invalid email or password"))?;
+    .ok_or_else(|| AppError::Unauthorized("Invalid email or password".to_string()))?;
 
     // Debug: print user email dan password hash dari DB
     println!("Found user email: {}", &user.email);
@@ -77,28 +73,24 @@ pub async fn login_user(
 
     // Force umar@app.com to always be user (not admin) regardless of DB value
     if user.email == "umar@app.com" {
-        user.is_admin = false;
+        user.role = Role::User.as_str().to_string();
     }
 
     // Verify password dengan bcrypt
     if !verify(&request.password, &user.password_hash)
         .map_err(|_| anyhow!("Password verification failed"))? 
     {
-        return Err(anyhow!("Invalid email or password"));
+        return Err(AppError::Unauthorized("Invalid email or password".to_string()).into());
     }
 
-    // Check admin login if required
+    // Check admin login if required (moderator juga boleh masuk panel admin untuk approve testimoni)
     if let Some(is_admin_required) = request.is_admin {
-        if is_admin_required && !user.is_admin {
-            return Err(anyhow!("Access denied: Admin privileges required"));
+        let role = Role::from_name(&user.role).unwrap_or(Role::User);
+        if is_admin_required && !role.can_moderate_testimoni() {
+            return Err(AppError::Forbidden("Access denied: Admin privileges required".to_string()).into());
         }
     }
 
-    // Generate JWT token
-    let token = generate_jwt_token(user.id, &user.email, user.is_admin)?;
-
-    Ok(AuthResponse {
-        token,
-        user: user.into(),
-    })
+    // Buat session baru (access token + refresh token)
+    create_session(pool, user).await
 }
@@ -0,0 +1,451 @@
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::{
+    Budget, BudgetSummary, CashflowFilter, CategoryBudgetSummary, CategoryKind, CreateExpenseCategoryRequest,
+    CreateExpenseRequest, Expense, ExpenseCategory, UpdateExpenseCategoryRequest, UpdateExpenseRequest,
+};
+use crate::services::currency_service::get_user_currency;
+use crate::services::notification_service::notify_budget_exceeded;
+use crate::utils::currency::check_minor_units;
+use crate::utils::money::Money;
+
+/// Parse bulan `YYYY-MM` menjadi tanggal 1 bulan tersebut
+pub fn parse_month(value: &str) -> Result<NaiveDate, AppError> {
+    NaiveDate::parse_from_str(&format!("{}-01", value.trim()), "%Y-%m-%d")
+        .map_err(|_| AppError::BadRequest(format!("Invalid month '{}', expected YYYY-MM", value)))
+}
+
+pub(crate) fn month_start(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+fn category_not_found(category_id: Uuid) -> AppError {
+    AppError::NotFound(format!("Expense category with ID {} not found", category_id))
+}
+
+pub async fn get_categories(
+    pool: &PgPool,
+    user_id: Uuid,
+    kind: Option<CategoryKind>,
+) -> Result<Vec<ExpenseCategory>> {
+    let categories = sqlx::query_as!(
+        ExpenseCategory,
+        r#"
+        SELECT id, user_id, name, kind, icon, icon_color, created_at, updated_at
+        FROM expense_categories
+        WHERE user_id = $1 AND ($2::varchar IS NULL OR kind = $2)
+        ORDER BY kind, LOWER(name)
+        "#,
+        user_id,
+        kind.map(|kind| kind.as_str())
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(categories)
+}
+
+/// Nama kategori unik per user (case-insensitive) dijaga unique index; pelanggarannya jadi 409
+fn map_name_conflict(error: sqlx::Error, name: &str) -> anyhow::Error {
+    if error.as_database_error().is_some_and(|e| e.is_unique_violation()) {
+        AppError::Conflict(format!("Expense category '{}' already exists", name)).into()
+    } else {
+        error.into()
+    }
+}
+
+pub async fn create_category(
+    pool: &PgPool,
+    user_id: Uuid,
+    req: &CreateExpenseCategoryRequest,
+) -> Result<ExpenseCategory> {
+    let name = req.name.trim();
+
+    let category = sqlx::query_as!(
+        ExpenseCategory,
+        r#"
+        INSERT INTO expense_categories (user_id, name, kind, icon, icon_color)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, user_id, name, kind, icon, icon_color, created_at, updated_at
+        "#,
+        user_id,
+        name,
+        req.kind.unwrap_or(CategoryKind::Expense).as_str(),
+        req.icon,
+        req.icon_color
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| map_name_conflict(e, name))?;
+
+    Ok(category)
+}
+
+pub async fn update_category(
+    pool: &PgPool,
+    category_id: Uuid,
+    user_id: Uuid,
+    req: &UpdateExpenseCategoryRequest,
+) -> Result<Option<ExpenseCategory>> {
+    let name = req.name.as_deref().map(str::trim);
+
+    let category = sqlx::query_as!(
+        ExpenseCategory,
+        r#"
+        UPDATE expense_categories
+        SET name = COALESCE($1, name),
+            icon = COALESCE($2, icon),
+            icon_color = COALESCE($3, icon_color),
+            updated_at = NOW()
+        WHERE id = $4 AND user_id = $5
+        RETURNING id, user_id, name, kind, icon, icon_color, created_at, updated_at
+        "#,
+        name,
+        req.icon,
+        req.icon_color,
+        category_id,
+        user_id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| map_name_conflict(e, name.unwrap_or_default()))?;
+
+    Ok(category)
+}
+
+/// Hapus kategori beserta budget-nya. Kategori yang masih punya pemasukan/pengeluaran ditolak (409).
+pub async fn delete_category(pool: &PgPool, category_id: Uuid, user_id: Uuid) -> Result<bool> {
+    let in_use = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(SELECT 1 FROM expenses WHERE category_id = $1 AND user_id = $2)
+            OR EXISTS(SELECT 1 FROM incomes WHERE category_id = $1 AND user_id = $2) as "in_use!"
+        "#,
+        category_id,
+        user_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    if in_use {
+        return Err(AppError::Conflict("Category still has incomes or expenses".to_string()).into());
+    }
+
+    let result = sqlx::query!(
+        "DELETE FROM expense_categories WHERE id = $1 AND user_id = $2",
+        category_id,
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Kunci kategori `kind` milik user selama transaksi, supaya cek overspend tidak balapan
+pub(crate) async fn lock_category(
+    conn: &mut PgConnection,
+    category_id: Uuid,
+    user_id: Uuid,
+    kind: CategoryKind,
+) -> Result<()> {
+    sqlx::query_scalar!(
+        "SELECT id FROM expense_categories WHERE id = $1 AND user_id = $2 AND kind = $3 FOR UPDATE",
+        category_id,
+        user_id,
+        kind.as_str()
+    )
+    .fetch_optional(&mut *conn)
+    .await?
+    .ok_or_else(|| match kind {
+        CategoryKind::Income => AppError::NotFound(format!("Income category with ID {} not found", category_id)),
+        CategoryKind::Expense => category_not_found(category_id),
+    })?;
+
+    Ok(())
+}
+
+/// Set batas pengeluaran kategori di bulan `month`, menimpa nilai sebelumnya.
+/// None kalau kategori bukan milik user.
+pub async fn set_budget(
+    pool: &PgPool,
+    user_id: Uuid,
+    category_id: Uuid,
+    month: NaiveDate,
+    amount: &Money,
+) -> Result<Option<Budget>> {
+    check_minor_units("amount", amount, &get_user_currency(pool, user_id).await?)?;
+
+    let budget = sqlx::query_as!(
+        Budget,
+        r#"
+        INSERT INTO budgets (user_id, category_id, month, amount)
+        SELECT user_id, id, $3, $4 FROM expense_categories WHERE id = $2 AND user_id = $1 AND kind = 'expense'
+        ON CONFLICT (category_id, month) DO UPDATE SET
+            amount = EXCLUDED.amount,
+            updated_at = NOW()
+        RETURNING id, user_id, category_id, month, amount, created_at, updated_at
+        "#,
+        user_id,
+        category_id,
+        month_start(month),
+        amount.as_decimal()
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(budget)
+}
+
+pub async fn delete_budget(pool: &PgPool, user_id: Uuid, category_id: Uuid, month: NaiveDate) -> Result<bool> {
+    let result = sqlx::query!(
+        "DELETE FROM budgets WHERE user_id = $1 AND category_id = $2 AND month = $3",
+        user_id,
+        category_id,
+        month_start(month)
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Rencana vs realisasi semua kategori user di bulan `month`
+pub async fn get_budget_summary(pool: &PgPool, user_id: Uuid, month: NaiveDate) -> Result<BudgetSummary> {
+    let month = month_start(month);
+    let currency = get_user_currency(pool, user_id).await?;
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT c.id, c.name, c.icon, c.icon_color,
+               b.amount as "planned?: Money",
+               COALESCE((
+                   SELECT SUM(e.amount) FROM expenses e
+                   WHERE e.category_id = c.id
+                     AND e.spent_on >= $2 AND e.spent_on < ($2 + INTERVAL '1 month')::date
+               ), 0) as "spent!: Money"
+        FROM expense_categories c
+        LEFT JOIN budgets b ON b.category_id = c.id AND b.month = $2
+        WHERE c.user_id = $1 AND c.kind = 'expense'
+        ORDER BY LOWER(c.name)
+        "#,
+        user_id,
+        month
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let categories: Vec<CategoryBudgetSummary> = rows
+        .into_iter()
+        .map(|row| {
+            let remaining = row.planned.clone().map(|planned| planned - row.spent.clone());
+            CategoryBudgetSummary {
+                category_id: row.id,
+                name: row.name,
+                icon: row.icon,
+                icon_color: row.icon_color,
+                percentage: row.planned.as_ref().map(|planned| row.spent.percent_of(planned)),
+                is_overspent: row.planned.as_ref().is_some_and(|planned| &row.spent > planned),
+                planned: row.planned,
+                spent: row.spent,
+                remaining,
+            }
+        })
+        .collect();
+
+    let total_planned: Money = categories.iter().filter_map(|c| c.planned.clone()).sum();
+    let total_spent: Money = categories.iter().map(|c| c.spent.clone()).sum();
+
+    Ok(BudgetSummary {
+        month: month.format("%Y-%m").to_string(),
+        currency,
+        total_remaining: total_planned.clone() - total_spent.clone(),
+        total_planned,
+        total_spent,
+        categories,
+    })
+}
+
+/// Kirim notifikasi kalau perubahan sebesar `added` membuat pengeluaran kategori di bulan
+/// `month` melewati budget-nya. Hanya saat melewati batas, jadi tidak dikirim ulang untuk
+/// setiap pengeluaran berikutnya di bulan yang sama.
+async fn notify_if_overspent(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    category_id: Uuid,
+    month: NaiveDate,
+    added: Money,
+) -> Result<()> {
+    if !added.is_positive() {
+        return Ok(());
+    }
+
+    let row = sqlx::query!(
+        r#"
+        SELECT c.name, u.currency, b.amount as "limit: Money",
+               COALESCE((
+                   SELECT SUM(e.amount) FROM expenses e
+                   WHERE e.category_id = c.id
+                     AND e.spent_on >= $2 AND e.spent_on < ($2 + INTERVAL '1 month')::date
+               ), 0) as "spent!: Money"
+        FROM expense_categories c
+        JOIN users u ON u.id = c.user_id
+        JOIN budgets b ON b.category_id = c.id AND b.month = $2
+        WHERE c.id = $1
+        "#,
+        category_id,
+        month
+    )
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(());
+    };
+
+    let spent_before = row.spent.clone() - added;
+    if spent_before <= row.limit && row.spent > row.limit {
+        notify_budget_exceeded(&mut *conn, user_id, &row.name, &row.spent, &row.limit, &row.currency).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn create_expense(pool: &PgPool, user_id: Uuid, req: &CreateExpenseRequest) -> Result<Expense> {
+    check_minor_units("amount", &req.amount, &get_user_currency(pool, user_id).await?)?;
+
+    let mut tx = pool.begin().await?;
+    lock_category(&mut tx, req.category_id, user_id, CategoryKind::Expense).await?;
+
+    let expense = sqlx::query_as!(
+        Expense,
+        r#"
+        INSERT INTO expenses (user_id, category_id, amount, payee, description, spent_on)
+        VALUES ($1, $2, $3, $4, $5, COALESCE($6, CURRENT_DATE))
+        RETURNING id, user_id, category_id, amount, payee, description, spent_on, created_at, updated_at
+        "#,
+        user_id,
+        req.category_id,
+        req.amount.as_decimal(),
+        req.payee,
+        req.description,
+        req.spent_on
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    notify_if_overspent(&mut tx, user_id, expense.category_id, month_start(expense.spent_on), expense.amount.clone())
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(expense)
+}
+
+pub async fn get_expenses(pool: &PgPool, user_id: Uuid, filter: &CashflowFilter) -> Result<Vec<Expense>> {
+    let month = filter.month.as_deref().map(parse_month).transpose()?;
+
+    let expenses = sqlx::query_as!(
+        Expense,
+        r#"
+        SELECT id, user_id, category_id, amount, payee, description, spent_on, created_at, updated_at
+        FROM expenses
+        WHERE user_id = $1
+          AND ($2::date IS NULL OR (spent_on >= $2 AND spent_on < ($2 + INTERVAL '1 month')::date))
+          AND ($3::uuid IS NULL OR category_id = $3)
+        ORDER BY spent_on DESC, created_at DESC
+        "#,
+        user_id,
+        month,
+        filter.category_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(expenses)
+}
+
+pub async fn update_expense(
+    pool: &PgPool,
+    expense_id: Uuid,
+    user_id: Uuid,
+    req: &UpdateExpenseRequest,
+) -> Result<Option<Expense>> {
+    if let Some(amount) = &req.amount {
+        check_minor_units("amount", amount, &get_user_currency(pool, user_id).await?)?;
+    }
+
+    let mut tx = pool.begin().await?;
+
+    let existing = sqlx::query_as!(
+        Expense,
+        r#"
+        SELECT id, user_id, category_id, amount, payee, description, spent_on, created_at, updated_at
+        FROM expenses
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+        expense_id,
+        user_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some(existing) = existing else {
+        return Ok(None);
+    };
+
+    let category_id = req.category_id.unwrap_or(existing.category_id);
+    lock_category(&mut tx, category_id, user_id, CategoryKind::Expense).await?;
+
+    let expense = sqlx::query_as!(
+        Expense,
+        r#"
+        UPDATE expenses
+        SET category_id = $1,
+            amount = COALESCE($2, amount),
+            payee = COALESCE($3, payee),
+            description = COALESCE($4, description),
+            spent_on = COALESCE($5, spent_on),
+            updated_at = NOW()
+        WHERE id = $6
+        RETURNING id, user_id, category_id, amount, payee, description, spent_on, created_at, updated_at
+        "#,
+        category_id,
+        req.amount.as_ref().map(Money::as_decimal),
+        req.payee,
+        req.description,
+        req.spent_on,
+        expense_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    // Yang bertambah di kategori/bulan tujuan: seluruh nominal kalau pindah, selisihnya kalau tidak
+    let month = month_start(expense.spent_on);
+    let added = if existing.category_id == expense.category_id && month_start(existing.spent_on) == month {
+        expense.amount.clone() - existing.amount
+    } else {
+        expense.amount.clone()
+    };
+    notify_if_overspent(&mut tx, user_id, expense.category_id, month, added).await?;
+
+    tx.commit().await?;
+
+    Ok(Some(expense))
+}
+
+pub async fn delete_expense(pool: &PgPool, expense_id: Uuid, user_id: Uuid) -> Result<bool> {
+    let result = sqlx::query!(
+        "DELETE FROM expenses WHERE id = $1 AND user_id = $2",
+        expense_id,
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
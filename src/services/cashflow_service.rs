@@ -0,0 +1,291 @@
+use anyhow::Result;
+use chrono::{Months, NaiveDate};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::{
+    CashflowFilter, CashflowGroup, CashflowGroupBy, CashflowReport, CashflowReportQuery, CategoryKind,
+    CreateIncomeRequest, Income, UpdateIncomeRequest,
+};
+use crate::services::budget_service::{lock_category, month_start, parse_month};
+use crate::services::currency_service::get_user_currency;
+use crate::utils::currency::check_minor_units;
+use crate::utils::money::Money;
+
+/// Rentang default laporan: bulan berjalan dan 5 bulan sebelumnya
+const DEFAULT_REPORT_MONTHS: u32 = 6;
+/// Batas rentang laporan supaya deret bulan tidak terlalu panjang
+const MAX_REPORT_DAYS: i64 = 3660;
+
+pub async fn create_income(pool: &PgPool, user_id: Uuid, req: &CreateIncomeRequest) -> Result<Income> {
+    // Pemasukan dicatat dalam mata uang utama user
+    check_minor_units("amount", &req.amount, &get_user_currency(pool, user_id).await?)?;
+
+    let mut tx = pool.begin().await?;
+    lock_category(&mut tx, req.category_id, user_id, CategoryKind::Income).await?;
+
+    let income = sqlx::query_as!(
+        Income,
+        r#"
+        INSERT INTO incomes (user_id, category_id, amount, payee, description, received_on)
+        VALUES ($1, $2, $3, $4, $5, COALESCE($6, CURRENT_DATE))
+        RETURNING id, user_id, category_id, amount, payee, description, received_on, created_at, updated_at
+        "#,
+        user_id,
+        req.category_id,
+        req.amount.as_decimal(),
+        req.payee,
+        req.description,
+        req.received_on
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(income)
+}
+
+pub async fn get_incomes(pool: &PgPool, user_id: Uuid, filter: &CashflowFilter) -> Result<Vec<Income>> {
+    let month = filter.month.as_deref().map(parse_month).transpose()?;
+
+    let incomes = sqlx::query_as!(
+        Income,
+        r#"
+        SELECT id, user_id, category_id, amount, payee, description, received_on, created_at, updated_at
+        FROM incomes
+        WHERE user_id = $1
+          AND ($2::date IS NULL OR (received_on >= $2 AND received_on < ($2 + INTERVAL '1 month')::date))
+          AND ($3::uuid IS NULL OR category_id = $3)
+        ORDER BY received_on DESC, created_at DESC
+        "#,
+        user_id,
+        month,
+        filter.category_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(incomes)
+}
+
+pub async fn update_income(
+    pool: &PgPool,
+    income_id: Uuid,
+    user_id: Uuid,
+    req: &UpdateIncomeRequest,
+) -> Result<Option<Income>> {
+    if let Some(amount) = &req.amount {
+        check_minor_units("amount", amount, &get_user_currency(pool, user_id).await?)?;
+    }
+
+    let mut tx = pool.begin().await?;
+
+    if let Some(category_id) = req.category_id {
+        lock_category(&mut tx, category_id, user_id, CategoryKind::Income).await?;
+    }
+
+    let income = sqlx::query_as!(
+        Income,
+        r#"
+        UPDATE incomes
+        SET category_id = COALESCE($1, category_id),
+            amount = COALESCE($2, amount),
+            payee = COALESCE($3, payee),
+            description = COALESCE($4, description),
+            received_on = COALESCE($5, received_on),
+            updated_at = NOW()
+        WHERE id = $6 AND user_id = $7
+        RETURNING id, user_id, category_id, amount, payee, description, received_on, created_at, updated_at
+        "#,
+        req.category_id,
+        req.amount.as_ref().map(Money::as_decimal),
+        req.payee,
+        req.description,
+        req.received_on,
+        income_id,
+        user_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(income)
+}
+
+pub async fn delete_income(pool: &PgPool, income_id: Uuid, user_id: Uuid) -> Result<bool> {
+    let result = sqlx::query!(
+        "DELETE FROM incomes WHERE id = $1 AND user_id = $2",
+        income_id,
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Persentase pemasukan yang tersisa (tidak dibelanjakan), kosong kalau tidak ada pemasukan
+fn savings_rate(income: &Money, net: &Money) -> Option<f64> {
+    income.is_positive().then(|| net.percent_of(income))
+}
+
+fn cashflow_group(month: Option<String>, category: Option<(Uuid, String)>, income: Money, expense: Money) -> CashflowGroup {
+    let net = income.clone() - expense.clone();
+    let (category_id, category_name) = category.unzip();
+
+    CashflowGroup {
+        month,
+        category_id,
+        category_name,
+        savings_rate: savings_rate(&income, &net),
+        income,
+        expense,
+        net,
+    }
+}
+
+/// Pemasukan vs pengeluaran dalam rentang `from..=to`, per bulan atau per kategori.
+/// Deret bulan dibuat dengan generate_series seperti trend di dashboard, jadi bulan tanpa
+/// transaksi tetap muncul dengan nilai nol.
+pub async fn get_cashflow_report(
+    pool: &PgPool,
+    user_id: Uuid,
+    query: &CashflowReportQuery,
+) -> Result<CashflowReport> {
+    let today = sqlx::query_scalar!(r#"SELECT CURRENT_DATE as "today!""#)
+        .fetch_one(pool)
+        .await?;
+    let to = query.to.unwrap_or(today);
+    let from = query.from.unwrap_or_else(|| default_from(to));
+    if from > to {
+        return Err(AppError::BadRequest("'from' must be on or before 'to'".to_string()).into());
+    }
+    if (to - from).num_days() > MAX_REPORT_DAYS {
+        return Err(AppError::BadRequest("Date range must be at most 10 years".to_string()).into());
+    }
+
+    let group_by = query.group_by.unwrap_or_default();
+    let currency = get_user_currency(pool, user_id).await?;
+
+    let groups = match group_by {
+        CashflowGroupBy::Month => {
+            let rows = sqlx::query!(
+                r#"
+                WITH months AS (
+                    SELECT generate_series(
+                        date_trunc('month', $2::date),
+                        date_trunc('month', $3::date),
+                        INTERVAL '1 month'
+                    )::date as month
+                )
+                SELECT
+                    m.month as "month!",
+                    COALESCE(i.total, 0) as "income!: Money",
+                    COALESCE(e.total, 0) as "expense!: Money"
+                FROM months m
+                LEFT JOIN (
+                    SELECT date_trunc('month', received_on)::date as month, SUM(amount) as total
+                    FROM incomes
+                    WHERE user_id = $1 AND received_on BETWEEN $2 AND $3
+                    GROUP BY 1
+                ) i ON i.month = m.month
+                LEFT JOIN (
+                    SELECT date_trunc('month', spent_on)::date as month, SUM(amount) as total
+                    FROM expenses
+                    WHERE user_id = $1 AND spent_on BETWEEN $2 AND $3
+                    GROUP BY 1
+                ) e ON e.month = m.month
+                ORDER BY m.month
+                "#,
+                user_id,
+                from,
+                to
+            )
+            .fetch_all(pool)
+            .await?;
+
+            rows.into_iter()
+                .map(|row| cashflow_group(Some(row.month.format("%Y-%m").to_string()), None, row.income, row.expense))
+                .collect::<Vec<_>>()
+        }
+        CashflowGroupBy::Category => {
+            let rows = sqlx::query!(
+                r#"
+                SELECT c.id, c.name, c.kind, t.total as "total!: Money"
+                FROM expense_categories c
+                JOIN (
+                    SELECT category_id, SUM(amount) as total
+                    FROM incomes
+                    WHERE user_id = $1 AND received_on BETWEEN $2 AND $3
+                    GROUP BY category_id
+                    UNION ALL
+                    SELECT category_id, SUM(amount) as total
+                    FROM expenses
+                    WHERE user_id = $1 AND spent_on BETWEEN $2 AND $3
+                    GROUP BY category_id
+                ) t ON t.category_id = c.id
+                ORDER BY c.kind = 'income' DESC, t.total DESC, LOWER(c.name)
+                "#,
+                user_id,
+                from,
+                to
+            )
+            .fetch_all(pool)
+            .await?;
+
+            rows.into_iter()
+                .map(|row| {
+                    let (income, expense) = if row.kind == CategoryKind::Income.as_str() {
+                        (row.total, Money::zero())
+                    } else {
+                        (Money::zero(), row.total)
+                    };
+                    cashflow_group(None, Some((row.id, row.name)), income, expense)
+                })
+                .collect::<Vec<_>>()
+        }
+    };
+
+    let total_income: Money = groups.iter().map(|g| g.income.clone()).sum();
+    let total_expense: Money = groups.iter().map(|g| g.expense.clone()).sum();
+    let net = total_income.clone() - total_expense.clone();
+
+    Ok(CashflowReport {
+        from,
+        to,
+        group_by,
+        currency,
+        savings_rate: savings_rate(&total_income, &net),
+        total_income,
+        total_expense,
+        net,
+        groups,
+    })
+}
+
+fn default_from(to: NaiveDate) -> NaiveDate {
+    let first = month_start(to);
+    first.checked_sub_months(Months::new(DEFAULT_REPORT_MONTHS - 1)).unwrap_or(first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn savings_rate_is_empty_without_income() {
+        let income: Money = "4000000".parse().unwrap();
+        let net: Money = "1000000".parse().unwrap();
+        assert_eq!(savings_rate(&income, &net), Some(25.0));
+        assert_eq!(savings_rate(&Money::zero(), &-net), None);
+    }
+
+    #[test]
+    fn default_range_covers_six_months() {
+        let to = NaiveDate::from_ymd_opt(2025, 3, 15).unwrap();
+        assert_eq!(default_from(to), NaiveDate::from_ymd_opt(2024, 10, 1).unwrap());
+    }
+}
@@ -0,0 +1,200 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use bigdecimal::{BigDecimal, RoundingMode, Zero};
+use chrono::NaiveDate;
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::{ExchangeRate, ExchangeRateQuery, UpsertExchangeRateRequest};
+use crate::utils::currency::Currency;
+use crate::utils::money::Money;
+
+/// Digit desimal kolom exchange_rates.rate (NUMERIC(20,10))
+const RATE_SCALE: i64 = 10;
+
+/// Total saldo target yang sudah dikonversi ke satu mata uang
+#[derive(Debug)]
+pub struct ConvertedTotal {
+    pub currency: String,
+    pub total: Money,
+    /// Mata uang target yang belum punya kurs ke `currency`, saldonya tidak ikut dijumlah
+    pub unconverted_currencies: Vec<String>,
+}
+
+/// Mata uang utama user
+pub async fn get_user_currency<'e, E: PgExecutor<'e>>(executor: E, user_id: Uuid) -> Result<String> {
+    let currency = sqlx::query_scalar!("SELECT currency FROM users WHERE id = $1", user_id)
+        .fetch_optional(executor)
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+    Ok(currency)
+}
+
+/// Jumlah saldo semua target (atau target milik `user_id` saja) dalam `currency`,
+/// memakai kurs yang berlaku hari ini
+pub async fn converted_savings_total<'e, E: PgExecutor<'e>>(
+    executor: E,
+    currency: &str,
+    user_id: Option<Uuid>,
+) -> Result<ConvertedTotal> {
+    let row = sqlx::query!(
+        r#"
+        SELECT
+            ROUND(COALESCE(SUM(COALESCE(current_amount, 0) * exchange_rate(currency, $1, CURRENT_DATE)), 0), 2) as "total!",
+            COALESCE(
+                ARRAY_AGG(DISTINCT currency::text) FILTER (
+                    WHERE exchange_rate(currency, $1, CURRENT_DATE) IS NULL AND COALESCE(current_amount, 0) <> 0
+                ),
+                '{}'
+            ) as "unconverted!"
+        FROM savings_targets
+        WHERE $2::uuid IS NULL OR user_id = $2
+        "#,
+        currency,
+        user_id
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(ConvertedTotal {
+        currency: currency.to_string(),
+        total: row.total.into(),
+        unconverted_currencies: row.unconverted,
+    })
+}
+
+/// Kurs terbaru per pasangan mata uang yang berlaku pada tanggal `date` (default hari ini)
+pub async fn get_exchange_rates(pool: &PgPool, query: &ExchangeRateQuery) -> Result<Vec<ExchangeRate>> {
+    let rates = sqlx::query_as!(
+        ExchangeRate,
+        r#"
+        SELECT DISTINCT ON (base_currency, quote_currency)
+               id, base_currency, quote_currency, rate, effective_date, source, created_at, updated_at
+        FROM exchange_rates
+        WHERE ($1::varchar IS NULL OR base_currency = $1)
+          AND ($2::varchar IS NULL OR quote_currency = $2)
+          AND effective_date <= COALESCE($3, CURRENT_DATE)
+        ORDER BY base_currency, quote_currency, effective_date DESC
+        "#,
+        query.base.as_deref().map(str::to_uppercase),
+        query.quote.as_deref().map(str::to_uppercase),
+        query.date
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rates)
+}
+
+/// Simpan kurs manual. Kurs untuk pasangan dan tanggal yang sama ditimpa.
+pub async fn upsert_exchange_rate(pool: &PgPool, req: &UpsertExchangeRateRequest) -> Result<ExchangeRate> {
+    let rate = validate_rate(&req.base_currency, &req.quote_currency, &req.rate).map_err(AppError::BadRequest)?;
+    let rate = save_rate(pool, &req.base_currency, &req.quote_currency, &rate, req.effective_date, "manual").await?;
+    Ok(rate)
+}
+
+/// Import kurs dari CSV `base_currency,quote_currency,rate[,effective_date]` (header opsional,
+/// baris kosong dan `#` diabaikan). Semua baris disimpan dalam satu transaksi; satu baris
+/// tidak valid membatalkan seluruh import.
+pub async fn import_exchange_rates(pool: &PgPool, csv: &str) -> Result<usize> {
+    let mut rows = Vec::new();
+    for (index, line) in csv.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || (rows.is_empty() && line.starts_with("base")) {
+            continue;
+        }
+        let row = parse_rate_line(line)
+            .map_err(|message| AppError::BadRequest(format!("Line {}: {}", index + 1, message)))?;
+        rows.push(row);
+    }
+
+    if rows.is_empty() {
+        return Err(AppError::BadRequest("CSV contains no exchange rates".to_string()).into());
+    }
+
+    let mut tx = pool.begin().await?;
+    for (base, quote, rate, effective_date) in &rows {
+        save_rate(&mut *tx, base, quote, rate, *effective_date, "import").await?;
+    }
+    tx.commit().await?;
+
+    Ok(rows.len())
+}
+
+type RateRow = (String, String, BigDecimal, Option<NaiveDate>);
+
+fn parse_rate_line(line: &str) -> Result<RateRow, String> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    if fields.len() < 3 || fields.len() > 4 {
+        return Err("expected base_currency,quote_currency,rate[,effective_date]".to_string());
+    }
+
+    let base = fields[0].to_uppercase();
+    let quote = fields[1].to_uppercase();
+    let rate = BigDecimal::from_str(fields[2]).map_err(|_| format!("invalid rate '{}'", fields[2]))?;
+    let effective_date = match fields.get(3).filter(|d| !d.is_empty()) {
+        Some(date) => Some(
+            NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| format!("invalid date '{}'", date))?,
+        ),
+        None => None,
+    };
+
+    let rate = validate_rate(&base, &quote, &rate)?;
+    Ok((base, quote, rate, effective_date))
+}
+
+/// Cek pasangan mata uang dan nilai kurs, hasilnya dibulatkan ke skala kolom
+fn validate_rate(base: &str, quote: &str, rate: &BigDecimal) -> Result<BigDecimal, String> {
+    for code in [base, quote] {
+        if Currency::find(code).is_none() {
+            return Err(format!("unsupported currency code '{}'", code));
+        }
+    }
+    if base == quote {
+        return Err("base and quote currency must be different".to_string());
+    }
+
+    let rate = rate.with_scale_round(RATE_SCALE, RoundingMode::HalfUp);
+    if rate <= BigDecimal::zero() {
+        return Err("rate must be greater than 0".to_string());
+    }
+    if rate >= BigDecimal::from(10_000_000_000i64) {
+        return Err("rate is too large".to_string());
+    }
+
+    Ok(rate)
+}
+
+async fn save_rate<'e, E: PgExecutor<'e>>(
+    executor: E,
+    base: &str,
+    quote: &str,
+    rate: &BigDecimal,
+    effective_date: Option<NaiveDate>,
+    source: &str,
+) -> Result<ExchangeRate> {
+    let rate = sqlx::query_as!(
+        ExchangeRate,
+        r#"
+        INSERT INTO exchange_rates (base_currency, quote_currency, rate, effective_date, source)
+        VALUES ($1, $2, $3, COALESCE($4, CURRENT_DATE), $5)
+        ON CONFLICT (base_currency, quote_currency, effective_date) DO UPDATE SET
+            rate = EXCLUDED.rate,
+            source = EXCLUDED.source,
+            updated_at = NOW()
+        RETURNING id, base_currency, quote_currency, rate, effective_date, source, created_at, updated_at
+        "#,
+        base,
+        quote,
+        rate,
+        effective_date,
+        source
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(rate)
+}
@@ -3,8 +3,14 @@ use uuid::Uuid;
 use anyhow::Result;
 use serde::{Serialize, Deserialize};
 use bigdecimal::BigDecimal;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize, Deserialize)]
+use crate::services::currency_service::converted_savings_total;
+use crate::utils::currency::DEFAULT_CURRENCY;
+use crate::utils::money::Money;
+
+/// Nominal dalam mata uang utama admin yang meminta (`currency`)
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DashboardStats {
     pub total_users: i64,
     pub total_testimoni: i64,
@@ -13,39 +19,46 @@ pub struct DashboardStats {
     pub avg_rating: Option<f64>,
     pub total_savings_targets: i64,
     pub completed_targets: i64,
-    pub total_saved_amount: f64,
+    pub total_saved_amount: Money,
     pub total_activities: i64,
     pub active_users_today: i64,
+    pub currency: String,
+    /// Mata uang target yang belum punya kurs ke `currency` sehingga tidak ikut dijumlah
+    pub unconverted_currencies: Vec<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UserAnalytics {
     pub user_testimoni_count: i64,
     pub user_avg_rating: Option<f64>,
     pub account_created: chrono::DateTime<chrono::Utc>,
     pub total_savings_targets: i64,
     pub completed_targets: i64,
-    pub total_saved: f64,
+    pub total_saved: Money,
     pub streak_days: i32,
     pub achievements_count: i32,
+    /// Mata uang utama user, dipakai untuk `total_saved`
+    pub currency: String,
+    pub unconverted_currencies: Vec<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// `savings_amount` dalam mata uang utama admin, dikonversi dengan kurs di tanggal deposit
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct TrendData {
     pub date: chrono::NaiveDate,
     pub user_count: i64,
-    pub savings_amount: f64,
+    pub savings_amount: Money,
     pub transactions_count: i64,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct RatingDistribution {
     pub rating: i32,
     pub count: i64,
     pub percentage: f64,
 }
 
-pub async fn get_dashboard_stats(pool: &PgPool) -> Result<DashboardStats> {
+pub async fn get_dashboard_stats(pool: &PgPool, currency: &str) -> Result<DashboardStats> {
     let stats = sqlx::query!(
         r#"
         SELECT 
@@ -56,7 +69,6 @@ pub async fn get_dashboard_stats(pool: &PgPool) -> Result<DashboardStats> {
             (SELECT AVG(rating::float) FROM testimoni WHERE is_approved = true) as avg_rating,
             (SELECT COUNT(*) FROM savings_targets) as total_savings_targets,
             (SELECT COUNT(*) FROM savings_targets WHERE is_completed = true) as completed_targets,
-            (SELECT COALESCE(SUM(total_saved), 0) FROM user_statistics) as total_saved_amount,
             (SELECT COUNT(*) FROM activities) as total_activities,
             (SELECT COUNT(DISTINCT user_id) FROM activities WHERE created_at::date = CURRENT_DATE) as active_users_today
         "#
@@ -64,6 +76,8 @@ pub async fn get_dashboard_stats(pool: &PgPool) -> Result<DashboardStats> {
     .fetch_one(pool)
     .await?;
 
+    let saved = converted_savings_total(pool, currency, None).await?;
+
     Ok(DashboardStats {
         total_users: stats.total_users.unwrap_or(0),
         total_testimoni: stats.total_testimoni.unwrap_or(0),
@@ -72,9 +86,11 @@ pub async fn get_dashboard_stats(pool: &PgPool) -> Result<DashboardStats> {
         avg_rating: stats.avg_rating,
         total_savings_targets: stats.total_savings_targets.unwrap_or(0),
         completed_targets: stats.completed_targets.unwrap_or(0),
-        total_saved_amount: stats.total_saved_amount.and_then(|d| d.to_string().parse().ok()).unwrap_or(0.0),
+        total_saved_amount: saved.total,
         total_activities: stats.total_activities.unwrap_or(0),
         active_users_today: stats.active_users_today.unwrap_or(0),
+        currency: saved.currency,
+        unconverted_currencies: saved.unconverted_currencies,
     })
 }
 
@@ -90,7 +106,7 @@ pub async fn get_user_analytics(
             (SELECT created_at FROM users WHERE id = $1) as account_created,
             (SELECT COUNT(*) FROM savings_targets WHERE user_id = $1) as total_savings_targets,
             (SELECT COUNT(*) FROM savings_targets WHERE user_id = $1 AND is_completed = true) as completed_targets,
-            (SELECT COALESCE(total_saved, 0) FROM user_statistics WHERE user_id = $1) as total_saved,
+            (SELECT currency FROM users WHERE id = $1) as currency,
             (SELECT COALESCE(streak_days, 0) FROM user_statistics WHERE user_id = $1) as streak_days,
             (SELECT COALESCE(achievements_count, 0) FROM user_statistics WHERE user_id = $1) as achievements_count
         "#,
@@ -99,19 +115,24 @@ pub async fn get_user_analytics(
     .fetch_one(pool)
     .await?;
 
+    let currency = analytics.currency.unwrap_or_else(|| DEFAULT_CURRENCY.to_string());
+    let saved = converted_savings_total(pool, &currency, Some(user_id)).await?;
+
     Ok(UserAnalytics {
         user_testimoni_count: analytics.user_testimoni_count.unwrap_or(0),
         user_avg_rating: analytics.user_avg_rating,
-        account_created: analytics.account_created.unwrap_or_else(|| chrono::Utc::now()),
+        account_created: analytics.account_created.unwrap_or_else(chrono::Utc::now),
         total_savings_targets: analytics.total_savings_targets.unwrap_or(0),
         completed_targets: analytics.completed_targets.unwrap_or(0),
-        total_saved: analytics.total_saved.and_then(|d| d.to_string().parse().ok()).unwrap_or(0.0),
+        total_saved: saved.total,
         streak_days: analytics.streak_days.unwrap_or(0),
         achievements_count: analytics.achievements_count.unwrap_or(0),
+        currency: saved.currency,
+        unconverted_currencies: saved.unconverted_currencies,
     })
 }
 
-pub async fn get_trend_data(pool: &PgPool, days: i32) -> Result<Vec<TrendData>> {
+pub async fn get_trend_data(pool: &PgPool, days: i32, currency: &str) -> Result<Vec<TrendData>> {
     let trends = sqlx::query!(
         r#"
         WITH date_series AS (
@@ -134,10 +155,12 @@ pub async fn get_trend_data(pool: &PgPool, days: i32) -> Result<Vec<TrendData>>
             GROUP BY created_at::date
         ) u ON ds.date = u.date
         LEFT JOIN (
-            SELECT created_at::date as date, COALESCE(SUM(amount), 0) as savings_amount
-            FROM activities
-            WHERE activity_type = 'deposit' AND created_at::date >= CURRENT_DATE - INTERVAL '1 day' * $3
-            GROUP BY created_at::date
+            SELECT a.created_at::date as date,
+                   COALESCE(SUM(a.amount * exchange_rate(COALESCE(st.currency, $5), $5, a.created_at::date)), 0) as savings_amount
+            FROM activities a
+            LEFT JOIN savings_targets st ON st.id = a.savings_target_id
+            WHERE a.activity_type = 'deposit' AND a.created_at::date >= CURRENT_DATE - INTERVAL '1 day' * $3
+            GROUP BY a.created_at::date
         ) s ON ds.date = s.date
         LEFT JOIN (
             SELECT created_at::date as date, COUNT(*) as transactions_count
@@ -150,7 +173,8 @@ pub async fn get_trend_data(pool: &PgPool, days: i32) -> Result<Vec<TrendData>>
         days as f64,
         days as f64,
         days as f64,
-        days as f64
+        days as f64,
+        currency
     )
     .fetch_all(pool)
     .await?;
@@ -160,7 +184,7 @@ pub async fn get_trend_data(pool: &PgPool, days: i32) -> Result<Vec<TrendData>>
         .map(|row| TrendData {
             date: row.date.unwrap(),
             user_count: row.user_count.unwrap_or(0),
-            savings_amount: row.savings_amount.and_then(|d| d.to_string().parse().ok()).unwrap_or(0.0),
+            savings_amount: row.savings_amount.map(Money::from).unwrap_or_default(),
             transactions_count: row.transactions_count.unwrap_or(0),
         })
         .collect();
@@ -0,0 +1,292 @@
+use anyhow::Result;
+use bigdecimal::{BigDecimal, RoundingMode, ToPrimitive, Zero};
+use chrono::{Duration, FixedOffset, Months, NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::{
+    DepositHistory, ForecastQuery, ForecastStatus, RecurringFrequency, RequiredContribution, TargetForecast,
+    WhatIfForecast,
+};
+use crate::services::savings_service::get_savings_target_by_id;
+use crate::utils::money::{Money, SCALE};
+
+/// Proyeksi memakai arus ledger 90 hari terakhir
+const LOOKBACK_DAYS: i64 = 90;
+
+/// Perkiraan status dari tanggal selesai yang diproyeksikan terhadap target_date
+fn forecast_status(today: NaiveDate, target_date: Option<NaiveDate>, projected: Option<NaiveDate>) -> ForecastStatus {
+    let Some(deadline) = target_date else {
+        return ForecastStatus::NoDeadline;
+    };
+    let Some(projected) = projected else {
+        return ForecastStatus::Behind;
+    };
+    if projected <= deadline {
+        return ForecastStatus::OnTrack;
+    }
+
+    // Masih at risk kalau butuh paling lama 25% lebih dari sisa waktu
+    let days_left = (deadline - today).num_days();
+    if days_left > 0 && (projected - today).num_days() * 4 <= days_left * 5 {
+        ForecastStatus::AtRisk
+    } else {
+        ForecastStatus::Behind
+    }
+}
+
+/// Tanggal setoran ke-`n` (dihitung dari 0) kalau setoran pertama hari ini
+fn nth_occurrence(start: NaiveDate, frequency: RecurringFrequency, n: i64) -> Option<NaiveDate> {
+    match frequency {
+        RecurringFrequency::Daily => start.checked_add_signed(Duration::try_days(n)?),
+        RecurringFrequency::Weekly => start.checked_add_signed(Duration::try_weeks(n)?),
+        RecurringFrequency::Monthly => start.checked_add_months(Months::new(u32::try_from(n).ok()?)),
+    }
+}
+
+/// Jumlah setoran dari `start` sampai `deadline` (inklusif), setoran pertama di `start`
+fn occurrences_until(start: NaiveDate, deadline: NaiveDate, frequency: RecurringFrequency) -> i64 {
+    if deadline < start {
+        return 0;
+    }
+    let days = (deadline - start).num_days();
+    match frequency {
+        RecurringFrequency::Daily => days + 1,
+        RecurringFrequency::Weekly => days / 7 + 1,
+        RecurringFrequency::Monthly => {
+            // checked_add_months menjepit ke akhir bulan (31 Jan + 1 bulan = 28/29 Feb)
+            let mut count = 1;
+            while nth_occurrence(start, frequency, count).is_some_and(|date| date <= deadline) {
+                count += 1;
+            }
+            count
+        }
+    }
+}
+
+/// Nominal per bagian dibulatkan ke atas ke sen, supaya totalnya tidak kurang dari `amount`
+fn split_rounding_up(amount: &Money, parts: i64) -> Money {
+    let share = amount.as_decimal() / BigDecimal::from(parts.max(1));
+    Money::from(share.with_scale_round(SCALE, RoundingMode::Ceiling))
+}
+
+fn ceil_div(amount: &BigDecimal, per: &BigDecimal) -> Option<i64> {
+    if per <= &BigDecimal::zero() {
+        return None;
+    }
+    (amount / per).with_scale_round(0, RoundingMode::Ceiling).to_i64()
+}
+
+fn required_contribution(remaining: &Money, today: NaiveDate, deadline: NaiveDate) -> Option<RequiredContribution> {
+    if deadline < today {
+        return None;
+    }
+
+    Some(RequiredContribution {
+        daily: split_rounding_up(remaining, occurrences_until(today, deadline, RecurringFrequency::Daily)),
+        weekly: split_rounding_up(remaining, occurrences_until(today, deadline, RecurringFrequency::Weekly)),
+        monthly: split_rounding_up(remaining, occurrences_until(today, deadline, RecurringFrequency::Monthly)),
+    })
+}
+
+fn what_if_forecast(
+    remaining: &Money,
+    today: NaiveDate,
+    target_date: Option<NaiveDate>,
+    amount: Money,
+    frequency: RecurringFrequency,
+) -> WhatIfForecast {
+    let contributions_needed = ceil_div(remaining.as_decimal(), amount.as_decimal()).unwrap_or_default();
+    let projected_completion_date = if contributions_needed == 0 {
+        Some(today)
+    } else {
+        nth_occurrence(today, frequency, contributions_needed - 1)
+    };
+
+    let status = if contributions_needed == 0 {
+        ForecastStatus::Completed
+    } else {
+        forecast_status(today, target_date, projected_completion_date)
+    };
+
+    WhatIfForecast {
+        amount,
+        frequency,
+        contributions_needed,
+        projected_completion_date,
+        status,
+    }
+}
+
+/// Proyeksi target dari arus bersih ledger (semua anggota) selama 90 hari terakhir.
+/// None kalau target tidak ditemukan / user bukan anggota.
+pub async fn get_target_forecast(
+    pool: &PgPool,
+    target_id: Uuid,
+    user_id: Uuid,
+    query: &ForecastQuery,
+) -> Result<Option<TargetForecast>> {
+    if query.what_if_amount.as_ref().is_some_and(|amount| !amount.is_positive()) {
+        return Err(AppError::BadRequest("What-if amount must be greater than 0".to_string()).into());
+    }
+
+    let Some(target) = get_savings_target_by_id(pool, target_id, user_id).await? else {
+        return Ok(None);
+    };
+
+    // Hari dihitung di zona waktu Indonesia seperti streak
+    let jakarta = FixedOffset::east_opt(7 * 3600).unwrap();
+    let today = Utc::now().with_timezone(&jakarta).date_naive();
+    let lookback_start = today - Duration::days(LOOKBACK_DAYS - 1);
+
+    // Semua entry ledger ikut dihitung: setoran, tarik dana, transfer masuk/keluar, pembatalan dan
+    // penyesuaian, supaya target yang rutin dikuras tidak terlihat on track
+    let flows = sqlx::query!(
+        r#"
+        SELECT COALESCE(SUM(amount) FILTER (WHERE amount > 0), 0) as "total_in!: Money",
+               COALESCE(-SUM(amount) FILTER (WHERE amount < 0), 0) as "total_out!: Money",
+               COUNT(*) FILTER (WHERE amount > 0) as "count_in!",
+               MIN((created_at AT TIME ZONE 'Asia/Jakarta')::date) as first_entry,
+               MAX((created_at AT TIME ZONE 'Asia/Jakarta')::date) FILTER (WHERE amount > 0) as last_deposit
+        FROM transactions
+        WHERE savings_target_id = $1
+          AND (created_at AT TIME ZONE 'Asia/Jakarta')::date BETWEEN $2 AND $3
+        "#,
+        target_id,
+        lookback_start,
+        today
+    )
+    .fetch_one(pool)
+    .await?;
+
+    // Target yang baru dibuat tidak dihitung rata-ratanya selama 90 hari penuh
+    let created_on = target
+        .created_at
+        .map(|created_at| created_at.with_timezone(&jakarta).date_naive());
+    let window_start = [created_on, flows.first_entry]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(today)
+        .clamp(lookback_start, today);
+    let window_days = (today - window_start).num_days() + 1;
+
+    let net_flow = flows.total_in.clone() - flows.total_out.clone();
+    let rate = net_flow.as_decimal() / BigDecimal::from(window_days);
+    let history = DepositHistory {
+        window_start,
+        window_days,
+        deposit_count: flows.count_in,
+        average_deposit: if flows.count_in > 0 {
+            Money::from(flows.total_in.as_decimal() / BigDecimal::from(flows.count_in))
+        } else {
+            Money::zero()
+        },
+        daily_rate: Money::from(rate.clone()),
+        total_deposited: flows.total_in,
+        total_withdrawn: flows.total_out,
+        net_flow,
+        last_deposit_date: flows.last_deposit,
+    };
+
+    let current_amount = target.current_amount.clone().unwrap_or_default();
+    let remaining = (target.target_amount.clone() - current_amount.clone()).max(Money::zero());
+    let completed = remaining.is_zero();
+
+    let projected_completion_date = if completed {
+        Some(today)
+    } else {
+        ceil_div(remaining.as_decimal(), &rate)
+            .and_then(Duration::try_days)
+            .and_then(|days| today.checked_add_signed(days))
+    };
+
+    let status = if completed {
+        ForecastStatus::Completed
+    } else {
+        forecast_status(today, target.target_date, projected_completion_date)
+    };
+
+    let required = match target.target_date {
+        Some(deadline) if !completed => required_contribution(&remaining, today, deadline),
+        _ => None,
+    };
+
+    let what_if = query.what_if_amount.clone().map(|amount| {
+        let frequency = query.what_if_frequency.unwrap_or(RecurringFrequency::Monthly);
+        what_if_forecast(&remaining, today, target.target_date, amount, frequency)
+    });
+
+    Ok(Some(TargetForecast {
+        target_id: target.id,
+        currency: target.currency,
+        percentage: current_amount.percent_of(&target.target_amount),
+        target_amount: target.target_amount,
+        current_amount,
+        remaining,
+        target_date: target.target_date,
+        days_remaining: target.target_date.map(|date| (date - today).num_days()),
+        history,
+        projected_completion_date,
+        required,
+        status,
+        what_if,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn money(value: &str) -> Money {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn status_compares_projection_with_deadline() {
+        let today = date(2025, 1, 1);
+        let deadline = Some(date(2025, 2, 10));
+
+        assert_eq!(forecast_status(today, None, Some(today)), ForecastStatus::NoDeadline);
+        assert_eq!(forecast_status(today, deadline, None), ForecastStatus::Behind);
+        assert_eq!(forecast_status(today, deadline, Some(date(2025, 2, 9))), ForecastStatus::OnTrack);
+        // 40 hari tersisa, 50 hari masih at risk, 51 hari sudah behind
+        assert_eq!(forecast_status(today, deadline, Some(date(2025, 2, 20))), ForecastStatus::AtRisk);
+        assert_eq!(forecast_status(today, deadline, Some(date(2025, 2, 21))), ForecastStatus::Behind);
+        assert_eq!(
+            forecast_status(today, Some(date(2024, 12, 31)), Some(date(2025, 1, 2))),
+            ForecastStatus::Behind
+        );
+    }
+
+    #[test]
+    fn required_contribution_counts_today_as_first_deposit() {
+        let today = date(2025, 1, 31);
+        let required = required_contribution(&money("1000"), today, date(2025, 3, 31)).unwrap();
+
+        // 59 hari lagi: 60 setoran harian (termasuk hari ini), 9 mingguan, 3 bulanan (31 Jan, 28 Feb, 31 Mar)
+        assert_eq!(required.daily, money("16.67"));
+        assert_eq!(required.weekly, money("111.12"));
+        assert_eq!(required.monthly, money("333.34"));
+        assert!(required_contribution(&money("1000"), today, date(2025, 1, 30)).is_none());
+    }
+
+    #[test]
+    fn what_if_projects_completion_from_recurring_amount() {
+        let today = date(2025, 1, 1);
+        let forecast = what_if_forecast(&money("1000"), today, Some(date(2025, 3, 1)), money("300"), RecurringFrequency::Monthly);
+
+        assert_eq!(forecast.contributions_needed, 4);
+        assert_eq!(forecast.projected_completion_date, Some(date(2025, 4, 1)));
+        assert_eq!(forecast.status, ForecastStatus::Behind);
+
+        let weekly = what_if_forecast(&money("1000"), today, Some(date(2025, 3, 1)), money("250"), RecurringFrequency::Weekly);
+        assert_eq!(weekly.projected_completion_date, Some(date(2025, 1, 22)));
+        assert_eq!(weekly.status, ForecastStatus::OnTrack);
+    }
+}
@@ -0,0 +1,115 @@
+use sqlx::PgConnection;
+use uuid::Uuid;
+use anyhow::Result;
+
+/// Key yang lebih lama dari ini boleh dipakai ulang untuk request baru
+const IDEMPOTENCY_KEY_TTL_HOURS: i32 = 24;
+
+pub enum IdempotencyStatus {
+    /// Key baru, request boleh diproses
+    Started,
+    /// Request sama sudah selesai, kembalikan response yang tersimpan
+    Completed { status: u16, body: serde_json::Value },
+    /// Request pertama dengan key ini masih diproses
+    InProgress,
+    /// Key sudah dipakai untuk request yang berbeda
+    Mismatch,
+}
+
+/// Dipanggil di dalam transaksi request: key baru ikut ter-rollback kalau request gagal,
+/// dan request lain dengan key yang sama menunggu di INSERT sampai transaksi ini selesai.
+pub async fn start_request(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    key: &str,
+    method: &str,
+    path: &str,
+    request_hash: &str,
+) -> Result<IdempotencyStatus> {
+    sqlx::query!(
+        r#"
+        DELETE FROM idempotency_keys
+        WHERE user_id = $1 AND created_at < NOW() - make_interval(hours => $2)
+        "#,
+        user_id,
+        IDEMPOTENCY_KEY_TTL_HOURS
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    let inserted = sqlx::query_scalar!(
+        r#"
+        INSERT INTO idempotency_keys (user_id, idempotency_key, request_method, request_path, request_hash)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (user_id, idempotency_key) DO NOTHING
+        RETURNING id
+        "#,
+        user_id,
+        key,
+        method,
+        path,
+        request_hash
+    )
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    if inserted.is_some() {
+        return Ok(IdempotencyStatus::Started);
+    }
+
+    let existing = sqlx::query!(
+        r#"
+        SELECT request_method, request_path, request_hash, response_status, response_body
+        FROM idempotency_keys
+        WHERE user_id = $1 AND idempotency_key = $2
+        "#,
+        user_id,
+        key
+    )
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    // Baris bisa saja terhapus di antara INSERT dan SELECT (kedaluwarsa dan dibersihkan request lain)
+    let Some(existing) = existing else {
+        return Ok(IdempotencyStatus::InProgress);
+    };
+
+    if existing.request_method != method
+        || existing.request_path != path
+        || existing.request_hash != request_hash
+    {
+        return Ok(IdempotencyStatus::Mismatch);
+    }
+
+    match (existing.response_status, existing.response_body) {
+        (Some(status), Some(body)) => Ok(IdempotencyStatus::Completed {
+            status: status as u16,
+            body,
+        }),
+        _ => Ok(IdempotencyStatus::InProgress),
+    }
+}
+
+pub async fn complete_request(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    key: &str,
+    status: u16,
+    body: &serde_json::Value,
+) -> Result<()> {
+    sqlx::query!(
+        r#"
+        UPDATE idempotency_keys
+        SET response_status = $3, response_body = $4, completed_at = NOW()
+        WHERE user_id = $1 AND idempotency_key = $2
+        "#,
+        user_id,
+        key,
+        status as i16,
+        body
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
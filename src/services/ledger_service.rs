@@ -0,0 +1,238 @@
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+use anyhow::Result;
+
+use crate::models::{
+    LedgerEntry, MemberRole, TransactionType, ReconciliationReport,
+    TargetBalanceMismatch
+};
+use crate::errors::AppError;
+use crate::services::sharing_service::authorize_member;
+use crate::utils::money::Money;
+
+/// Data untuk satu entry baru di ledger
+pub struct NewTransaction {
+    pub user_id: Uuid,
+    pub savings_target_id: Uuid,
+    pub transaction_type: TransactionType,
+    /// Positif = masuk ke target, negatif = keluar dari target
+    pub amount: Money,
+    pub description: Option<String>,
+    pub group_id: Option<Uuid>,
+}
+
+/// Catat transaksi ke ledger. Saldo target ikut ter-update
+/// lewat trigger, jadi harus dipanggil di dalam transaksi database yang sama
+/// dengan pengecekan saldo/kepemilikan.
+pub async fn record_transaction(
+    conn: &mut PgConnection,
+    transaction: NewTransaction,
+) -> Result<LedgerEntry> {
+    if transaction.amount.is_zero() {
+        return Err(AppError::BadRequest("Transaction amount cannot be zero".to_string()).into());
+    }
+
+    let entry = sqlx::query_as!(
+        LedgerEntry,
+        r#"
+        INSERT INTO transactions (user_id, savings_target_id, transaction_type, amount, description, group_id)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, user_id, savings_target_id, transaction_type, amount, description,
+                  group_id, reverses_transaction_id, created_at
+        "#,
+        transaction.user_id,
+        transaction.savings_target_id,
+        transaction.transaction_type.as_str(),
+        transaction.amount.as_decimal(),
+        transaction.description,
+        transaction.group_id
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    Ok(entry)
+}
+
+pub async fn get_target_transactions(
+    pool: &PgPool,
+    target_id: Uuid,
+    user_id: Uuid,
+) -> Result<Vec<LedgerEntry>> {
+    // Semua anggota (termasuk viewer) boleh melihat ledger target
+    if authorize_member(pool, target_id, user_id, MemberRole::Viewer).await?.is_none() {
+        return Err(AppError::NotFound("Savings target not found".to_string()).into());
+    }
+
+    let entries = sqlx::query_as!(
+        LedgerEntry,
+        r#"
+        SELECT id, user_id, savings_target_id, transaction_type, amount, description,
+               group_id, reverses_transaction_id, created_at
+        FROM transactions
+        WHERE savings_target_id = $1
+        ORDER BY created_at DESC, id DESC
+        "#,
+        target_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(entries)
+}
+
+/// Batalkan transaksi dengan menulis entry 'reversal' berlawanan arah.
+/// Kalau transaksi bagian dari grup (misal transfer), semua entry di grup ikut dibatalkan.
+/// Hanya transaksi milik user sendiri, dan user harus masih anggota targetnya: membatalkan setoran
+/// mengeluarkan uang dari target sehingga butuh role owner seperti withdraw/transfer, sedangkan
+/// membatalkan uang keluar cukup contributor.
+pub async fn reverse_transaction(
+    pool: &PgPool,
+    transaction_id: Uuid,
+    user_id: Uuid,
+) -> Result<Vec<LedgerEntry>> {
+    let mut tx = pool.begin().await?;
+
+    let original = sqlx::query_as!(
+        LedgerEntry,
+        r#"
+        SELECT id, user_id, savings_target_id, transaction_type, amount, description,
+               group_id, reverses_transaction_id, created_at
+        FROM transactions
+        WHERE id = $1 AND user_id = $2
+        "#,
+        transaction_id,
+        user_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Transaction not found".to_string()))?;
+
+    if original.transaction_type == TransactionType::Reversal.as_str() {
+        return Err(AppError::Unprocessable("A reversal cannot be reversed".to_string()).into());
+    }
+
+    let entries = match original.group_id {
+        Some(group_id) => sqlx::query_as!(
+            LedgerEntry,
+            r#"
+            SELECT id, user_id, savings_target_id, transaction_type, amount, description,
+                   group_id, reverses_transaction_id, created_at
+            FROM transactions
+            WHERE group_id = $1 AND user_id = $2 AND transaction_type <> 'reversal'
+            ORDER BY created_at, id
+            "#,
+            group_id,
+            user_id
+        )
+        .fetch_all(&mut *tx)
+        .await?,
+        None => vec![original],
+    };
+
+    for entry in &entries {
+        let required = if entry.amount.is_positive() { MemberRole::Owner } else { MemberRole::Contributor };
+        if authorize_member(&mut *tx, entry.savings_target_id, user_id, required).await?.is_none() {
+            return Err(AppError::NotFound("Transaction not found".to_string()).into());
+        }
+    }
+
+    let ids: Vec<Uuid> = entries.iter().map(|entry| entry.id).collect();
+    let already_reversed = sqlx::query_scalar!(
+        "SELECT EXISTS(SELECT 1 FROM transactions WHERE reverses_transaction_id = ANY($1))",
+        &ids
+    )
+    .fetch_one(&mut *tx)
+    .await?
+    .unwrap_or(false);
+
+    if already_reversed {
+        return Err(AppError::Conflict("Transaction has already been reversed".to_string()).into());
+    }
+
+    let reversal_group = if entries.len() > 1 { Some(Uuid::new_v4()) } else { None };
+    let mut reversals = Vec::with_capacity(entries.len());
+
+    for entry in &entries {
+        let amount = -entry.amount.clone();
+        let reversal = sqlx::query_as!(
+            LedgerEntry,
+            r#"
+            INSERT INTO transactions (user_id, savings_target_id, transaction_type, amount, description,
+                                      group_id, reverses_transaction_id)
+            VALUES ($1, $2, 'reversal', $3, $4, $5, $6)
+            RETURNING id, user_id, savings_target_id, transaction_type, amount, description,
+                      group_id, reverses_transaction_id, created_at
+            "#,
+            entry.user_id,
+            entry.savings_target_id,
+            amount.as_decimal(),
+            format!("Pembatalan transaksi {}", entry.transaction_type),
+            reversal_group,
+            entry.id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        reversals.push(reversal);
+    }
+
+    // Pembatalan tidak boleh membuat saldo target minus
+    let target_ids: Vec<Uuid> = entries.iter().map(|entry| entry.savings_target_id).collect();
+    let overdrawn = sqlx::query_scalar!(
+        "SELECT EXISTS(SELECT 1 FROM savings_targets WHERE id = ANY($1) AND current_amount < 0)",
+        &target_ids
+    )
+    .fetch_one(&mut *tx)
+    .await?
+    .unwrap_or(false);
+
+    if overdrawn {
+        return Err(AppError::Unprocessable("Insufficient balance to reverse this transaction".to_string()).into());
+    }
+
+    tx.commit().await?;
+
+    Ok(reversals)
+}
+
+/// Bandingkan saldo cache (savings_targets.current_amount) dengan hasil penjumlahan ledger. user_id None = cek semua user (admin).
+pub async fn reconcile(
+    pool: &PgPool,
+    user_id: Option<Uuid>,
+) -> Result<ReconciliationReport> {
+    let targets = sqlx::query!(
+        r#"
+        SELECT st.id, st.user_id, st.name,
+               COALESCE(st.current_amount, 0) as "cached_balance!",
+               COALESCE(SUM(t.amount), 0) as "ledger_balance!"
+        FROM savings_targets st
+        LEFT JOIN transactions t ON t.savings_target_id = st.id
+        WHERE $1::uuid IS NULL OR st.user_id = $1
+        GROUP BY st.id
+        ORDER BY st.created_at
+        "#,
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let checked_targets = targets.len();
+
+    let target_mismatches: Vec<TargetBalanceMismatch> = targets
+        .into_iter()
+        .filter(|row| row.cached_balance != row.ledger_balance)
+        .map(|row| TargetBalanceMismatch {
+            savings_target_id: row.id,
+            user_id: row.user_id,
+            name: row.name,
+            cached_balance: row.cached_balance.into(),
+            ledger_balance: row.ledger_balance.into(),
+        })
+        .collect();
+
+    Ok(ReconciliationReport {
+        checked_targets,
+        is_consistent: target_mismatches.is_empty(),
+        target_mismatches,
+    })
+}
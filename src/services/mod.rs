@@ -7,3 +7,15 @@ pub mod savings_service;
 pub mod activity_service;
 pub mod statistics_service;
 pub mod reminder_service;
+pub mod session_service;
+pub mod ledger_service;
+pub mod idempotency_service;
+pub mod recurring_deposit_service;
+pub mod notification_service;
+pub mod notification_hub;
+pub mod search_service;
+pub mod currency_service;
+pub mod budget_service;
+pub mod cashflow_service;
+pub mod sharing_service;
+pub mod forecast_service;
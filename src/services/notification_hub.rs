@@ -0,0 +1,85 @@
+use std::sync::Arc;
+use std::time::Duration;
+use serde::Deserialize;
+use sqlx::postgres::PgListener;
+use sqlx::PgPool;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::models::Notification;
+
+const CHANNEL: &str = "notifications";
+const BUFFER_SIZE: usize = 256;
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Payload pg_notify dari trigger notify_new_notification
+#[derive(Debug, Deserialize)]
+struct NotificationSignal {
+    id: i32,
+    user_id: Uuid,
+}
+
+/// Teruskan notifikasi baru dari Postgres (LISTEN/NOTIFY) ke semua stream SSE di instance ini.
+/// Karena lewat database, notifikasi yang dibuat instance lain atau worker juga ikut terkirim.
+pub struct NotificationHub {
+    sender: broadcast::Sender<Notification>,
+}
+
+impl NotificationHub {
+    pub fn start(pool: PgPool) -> Arc<Self> {
+        let (sender, _) = broadcast::channel(BUFFER_SIZE);
+        let hub = Arc::new(NotificationHub { sender });
+
+        let listener_hub = hub.clone();
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = listener_hub.listen(&pool).await {
+                    eprintln!("❌ Listener notifikasi terputus: {}", e);
+                }
+                tokio::time::sleep(RECONNECT_DELAY).await;
+            }
+        });
+
+        hub
+    }
+
+    /// Receiver berisi notifikasi semua user, filter per user dilakukan oleh pemanggil
+    pub fn subscribe(&self) -> broadcast::Receiver<Notification> {
+        self.sender.subscribe()
+    }
+
+    async fn listen(&self, pool: &PgPool) -> anyhow::Result<()> {
+        let mut listener = PgListener::connect_with(pool).await?;
+        listener.listen(CHANNEL).await?;
+
+        loop {
+            let message = listener.recv().await?;
+
+            // Tidak ada client yang terhubung, tidak perlu ambil isi notifikasi
+            if self.sender.receiver_count() == 0 {
+                continue;
+            }
+
+            let signal: NotificationSignal = match serde_json::from_str(message.payload()) {
+                Ok(signal) => signal,
+                Err(e) => {
+                    eprintln!("❌ Payload notifikasi tidak valid: {}", e);
+                    continue;
+                }
+            };
+
+            let notification = sqlx::query_as!(Notification,
+                r#"SELECT id, user_id, type, message, read, timestamp FROM notifications WHERE id = $1 AND user_id = $2"#,
+                signal.id,
+                signal.user_id
+            )
+            .fetch_optional(pool)
+            .await?;
+
+            if let Some(notification) = notification {
+                // Error hanya berarti semua client sudah disconnect
+                let _ = self.sender.send(notification);
+            }
+        }
+    }
+}
@@ -1,36 +1,173 @@
-use crate::models::Notification;
-use sqlx::PgPool;
+use anyhow::Result;
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
 
-pub async fn get_notifications(pool: &PgPool, user_id: uuid::Uuid) -> Result<Vec<Notification>, sqlx::Error> {
-    let rows = sqlx::query_as!(Notification,
-        r#"SELECT id, user_id, type, message, read, timestamp FROM notifications WHERE user_id = $1 ORDER BY timestamp DESC"#,
-        user_id
+use crate::models::{Notification, NotificationPage, NotificationResponse, NotificationType};
+use crate::utils::currency::format_money;
+use crate::utils::money::Money;
+use crate::utils::pagination::page_offset;
+
+pub const DEFAULT_PER_PAGE: i64 = 20;
+
+pub async fn create_notification<'e, E: PgExecutor<'e>>(
+    executor: E,
+    user_id: Uuid,
+    notif_type: NotificationType,
+    message: &str,
+) -> Result<Notification> {
+    let notification = sqlx::query_as!(Notification,
+        r#"INSERT INTO notifications (user_id, type, message) VALUES ($1, $2, $3)
+           RETURNING id, user_id, type, message, read, timestamp"#,
+        user_id,
+        notif_type.as_str(),
+        message
+    )
+    .fetch_one(executor)
+    .await?;
+    Ok(notification)
+}
+
+// Notifikasi dari service lain. Dipanggil di transaksi yang sama dengan perubahan datanya,
+// jadi notifikasi ikut batal kalau transaksinya gagal.
+
+pub async fn notify_deposit<'e, E: PgExecutor<'e>>(
+    executor: E,
+    user_id: Uuid,
+    target_name: &str,
+    amount: &Money,
+    currency: &str,
+) -> Result<Notification> {
+    let message = format!("Setoran {} ke target \"{}\" berhasil.", format_money(amount.as_decimal(), currency), target_name);
+    create_notification(executor, user_id, NotificationType::Deposit, &message).await
+}
+
+pub async fn notify_target_completed<'e, E: PgExecutor<'e>>(
+    executor: E,
+    user_id: Uuid,
+    target_name: &str,
+) -> Result<Notification> {
+    let message = format!("Selamat! Target \"{}\" sudah tercapai 🎉", target_name);
+    create_notification(executor, user_id, NotificationType::TargetCompleted, &message).await
+}
+
+pub async fn notify_achievement<'e, E: PgExecutor<'e>>(
+    executor: E,
+    user_id: Uuid,
+    achievement_title: &str,
+) -> Result<Notification> {
+    let message = format!("Achievement baru: {}", achievement_title);
+    create_notification(executor, user_id, NotificationType::Achievement, &message).await
+}
+
+pub async fn notify_budget_exceeded<'e, E: PgExecutor<'e>>(
+    executor: E,
+    user_id: Uuid,
+    category_name: &str,
+    spent: &Money,
+    limit: &Money,
+    currency: &str,
+) -> Result<Notification> {
+    let message = format!(
+        "Pengeluaran kategori \"{}\" bulan ini {} sudah melewati budget {}.",
+        category_name,
+        format_money(spent.as_decimal(), currency),
+        format_money(limit.as_decimal(), currency)
+    );
+    create_notification(executor, user_id, NotificationType::BudgetExceeded, &message).await
+}
+
+pub async fn get_notifications(
+    pool: &PgPool,
+    user_id: Uuid,
+    unread_only: bool,
+    page: i64,
+    per_page: i64,
+) -> Result<NotificationPage> {
+    let offset = page_offset(page, per_page);
+
+    let notifications = sqlx::query_as!(Notification,
+        r#"
+        SELECT id, user_id, type, message, read, timestamp
+        FROM notifications
+        WHERE user_id = $1 AND (NOT $2 OR NOT read)
+        ORDER BY timestamp DESC, id DESC
+        LIMIT $3 OFFSET $4
+        "#,
+        user_id,
+        unread_only,
+        per_page,
+        offset
     )
     .fetch_all(pool)
     .await?;
-    Ok(rows)
+
+    let counts = sqlx::query!(
+        r#"
+        SELECT COUNT(*) as "total!", COUNT(*) FILTER (WHERE NOT read) as "unread!"
+        FROM notifications
+        WHERE user_id = $1
+        "#,
+        user_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(NotificationPage {
+        notifications: notifications.into_iter().map(NotificationResponse::from).collect(),
+        page,
+        per_page,
+        total: if unread_only { counts.unread } else { counts.total },
+        unread_count: counts.unread,
+    })
 }
 
-pub async fn add_notification(pool: &PgPool, notif: &Notification) -> Result<(), sqlx::Error> {
-    sqlx::query!(
-        "INSERT INTO notifications (user_id, type, message, read, timestamp) VALUES ($1, $2, $3, $4, $5)",
-        notif.user_id,
-        notif.r#type,
-        notif.message,
-        notif.read,
-        notif.timestamp
+pub async fn get_unread_count(pool: &PgPool, user_id: Uuid) -> Result<i64> {
+    let count = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!" FROM notifications WHERE user_id = $1 AND NOT read"#,
+        user_id
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(count)
+}
+
+pub async fn mark_as_read(
+    pool: &PgPool,
+    notif_id: i32,
+    user_id: Uuid,
+) -> Result<Option<NotificationResponse>> {
+    let notification = sqlx::query_as!(Notification,
+        r#"
+        UPDATE notifications SET read = true
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, type, message, read, timestamp
+        "#,
+        notif_id,
+        user_id
+    )
+    .fetch_optional(pool)
+    .await?;
+    Ok(notification.map(NotificationResponse::from))
+}
+
+/// Return jumlah notifikasi yang baru ditandai dibaca
+pub async fn mark_all_as_read(pool: &PgPool, user_id: Uuid) -> Result<u64> {
+    let result = sqlx::query!(
+        "UPDATE notifications SET read = true WHERE user_id = $1 AND NOT read",
+        user_id
     )
     .execute(pool)
     .await?;
-    Ok(())
+    Ok(result.rows_affected())
 }
 
-pub async fn mark_as_read(pool: &PgPool, notif_id: uuid::Uuid) -> Result<(), sqlx::Error> {
-    sqlx::query!(
-        "UPDATE notifications SET read = true WHERE id = $1",
-        notif_id
+pub async fn delete_notification(pool: &PgPool, notif_id: i32, user_id: Uuid) -> Result<bool> {
+    let result = sqlx::query!(
+        "DELETE FROM notifications WHERE id = $1 AND user_id = $2",
+        notif_id,
+        user_id
     )
     .execute(pool)
     .await?;
-    Ok(())
+    Ok(result.rows_affected() > 0)
 }
@@ -1,33 +1,66 @@
 use sqlx::PgPool;
 use uuid::Uuid;
 use anyhow::{Result, anyhow};
+use bcrypt::{hash, verify, DEFAULT_COST};
 use chrono::{Utc, Duration};
 
+use crate::config::Config;
+use crate::mailer::{templates, Mailer};
 use crate::models::{User, ForgotPasswordRequest, ResetPasswordRequest};
-use crate::utils::jwt::generate_jwt_token;
+use crate::services::session_service::revoke_all_sessions;
+use crate::utils::token::{generate_token, hash_token};
+use crate::errors::AppError;
+
+// Reset token berlaku 1 jam sejak dibuat
+const RESET_TOKEN_TTL_MINUTES: i64 = 60;
 
 pub async fn forgot_password(
     pool: &PgPool,
+    mailer: &dyn Mailer,
+    config: &Config,
     request: &ForgotPasswordRequest,
-) -> Result<String> {
+) -> Result<()> {
     // Check if user exists
     let user = sqlx::query_as::<_, User>(
-        "SELECT * FROM users WHERE email = $1"
+        "SELECT * FROM users WHERE LOWER(email) = LOWER($1)"
     )
-    .bind(&request.email)
+    .bind(request.email.trim())
     .fetch_optional(pool)
     .await?
-    .ok_or_else(|| anyhow!("User not found"))?;
-
-    // Generate reset token (bisa juga pakai JWT atau random string)
-    let reset_token = generate_jwt_token(user.id, &user.email, user.is_admin)?;
-    
-    // Dalam implementasi real, Anda akan:
-    // 1. Simpan token ke database dengan expiry time
-    // 2. Kirim email dengan link reset password
-    
-    // Untuk demo, kita return token langsung
-    Ok(reset_token)
+    .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+    // Generate random token, yang disimpan ke database hanya hash-nya
+    let reset_token = generate_token();
+    let expires_at = Utc::now() + Duration::minutes(RESET_TOKEN_TTL_MINUTES);
+
+    sqlx::query!(
+        r#"
+        INSERT INTO password_reset_tokens (user_id, token_hash, expires_at)
+        VALUES ($1, $2, $3)
+        "#,
+        user.id,
+        hash_token(&reset_token),
+        expires_at
+    )
+    .execute(pool)
+    .await?;
+
+    // Kirim link reset ke email user
+    let reset_link = format!(
+        "{}/reset-password?token={}",
+        config.frontend_url.trim_end_matches('/'),
+        reset_token
+    );
+    let message = templates::password_reset(
+        config.mail.default_locale,
+        &user.email,
+        &user.full_name,
+        &reset_link,
+        RESET_TOKEN_TTL_MINUTES,
+    );
+    mailer.send(message).await?;
+
+    Ok(())
 }
 
 pub async fn reset_password(
@@ -36,31 +69,59 @@ pub async fn reset_password(
 ) -> Result<String> {
     // Validasi password confirmation
     if request.new_password != request.confirm_password {
-        return Err(anyhow!("Passwords do not match"));
+        return Err(AppError::BadRequest("Passwords do not match".to_string()).into());
     }
 
-    // Dalam implementasi real, Anda akan:
-    // 1. Validate reset token dari database
-    // 2. Check expiry time
-    // 3. Decode user_id dari token
-    
-    // Untuk demo, kita decode token JWT
-    let claims = crate::utils::jwt::validate_jwt_token(&request.reset_token)?;
-    
+    let mut tx = pool.begin().await?;
+
+    // Lock token supaya tidak bisa dipakai dua kali secara bersamaan
+    let token = sqlx::query!(
+        r#"
+        SELECT id, user_id
+        FROM password_reset_tokens
+        WHERE token_hash = $1 AND used_at IS NULL AND expires_at > NOW()
+        FOR UPDATE
+        "#,
+        hash_token(&request.reset_token)
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::BadRequest("Invalid or expired reset token".to_string()))?;
+
+    let password_hash = hash(&request.new_password, DEFAULT_COST)
+        .map_err(|_| anyhow!("Failed to hash password"))?;
+
     // Update password user
     let updated_rows = sqlx::query!(
-        "UPDATE users SET password_hash = $1, updated_at = NOW() WHERE id = $2",
-        &request.new_password, // Plain password untuk demo
-        claims.user_id
+        "UPDATE users SET password_hash = $1, password_changed_at = NOW(), updated_at = NOW() WHERE id = $2",
+        password_hash,
+        token.user_id
     )
-    .execute(pool)
+    .execute(&mut *tx)
     .await?
     .rows_affected();
 
     if updated_rows == 0 {
-        return Err(anyhow!("User not found or password reset failed"));
+        return Err(AppError::NotFound("User not found or password reset failed".to_string()).into());
     }
 
+    // Tandai token ini terpakai dan invalidate semua reset token lain milik user
+    sqlx::query!(
+        r#"
+        UPDATE password_reset_tokens
+        SET used_at = NOW()
+        WHERE user_id = $1 AND used_at IS NULL
+        "#,
+        token.user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // Logout dari semua device
+    revoke_all_sessions(&mut *tx, token.user_id).await?;
+
+    tx.commit().await?;
+
     Ok("Password reset successfully".to_string())
 }
 
@@ -77,21 +138,33 @@ pub async fn change_password(
     .bind(user_id)
     .fetch_optional(pool)
     .await?
-    .ok_or_else(|| anyhow!("User not found"))?;
+    .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
-    // Verify old password
-    if old_password != user.password_hash {
-        return Err(anyhow!("Current password is incorrect"));
+    // Verify old password dengan bcrypt
+    if !verify(old_password, &user.password_hash)
+        .map_err(|_| anyhow!("Password verification failed"))?
+    {
+        return Err(AppError::BadRequest("Current password is incorrect".to_string()).into());
     }
 
+    let password_hash = hash(new_password, DEFAULT_COST)
+        .map_err(|_| anyhow!("Failed to hash password"))?;
+
+    let mut tx = pool.begin().await?;
+
     // Update password
     sqlx::query!(
-        "UPDATE users SET password_hash = $1, updated_at = NOW() WHERE id = $2",
-        new_password,
+        "UPDATE users SET password_hash = $1, password_changed_at = NOW(), updated_at = NOW() WHERE id = $2",
+        password_hash,
         user_id
     )
-    .execute(pool)
+    .execute(&mut *tx)
     .await?;
 
-    Ok("Password changed successfully".to_string())
+    // Semua token lama (termasuk session ini) jadi tidak valid, user harus login ulang
+    revoke_all_sessions(&mut *tx, user_id).await?;
+
+    tx.commit().await?;
+
+    Ok("Password changed successfully, please login again".to_string())
 }
@@ -0,0 +1,494 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+use anyhow::{Result, anyhow};
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+
+use crate::models::{RecurringDeposit, RecurringFrequency, CreateRecurringDepositRequest, MemberRole};
+use crate::services::savings_service::deposit_in_transaction;
+use crate::services::sharing_service::authorize_member;
+use crate::errors::AppError;
+use crate::utils::currency::check_minor_units;
+use crate::utils::money::Money;
+
+/// Batas jumlah rule yang diproses worker per putaran
+const DUE_BATCH_SIZE: i64 = 100;
+
+/// Rule di-pause otomatis setelah gagal sebanyak ini berturut-turut
+const MAX_CONSECUTIVE_FAILURES: i32 = 5;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PreviewOccurrence {
+    pub date: NaiveDate,
+    pub amount: Money,
+}
+
+/// Jadwal setoran berulang, dipakai untuk menghitung tanggal setoran berikutnya
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    pub frequency: RecurringFrequency,
+    pub day_of_month: Option<u32>,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+}
+
+impl Schedule {
+    pub fn from_rule(rule: &RecurringDeposit) -> Result<Self> {
+        let frequency = RecurringFrequency::from_name(&rule.frequency)
+            .ok_or_else(|| anyhow!("Unknown frequency '{}'", rule.frequency))?;
+
+        Ok(Self {
+            frequency,
+            day_of_month: rule.day_of_month.map(|day| day as u32),
+            start_date: rule.start_date,
+            end_date: rule.end_date,
+        })
+    }
+
+    pub fn from_request(req: &CreateRecurringDepositRequest) -> Result<Self> {
+        if let Some(end_date) = req.end_date {
+            if end_date < req.start_date {
+                return Err(AppError::BadRequest("End date must be on or after start date".to_string()).into());
+            }
+        }
+
+        if req.day_of_month.is_some() && req.frequency != RecurringFrequency::Monthly {
+            return Err(AppError::BadRequest("Day of month is only allowed for monthly frequency".to_string()).into());
+        }
+
+        Ok(Self {
+            frequency: req.frequency,
+            day_of_month: req.day_of_month.map(|day| day as u32),
+            start_date: req.start_date,
+            end_date: req.end_date,
+        })
+    }
+
+    /// Tanggal setoran pertama yang jatuh pada atau setelah `date` (belum dicek end_date)
+    pub fn first_on_or_after(&self, date: NaiveDate) -> NaiveDate {
+        let date = date.max(self.start_date);
+
+        match self.frequency {
+            RecurringFrequency::Daily => date,
+            RecurringFrequency::Weekly => {
+                // Hari dalam minggu mengikuti start_date
+                let offset = (date - self.start_date).num_days().rem_euclid(7);
+                if offset == 0 {
+                    date
+                } else {
+                    date + Duration::days(7 - offset)
+                }
+            }
+            RecurringFrequency::Monthly => {
+                let day = self.day_of_month.unwrap_or_else(|| self.start_date.day());
+                let candidate = day_in_month(date.year(), date.month(), day);
+                if candidate >= date {
+                    candidate
+                } else {
+                    let (year, month) = next_month(date.year(), date.month());
+                    day_in_month(year, month, day)
+                }
+            }
+        }
+    }
+
+    pub fn next_after(&self, date: NaiveDate) -> NaiveDate {
+        self.first_on_or_after(date + Duration::days(1))
+    }
+
+    pub fn is_active_on(&self, date: NaiveDate) -> bool {
+        self.end_date.is_none_or(|end_date| date <= end_date)
+    }
+
+    /// N tanggal setoran berikutnya mulai dari `from`, berhenti di end_date
+    pub fn occurrences(&self, from: NaiveDate, count: usize) -> Vec<NaiveDate> {
+        let mut dates = Vec::with_capacity(count);
+        let mut date = self.first_on_or_after(from);
+
+        while dates.len() < count && self.is_active_on(date) {
+            dates.push(date);
+            date = self.next_after(date);
+        }
+
+        dates
+    }
+}
+
+fn next_month(year: i32, month: u32) -> (i32, u32) {
+    if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    }
+}
+
+/// Tanggal `day` di bulan tersebut, dibatasi ke tanggal terakhir bulan (misal 31 -> 28 Feb)
+fn day_in_month(year: i32, month: u32, day: u32) -> NaiveDate {
+    let (next_year, next_month) = next_month(year, month);
+    let last_day = NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .map(|first| (first - Duration::days(1)).day())
+        .unwrap_or(28);
+
+    NaiveDate::from_ymd_opt(year, month, day.min(last_day))
+        .unwrap_or_else(|| NaiveDate::from_ymd_opt(year, month, 1).unwrap_or_default())
+}
+
+async fn current_date(pool: &PgPool) -> Result<NaiveDate> {
+    let today = sqlx::query_scalar!(r#"SELECT CURRENT_DATE as "today!""#)
+        .fetch_one(pool)
+        .await?;
+
+    Ok(today)
+}
+
+pub async fn create_recurring_deposit(
+    pool: &PgPool,
+    user_id: Uuid,
+    req: &CreateRecurringDepositRequest,
+) -> Result<Option<RecurringDeposit>> {
+    let schedule = Schedule::from_request(req)?;
+
+    // Setoran otomatis butuh hak setor (owner atau contributor)
+    if authorize_member(pool, req.savings_target_id, user_id, MemberRole::Contributor).await?.is_none() {
+        return Ok(None);
+    }
+
+    let currency = sqlx::query_scalar!(
+        "SELECT currency FROM savings_targets WHERE id = $1",
+        req.savings_target_id
+    )
+    .fetch_one(pool)
+    .await?;
+    check_minor_units("amount", &req.amount, &currency)?;
+
+    // Start date di masa lalu tidak membuat setoran mundur, mulai dari hari ini
+    let next_run_date = schedule.first_on_or_after(current_date(pool).await?);
+    if !schedule.is_active_on(next_run_date) {
+        return Err(AppError::BadRequest("Schedule has no upcoming occurrences".to_string()).into());
+    }
+
+    let rule = sqlx::query_as!(
+        RecurringDeposit,
+        r#"
+        INSERT INTO recurring_deposits (user_id, savings_target_id, amount, frequency, day_of_month,
+                                        start_date, end_date, next_run_date)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING id, user_id, savings_target_id, amount, frequency, day_of_month, start_date,
+                  end_date, next_run_date, is_paused, failure_count, last_error, last_run_at, created_at, updated_at
+        "#,
+        user_id,
+        req.savings_target_id,
+        req.amount.as_decimal(),
+        req.frequency.as_str(),
+        req.day_of_month,
+        req.start_date,
+        req.end_date,
+        next_run_date
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(Some(rule))
+}
+
+pub async fn get_user_recurring_deposits(
+    pool: &PgPool,
+    user_id: Uuid,
+) -> Result<Vec<RecurringDeposit>> {
+    let rules = sqlx::query_as!(
+        RecurringDeposit,
+        r#"
+        SELECT id, user_id, savings_target_id, amount, frequency, day_of_month, start_date,
+               end_date, next_run_date, is_paused, failure_count, last_error, last_run_at, created_at, updated_at
+        FROM recurring_deposits
+        WHERE user_id = $1
+        ORDER BY created_at DESC
+        "#,
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rules)
+}
+
+pub async fn get_recurring_deposit_by_id(
+    pool: &PgPool,
+    rule_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<RecurringDeposit>> {
+    let rule = sqlx::query_as!(
+        RecurringDeposit,
+        r#"
+        SELECT id, user_id, savings_target_id, amount, frequency, day_of_month, start_date,
+               end_date, next_run_date, is_paused, failure_count, last_error, last_run_at, created_at, updated_at
+        FROM recurring_deposits
+        WHERE id = $1 AND user_id = $2
+        "#,
+        rule_id,
+        user_id
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(rule)
+}
+
+pub async fn delete_recurring_deposit(
+    pool: &PgPool,
+    rule_id: Uuid,
+    user_id: Uuid,
+) -> Result<bool> {
+    let result = sqlx::query!(
+        "DELETE FROM recurring_deposits WHERE id = $1 AND user_id = $2",
+        rule_id,
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn pause_recurring_deposit(
+    pool: &PgPool,
+    rule_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<RecurringDeposit>> {
+    let rule = sqlx::query_as!(
+        RecurringDeposit,
+        r#"
+        UPDATE recurring_deposits
+        SET is_paused = true, updated_at = NOW()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, savings_target_id, amount, frequency, day_of_month, start_date,
+                  end_date, next_run_date, is_paused, failure_count, last_error, last_run_at, created_at, updated_at
+        "#,
+        rule_id,
+        user_id
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(rule)
+}
+
+/// Lanjutkan rule yang di-pause. Setoran yang terlewat selama pause tidak ditagih.
+pub async fn resume_recurring_deposit(
+    pool: &PgPool,
+    rule_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<RecurringDeposit>> {
+    let Some(rule) = get_recurring_deposit_by_id(pool, rule_id, user_id).await? else {
+        return Ok(None);
+    };
+
+    let schedule = Schedule::from_rule(&rule)?;
+    let today = current_date(pool).await?;
+    let next_run_date = schedule.first_on_or_after(rule.next_run_date.max(today));
+
+    update_next_run_date(pool, rule_id, user_id, next_run_date, false).await
+}
+
+/// Lewati satu setoran berikutnya
+pub async fn skip_next_recurring_deposit(
+    pool: &PgPool,
+    rule_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<RecurringDeposit>> {
+    let Some(rule) = get_recurring_deposit_by_id(pool, rule_id, user_id).await? else {
+        return Ok(None);
+    };
+
+    let schedule = Schedule::from_rule(&rule)?;
+    let next_run_date = schedule.next_after(rule.next_run_date);
+
+    update_next_run_date(pool, rule_id, user_id, next_run_date, rule.is_paused).await
+}
+
+async fn update_next_run_date(
+    pool: &PgPool,
+    rule_id: Uuid,
+    user_id: Uuid,
+    next_run_date: NaiveDate,
+    is_paused: bool,
+) -> Result<Option<RecurringDeposit>> {
+    let rule = sqlx::query_as!(
+        RecurringDeposit,
+        r#"
+        UPDATE recurring_deposits
+        SET next_run_date = $3,
+            is_paused = $4,
+            -- Resume memberi kesempatan baru untuk rule yang di-pause karena gagal
+            failure_count = CASE WHEN is_paused AND NOT $4 THEN 0 ELSE failure_count END,
+            updated_at = NOW()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, savings_target_id, amount, frequency, day_of_month, start_date,
+                  end_date, next_run_date, is_paused, failure_count, last_error, last_run_at, created_at, updated_at
+        "#,
+        rule_id,
+        user_id,
+        next_run_date,
+        is_paused
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(rule)
+}
+
+/// Dry-run: tanggal setoran berikutnya untuk rule yang sudah ada
+pub async fn preview_recurring_deposit(
+    pool: &PgPool,
+    rule_id: Uuid,
+    user_id: Uuid,
+    count: usize,
+) -> Result<Option<Vec<PreviewOccurrence>>> {
+    let Some(rule) = get_recurring_deposit_by_id(pool, rule_id, user_id).await? else {
+        return Ok(None);
+    };
+
+    let schedule = Schedule::from_rule(&rule)?;
+    let occurrences = schedule
+        .occurrences(rule.next_run_date, count)
+        .into_iter()
+        .map(|date| PreviewOccurrence { date, amount: rule.amount.clone() })
+        .collect();
+
+    Ok(Some(occurrences))
+}
+
+/// Dry-run: tanggal setoran untuk rule yang belum dibuat
+pub async fn preview_schedule(
+    pool: &PgPool,
+    req: &CreateRecurringDepositRequest,
+    count: usize,
+) -> Result<Vec<PreviewOccurrence>> {
+    let schedule = Schedule::from_request(req)?;
+    let today = current_date(pool).await?;
+
+    Ok(schedule
+        .occurrences(today, count)
+        .into_iter()
+        .map(|date| PreviewOccurrence { date, amount: req.amount.clone() })
+        .collect())
+}
+
+/// Dipanggil worker: buat deposit untuk semua rule yang jatuh tempo.
+/// Aman dijalankan di beberapa instance karena rule di-lock dengan SKIP LOCKED.
+pub async fn process_due_recurring_deposits(pool: &PgPool) -> Result<usize> {
+    let due_ids = sqlx::query_scalar!(
+        r#"
+        SELECT id FROM recurring_deposits
+        WHERE NOT is_paused
+          AND next_run_date <= CURRENT_DATE
+          AND (end_date IS NULL OR next_run_date <= end_date)
+        ORDER BY next_run_date
+        LIMIT $1
+        "#,
+        DUE_BATCH_SIZE
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut processed = 0;
+    for rule_id in due_ids {
+        match process_recurring_deposit(pool, rule_id).await {
+            Ok(true) => processed += 1,
+            Ok(false) => {}
+            Err(e) => {
+                eprintln!("❌ Gagal memproses setoran otomatis {}: {}", rule_id, e);
+                if let Err(e) = record_failure(pool, rule_id, &e).await {
+                    eprintln!("❌ Gagal mencatat kegagalan setoran otomatis {}: {}", rule_id, e);
+                }
+            }
+        }
+    }
+
+    Ok(processed)
+}
+
+/// Error yang tidak akan berhasil kalau dicoba ulang: target tidak ada, akses dicabut,
+/// atau melanggar aturan bisnis
+fn is_permanent_failure(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<AppError>(),
+        Some(
+            AppError::NotFound(_)
+                | AppError::Forbidden(_)
+                | AppError::BadRequest(_)
+                | AppError::Validation(_)
+                | AppError::Unprocessable(_)
+        )
+    )
+}
+
+/// Catat kegagalan supaya rule tidak dicoba ulang tiap putaran dan menghalangi rule lain:
+/// dicoba lagi besok, di-pause kalau errornya permanen atau sudah terlalu sering gagal
+async fn record_failure(pool: &PgPool, rule_id: Uuid, error: &anyhow::Error) -> Result<()> {
+    sqlx::query!(
+        r#"
+        UPDATE recurring_deposits
+        SET failure_count = failure_count + 1,
+            last_error = $2,
+            is_paused = $3 OR failure_count + 1 >= $4,
+            next_run_date = GREATEST(next_run_date, CURRENT_DATE + 1),
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+        rule_id,
+        error.to_string(),
+        is_permanent_failure(error),
+        MAX_CONSECUTIVE_FAILURES
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+async fn process_recurring_deposit(pool: &PgPool, rule_id: Uuid) -> Result<bool> {
+    let mut tx = pool.begin().await?;
+
+    let rule = sqlx::query_as!(
+        RecurringDeposit,
+        r#"
+        SELECT id, user_id, savings_target_id, amount, frequency, day_of_month, start_date,
+               end_date, next_run_date, is_paused, failure_count, last_error, last_run_at, created_at, updated_at
+        FROM recurring_deposits
+        WHERE id = $1
+          AND NOT is_paused
+          AND next_run_date <= CURRENT_DATE
+          AND (end_date IS NULL OR next_run_date <= end_date)
+        FOR UPDATE SKIP LOCKED
+        "#,
+        rule_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    // Sudah diproses instance lain atau tidak jatuh tempo lagi
+    let Some(rule) = rule else {
+        return Ok(false);
+    };
+
+    let schedule = Schedule::from_rule(&rule)?;
+    deposit_in_transaction(&mut tx, rule.user_id, rule.savings_target_id, rule.amount.clone()).await?;
+
+    sqlx::query!(
+        r#"
+        UPDATE recurring_deposits
+        SET next_run_date = $2, failure_count = 0, last_error = NULL, last_run_at = NOW(), updated_at = NOW()
+        WHERE id = $1
+        "#,
+        rule.id,
+        schedule.next_after(rule.next_run_date)
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(true)
+}
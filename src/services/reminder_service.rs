@@ -1,7 +1,32 @@
-use crate::models::ReminderResponse;
+use crate::models::{NotificationType, ReminderResponse};
+use crate::services::notification_service::create_notification;
+use crate::utils::pagination::{Cursor, Page, PageRequest, SortField};
+use crate::utils::money::Money;
+use chrono::NaiveDate;
 use sqlx::PgPool;
 use uuid::Uuid;
 use anyhow::Result;
+use crate::errors::AppError;
+
+/// Reminder yang jatuh tempo lebih dari sekian hari lalu tidak lagi dikirim
+pub const DISPATCH_WINDOW_DAYS: i32 = 3;
+
+/// Reminder jatuh tempo beserta data yang dibutuhkan untuk notifikasi/email
+#[derive(Debug)]
+pub struct DueReminder {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub reminder_date: NaiveDate,
+    pub title: String,
+    pub description: Option<String>,
+    pub target_name: String,
+    pub target_date: Option<NaiveDate>,
+    pub current_amount: Option<Money>,
+    pub target_amount: Money,
+    pub currency: String,
+    pub email: String,
+    pub full_name: String,
+}
 
 pub struct ReminderService {
     pool: PgPool,
@@ -12,37 +37,67 @@ impl ReminderService {
         Self { pool }
     }
 
-    pub async fn get_user_reminders(&self, user_id: Uuid, limit: Option<i32>) -> Result<Vec<ReminderResponse>> {
-        let limit = limit.unwrap_or(50);
-        
+    /// List reminder dengan cursor, sort berdasarkan reminder_date atau created_at
+    pub async fn get_user_reminders(&self, user_id: Uuid, page: &PageRequest) -> Result<Page<ReminderResponse>> {
         let reminders = sqlx::query_as!(
             ReminderResponse,
             r#"
             SELECT 
-                r.id,
-                r.reminder_date,
-                r.reminder_type,
-                r.title,
-                r.description as "description!",
-                r.is_completed as "is_completed!",
-                r.is_notified as "is_notified!",
-                st.name as target_name,
-                st.icon as "target_icon!",
-                st.icon_color as "target_icon_color!",
-                r.created_at as "created_at!"
-            FROM reminders r
-            JOIN savings_targets st ON r.savings_target_id = st.id
-            WHERE r.user_id = $1
-            ORDER BY r.reminder_date ASC, r.created_at DESC
-            LIMIT $2
+                id as "id!",
+                reminder_date as "reminder_date!",
+                reminder_type as "reminder_type!",
+                title as "title!",
+                description as "description!",
+                is_completed as "is_completed!",
+                is_notified as "is_notified!",
+                target_name as "target_name!",
+                target_icon as "target_icon!",
+                target_icon_color as "target_icon_color!",
+                created_at as "created_at!"
+            FROM (
+                SELECT r.id, r.reminder_date, r.reminder_type, r.title, r.description,
+                       r.is_completed, r.is_notified, r.created_at,
+                       st.name as target_name, st.icon as target_icon, st.icon_color as target_icon_color,
+                       CASE WHEN $2 = 'created_at' THEN r.created_at
+                            ELSE r.reminder_date::timestamp AT TIME ZONE 'UTC'
+                       END AS sort_key
+                FROM reminders r
+                JOIN savings_targets st ON r.savings_target_id = st.id
+                WHERE r.user_id = $1
+            ) reminders
+            WHERE $3::timestamptz IS NULL OR CASE
+                WHEN $5 THEN (sort_key, id) < ($3, $4::uuid)
+                ELSE (sort_key, id) > ($3, $4::uuid)
+            END
+            ORDER BY CASE WHEN $5 THEN sort_key END DESC, CASE WHEN $5 THEN id END DESC,
+                     sort_key ASC, id ASC
+            LIMIT $6
             "#,
             user_id,
-            limit as i64
+            page.sort.field.as_str(),
+            page.cursor_key(),
+            page.cursor_id(),
+            page.sort.descending,
+            page.fetch_limit()
         )
         .fetch_all(&self.pool)
         .await?;
 
-        Ok(reminders)
+        let total = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!" FROM reminders WHERE user_id = $1"#,
+            user_id
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        let sort_field = page.sort.field;
+        Ok(Page::from_rows(reminders, page, Some(total), |reminder| Cursor {
+            key: match sort_field {
+                SortField::CreatedAt => reminder.created_at,
+                _ => reminder.reminder_date.and_time(chrono::NaiveTime::MIN).and_utc(),
+            },
+            id: reminder.id,
+        }))
     }
 
     pub async fn get_upcoming_reminders(&self, user_id: Uuid, days: Option<i32>) -> Result<Vec<ReminderResponse>> {
@@ -96,19 +151,63 @@ impl ReminderService {
         Ok(result.rows_affected() > 0)
     }
 
-    pub async fn mark_reminder_notified(&self, reminder_id: Uuid) -> Result<bool> {
-        let result = sqlx::query!(
+    /// Ambil reminder yang jatuh tempo dan belum dikirim, buat notifikasi in-app,
+    /// lalu tandai is_notified. Baris di-lock dengan SKIP LOCKED sehingga aman dijalankan
+    /// di beberapa instance: satu reminder hanya diproses sekali.
+    ///
+    /// Hanya reminder dalam `DISPATCH_WINDOW_DAYS` hari terakhir yang dikirim: reminder lama
+    /// (termasuk semua yang jatuh tempo sebelum worker ini ada) sudah basi, dan target yang sudah
+    /// tercapai tidak perlu diingatkan lagi.
+    pub async fn dispatch_due_reminders(&self, limit: i64) -> Result<Vec<DueReminder>> {
+        let mut tx = self.pool.begin().await?;
+
+        let due = sqlx::query_as!(
+            DueReminder,
             r#"
-            UPDATE reminders 
+            SELECT r.id, r.user_id, r.reminder_date, r.title, r.description,
+                   st.name as target_name, st.target_date, st.current_amount as "current_amount: Money", st.target_amount,
+                   st.currency, u.email, u.full_name
+            FROM reminders r
+            JOIN savings_targets st ON r.savings_target_id = st.id
+            JOIN users u ON r.user_id = u.id
+            WHERE r.reminder_date <= CURRENT_DATE
+              AND r.reminder_date > CURRENT_DATE - $2::int
+              AND NOT COALESCE(r.is_notified, false)
+              AND NOT COALESCE(r.is_completed, false)
+              AND NOT COALESCE(st.is_completed, false)
+            ORDER BY r.reminder_date
+            LIMIT $1
+            FOR UPDATE OF r SKIP LOCKED
+            "#,
+            limit,
+            DISPATCH_WINDOW_DAYS
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for reminder in &due {
+            let message = match &reminder.description {
+                Some(description) => format!("{}: {}", reminder.title, description),
+                None => reminder.title.clone(),
+            };
+            create_notification(&mut *tx, reminder.user_id, NotificationType::Reminder, &message).await?;
+        }
+
+        let ids: Vec<Uuid> = due.iter().map(|reminder| reminder.id).collect();
+        sqlx::query!(
+            r#"
+            UPDATE reminders
             SET is_notified = true, updated_at = NOW()
-            WHERE id = $1
+            WHERE id = ANY($1)
             "#,
-            reminder_id
+            &ids
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
 
-        Ok(result.rows_affected() > 0)
+        tx.commit().await?;
+
+        Ok(due)
     }
 
     pub async fn get_todays_reminders(&self, user_id: Uuid) -> Result<Vec<ReminderResponse>> {
@@ -144,12 +243,12 @@ impl ReminderService {
 
     pub async fn get_calendar_events(&self, user_id: Uuid, month: i32, year: i32) -> Result<Vec<ReminderResponse>> {
         let start_date = chrono::NaiveDate::from_ymd_opt(year, month as u32, 1)
-            .ok_or_else(|| anyhow::anyhow!("Invalid date"))?;
+            .ok_or_else(|| AppError::BadRequest("Invalid date".to_string()))?;
         let end_date = if month == 12 {
             chrono::NaiveDate::from_ymd_opt(year + 1, 1, 1)
         } else {
             chrono::NaiveDate::from_ymd_opt(year, month as u32 + 1, 1)
-        }.ok_or_else(|| anyhow::anyhow!("Invalid date"))?;
+        }.ok_or_else(|| AppError::BadRequest("Invalid date".to_string()))?;
 
         println!("🗓️ Calendar events query - User: {}, Month: {}, Year: {}", user_id, month, year);
         println!("🗓️ Date range: {} to {}", start_date, end_date);
@@ -1,66 +1,130 @@
-use sqlx::PgPool;
+use sqlx::{Connection, PgConnection, PgPool};
 use uuid::Uuid;
+use utoipa::ToSchema;
 use anyhow::Result;
-use bigdecimal::BigDecimal;
-use crate::models::{SavingsTarget, CreateSavingsTargetRequest, UpdateSavingsTargetRequest, SavingsTargetResponse};
+use serde::{Serialize, Deserialize};
+use crate::models::{
+    Activity, SavingsTarget, CreateSavingsTargetRequest, UpdateSavingsTargetRequest,
+    WithdrawRequest, TransferRequest, TransactionType, MemberRole
+};
+use crate::services::activity_service::{
+    create_deposit_activity, create_target_completed_activity, create_target_created_activity,
+    create_transfer_activity, log_withdrawal
+};
+use crate::services::ledger_service::{record_transaction, NewTransaction};
+use crate::utils::pagination::{Cursor, Page, PageRequest, SortField};
+use crate::services::notification_service::{notify_deposit, notify_target_completed};
+use crate::services::sharing_service::{add_owner, authorize_member};
+use crate::services::statistics_service::{
+    update_user_statistics_after_deposit, AchievementResponse, UserStatisticsResponse
+};
+use crate::errors::AppError;
+use crate::utils::currency::check_minor_units;
+use crate::utils::money::Money;
 
+/// Buat target beserta owner dan activity-nya. `conn` boleh koneksi biasa atau transaksi caller
+/// (misal transaksi Idempotency-Key), di dalam transaksi dijalankan sebagai savepoint.
 pub async fn create_savings_target(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     user_id: Uuid,
     req: CreateSavingsTargetRequest,
 ) -> Result<SavingsTarget> {
-    let decimal_amount = BigDecimal::try_from(req.target_amount)
-        .map_err(|_| anyhow::anyhow!("Invalid target amount"))?;
+    let mut tx = conn.begin().await?;
+
+    let currency = match req.currency {
+        Some(currency) => currency,
+        None => sqlx::query_scalar!("SELECT currency FROM users WHERE id = $1", user_id)
+            .fetch_one(&mut *tx)
+            .await?,
+    };
+    check_minor_units("target_amount", &req.target_amount, &currency)?;
 
     let savings_target = sqlx::query_as!(
         SavingsTarget,
         r#"
-        INSERT INTO savings_targets (user_id, name, target_amount, icon, icon_color, target_date)
-        VALUES ($1, $2, $3, $4, $5, $6)
-        RETURNING id, user_id, name, target_amount, current_amount, icon, icon_color,
+        INSERT INTO savings_targets (user_id, name, target_amount, icon, icon_color, target_date, currency)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id, user_id, name, target_amount, current_amount as "current_amount: Money", currency, icon, icon_color,
                   target_date, is_completed, created_at, updated_at
         "#,
         user_id,
         req.name,
-        decimal_amount,
+        req.target_amount.as_decimal(),
         req.icon.unwrap_or_else(|| "💰".to_string()),
         req.icon_color.unwrap_or_else(|| "bg-blue-500".to_string()),
-        req.target_date
+        req.target_date,
+        currency
     )
-    .fetch_one(pool)
+    .fetch_one(&mut *tx)
     .await?;
 
-    // Activity creation is now handled in the handler to avoid duplication
-    // Only create activity here to be sure it's done once
-    let _ = crate::services::activity_service::create_target_created_activity(
-        pool,
+    add_owner(&mut *tx, savings_target.id, user_id).await?;
+
+    // Activity ikut di transaksi yang sama, kalau gagal target batal dibuat
+    create_target_created_activity(
+        &mut *tx,
         user_id,
         savings_target.id,
         savings_target.name.clone(),
-    ).await;
+    ).await?;
+
+    tx.commit().await?;
 
     Ok(savings_target)
 }
 
+/// List target yang user jadi anggotanya (milik sendiri maupun bersama) dengan cursor,
+/// sort berdasarkan created_at atau updated_at
 pub async fn get_user_savings_targets(
     pool: &PgPool,
     user_id: Uuid,
-) -> Result<Vec<SavingsTarget>> {
+    page: &PageRequest,
+) -> Result<Page<SavingsTarget>> {
     let targets = sqlx::query_as!(
         SavingsTarget,
         r#"
-        SELECT id, user_id, name, target_amount, current_amount, icon, icon_color,
-               target_date, is_completed, created_at, updated_at
-        FROM savings_targets
-        WHERE user_id = $1
-        ORDER BY created_at DESC
+        SELECT id as "id!", user_id as "user_id!", name as "name!", target_amount as "target_amount!",
+               current_amount as "current_amount: Money", currency as "currency!", icon, icon_color, target_date, is_completed,
+               created_at, updated_at
+        FROM (
+            SELECT *, CASE WHEN $2 = 'updated_at' THEN updated_at ELSE created_at END AS sort_key
+            FROM savings_targets
+            WHERE id IN (SELECT savings_target_id FROM savings_target_members WHERE user_id = $1)
+        ) st
+        WHERE $3::timestamptz IS NULL OR CASE
+            WHEN $5 THEN (sort_key, id) < ($3, $4::uuid)
+            ELSE (sort_key, id) > ($3, $4::uuid)
+        END
+        ORDER BY CASE WHEN $5 THEN sort_key END DESC, CASE WHEN $5 THEN id END DESC,
+                 sort_key ASC, id ASC
+        LIMIT $6
         "#,
-        user_id
+        user_id,
+        page.sort.field.as_str(),
+        page.cursor_key(),
+        page.cursor_id(),
+        page.sort.descending,
+        page.fetch_limit()
     )
     .fetch_all(pool)
     .await?;
 
-    Ok(targets)
+    let total = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!" FROM savings_target_members WHERE user_id = $1"#,
+        user_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    let sort_field = page.sort.field;
+    Ok(Page::from_rows(targets, page, Some(total), |target| Cursor {
+        key: match sort_field {
+            SortField::UpdatedAt => target.updated_at,
+            _ => target.created_at,
+        }
+        .unwrap_or_default(),
+        id: target.id,
+    }))
 }
 
 pub async fn get_savings_target_by_id(
@@ -71,10 +135,11 @@ pub async fn get_savings_target_by_id(
     let target = sqlx::query_as!(
         SavingsTarget,
         r#"
-        SELECT id, user_id, name, target_amount, current_amount, icon, icon_color,
+        SELECT id, user_id, name, target_amount, current_amount as "current_amount: Money", currency, icon, icon_color,
                target_date, is_completed, created_at, updated_at
         FROM savings_targets
-        WHERE id = $1 AND user_id = $2
+        WHERE id = $1
+          AND EXISTS(SELECT 1 FROM savings_target_members WHERE savings_target_id = $1 AND user_id = $2)
         "#,
         target_id,
         user_id
@@ -85,128 +150,302 @@ pub async fn get_savings_target_by_id(
     Ok(target)
 }
 
+/// Hasil deposit lengkap supaya client tidak perlu request ulang activity/statistik
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DepositResult {
+    pub target: SavingsTarget,
+    pub activity: Activity,
+    /// Terisi kalau deposit ini yang membuat target tercapai
+    pub completed_activity: Option<Activity>,
+    pub new_achievements: Vec<AchievementResponse>,
+    pub statistics: UserStatisticsResponse,
+}
+
 pub async fn add_deposit_to_target(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     user_id: Uuid,
     target_id: Uuid,
-    amount: f64,
-) -> Result<SavingsTarget> {
-    let decimal_amount = BigDecimal::try_from(amount)
-        .map_err(|_| anyhow::anyhow!("Invalid deposit amount"))?;
+    amount: Money,
+) -> Result<DepositResult> {
+    let mut tx = conn.begin().await?;
+    let result = deposit_in_transaction(&mut tx, user_id, target_id, amount).await?;
+    tx.commit().await?;
 
-    let mut tx = pool.begin().await?;
+    Ok(result)
+}
+
+/// Seluruh pipeline deposit (ledger, activity, statistik, achievement) di atas
+/// koneksi/transaksi milik caller. Commit/rollback jadi tanggung jawab caller.
+pub async fn deposit_in_transaction(
+    tx: &mut PgConnection,
+    user_id: Uuid,
+    target_id: Uuid,
+    amount: Money,
+) -> Result<DepositResult> {
+    if !amount.is_positive() {
+        return Err(AppError::BadRequest("Deposit amount must be greater than 0".to_string()).into());
+    }
+
+    // Keanggotaan dicek dulu: bukan anggota selalu 404 supaya target user lain tidak ketahuan ada,
+    // viewer 403 (hanya owner dan contributor yang boleh menabung)
+    if authorize_member(&mut *tx, target_id, user_id, MemberRole::Contributor).await?.is_none() {
+        return Err(AppError::NotFound(format!("Savings target with ID {} not found", target_id)).into());
+    }
 
-    // First check if target exists and belongs to user
     let existing_target = sqlx::query!(
-        "SELECT id, user_id, name, current_amount, target_amount FROM savings_targets WHERE id = $1",
+        "SELECT is_completed, currency FROM savings_targets WHERE id = $1 FOR UPDATE",
         target_id
     )
     .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Savings target with ID {} not found", target_id)))?;
+
+    check_minor_units("amount", &amount, &existing_target.currency)?;
+    let was_completed = existing_target.is_completed.unwrap_or(false);
+
+    // Saldo target di-update trigger ledger
+    record_transaction(&mut *tx, NewTransaction {
+        user_id,
+        savings_target_id: target_id,
+        transaction_type: TransactionType::Deposit,
+        amount: amount.clone(),
+        description: None,
+        group_id: None,
+    })
     .await?;
 
-    match existing_target {
-        None => {
-            return Err(anyhow::anyhow!("Savings target with ID {} not found", target_id));
-        }
-        Some(target) => {
-            if target.user_id != user_id {
-                return Err(anyhow::anyhow!(
-                    "Access denied: target belongs to user {} but requested by user {}", 
-                    target.user_id, user_id
-                ));
-            }
-            println!("Target found: {} (Current: {:?}, Adding: {})", target.name, target.current_amount, amount);
-        }
-    }
+    let target = fetch_target(&mut *tx, target_id, user_id).await?;
 
-    // Update target
-    let target = sqlx::query_as!(
-        SavingsTarget,
-        r#"
-        UPDATE savings_targets
-        SET current_amount = current_amount + $1,
-            is_completed = CASE 
-                WHEN current_amount + $1 >= target_amount THEN true
-                ELSE is_completed
-            END,
-            updated_at = NOW()
-        WHERE id = $2 AND user_id = $3
-        RETURNING id, user_id, name, target_amount, current_amount, icon, icon_color,
-                  target_date, is_completed, created_at, updated_at
-        "#,
-        decimal_amount,
-        target_id,
-        user_id
-    )
-    .fetch_one(&mut *tx)
-    .await
-    .map_err(|e| anyhow::anyhow!("Failed to update target: {}", e))?;
+    // Activity, statistik dan achievement ikut di transaksi yang sama,
+    // kalau salah satu gagal deposit ikut dibatalkan
+    let activity = create_deposit_activity(
+        &mut *tx,
+        user_id,
+        Some(target_id),
+        &amount,
+        Some(target.name.clone()),
+        &target.currency,
+    ).await?;
 
-    tx.commit().await?;
+    notify_deposit(&mut *tx, user_id, &target.name, &amount, &target.currency).await?;
 
-    // After successful transaction, create activity and update statistics
-    let _ = crate::services::activity_service::create_deposit_activity(
-        pool, 
-        user_id, 
-        Some(target_id), 
-        amount, 
-        Some(target.name.clone())
-    ).await;
-
-    // Check if target is completed and log achievement
-    if target.is_completed.unwrap_or(false) {
-        let _ = crate::services::activity_service::create_target_completed_activity(
-            pool,
+    // Activity target tercapai hanya dibuat saat deposit ini yang menyelesaikan target
+    let completed_activity = if !was_completed && target.is_completed.unwrap_or(false) {
+        notify_target_completed(&mut *tx, user_id, &target.name).await?;
+        Some(create_target_completed_activity(
+            &mut *tx,
             user_id,
             target_id,
             target.name.clone(),
-        ).await;
-    }
+        ).await?)
+    } else {
+        None
+    };
 
-    // Update user statistics
-    let _ = crate::services::statistics_service::update_user_statistics_after_deposit(
-        pool, 
-        user_id, 
-        amount
-    ).await;
+    let stats_update = update_user_statistics_after_deposit(&mut *tx, user_id).await?;
 
-    Ok(target)
+    Ok(DepositResult {
+        target,
+        activity,
+        completed_activity,
+        new_achievements: stats_update.new_achievements,
+        statistics: stats_update.statistics,
+    })
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WithdrawResult {
+    pub target: SavingsTarget,
+    pub activity: Activity,
+}
+
+/// Tarik dana dari target, hanya owner. None kalau target tidak ditemukan / user bukan anggota,
+/// Unprocessable kalau saldo kurang.
 pub async fn withdraw_from_target(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     target_id: Uuid,
     user_id: Uuid,
-    amount: f64,
-) -> Result<SavingsTarget> {
-    let decimal_amount = BigDecimal::try_from(amount)
-        .map_err(|_| anyhow::anyhow!("Invalid withdrawal amount"))?;
+    req: &WithdrawRequest,
+) -> Result<Option<WithdrawResult>> {
+    let mut tx = conn.begin().await?;
 
-    let target = sqlx::query_as!(
-        SavingsTarget,
+    if authorize_member(&mut *tx, target_id, user_id, MemberRole::Owner).await?.is_none() {
+        return Ok(None);
+    }
+
+    let existing = sqlx::query!(
+        r#"SELECT current_amount as "current_amount: Money", currency FROM savings_targets WHERE id = $1 FOR UPDATE"#,
+        target_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some(existing) = existing else {
+        return Ok(None);
+    };
+    check_minor_units("amount", &req.amount, &existing.currency)?;
+
+    let available = existing.current_amount.unwrap_or_default();
+    if available < req.amount {
+        return Err(insufficient_funds(&available, &req.amount).into());
+    }
+
+    // Saldo dan is_completed dihitung ulang oleh trigger ledger
+    record_transaction(&mut tx, NewTransaction {
+        user_id,
+        savings_target_id: target_id,
+        transaction_type: TransactionType::Withdrawal,
+        amount: -req.amount.clone(),
+        description: req.reason.clone(),
+        group_id: None,
+    })
+    .await?;
+
+    let target = fetch_target(&mut tx, target_id, user_id).await?;
+
+    let activity = log_withdrawal(
+        &mut *tx,
+        user_id,
+        target_id,
+        &req.amount,
+        req.reason.as_deref(),
+        &existing.currency,
+    ).await?;
+
+    tx.commit().await?;
+
+    Ok(Some(WithdrawResult { target, activity }))
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TransferResult {
+    pub transfer_id: Uuid,
+    pub from_target: SavingsTarget,
+    pub to_target: SavingsTarget,
+    pub from_activity: Activity,
+    pub to_activity: Activity,
+}
+
+/// Pindahkan dana antar dua target dalam satu transaksi. User harus owner target asal
+/// dan minimal contributor di target tujuan. None kalau salah satu target tidak ditemukan /
+/// user bukan anggotanya.
+pub async fn transfer_between_targets(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    req: &TransferRequest,
+) -> Result<Option<TransferResult>> {
+    if req.from_target_id == req.to_target_id {
+        return Err(AppError::BadRequest("Source and destination targets must be different".to_string()).into());
+    }
+
+    let mut tx = conn.begin().await?;
+
+    // Lock kedua target dengan urutan id yang konsisten supaya tidak deadlock
+    let locked = sqlx::query!(
         r#"
-        UPDATE savings_targets
-        SET current_amount = GREATEST(current_amount - $1, 0),
-            is_completed = CASE 
-                WHEN current_amount - $1 < target_amount THEN false
-                ELSE is_completed
-            END,
-            updated_at = NOW()
-        WHERE id = $2 AND user_id = $3
-        RETURNING id, user_id, name, target_amount, current_amount, icon, icon_color,
-                  target_date, is_completed, created_at, updated_at
+        SELECT id, name, current_amount as "current_amount: Money", currency, is_completed
+        FROM savings_targets
+        WHERE id = ANY($1)
+        ORDER BY id
+        FOR UPDATE
         "#,
-        decimal_amount,
-        target_id,
-        user_id
+        &[req.from_target_id, req.to_target_id][..]
     )
-    .fetch_one(pool)
+    .fetch_all(&mut *tx)
     .await?;
 
-    // Log activity
-    crate::services::activity_service::log_withdrawal(pool, user_id, target_id, amount).await?;
+    let (Some(from), Some(to)) = (
+        locked.iter().find(|t| t.id == req.from_target_id),
+        locked.iter().find(|t| t.id == req.to_target_id),
+    ) else {
+        return Ok(None);
+    };
 
-    Ok(target)
+    let from_role = authorize_member(&mut *tx, from.id, user_id, MemberRole::Owner).await?;
+    let to_role = authorize_member(&mut *tx, to.id, user_id, MemberRole::Contributor).await?;
+    if from_role.is_none() || to_role.is_none() {
+        return Ok(None);
+    }
+
+    // Belum ada konversi kurs untuk transfer, saldo di ledger selalu dalam mata uang target
+    if from.currency != to.currency {
+        return Err(AppError::Unprocessable(format!(
+            "Cannot transfer between targets with different currencies ({} to {})",
+            from.currency, to.currency
+        )).into());
+    }
+    check_minor_units("amount", &req.amount, &from.currency)?;
+
+    let available = from.current_amount.clone().unwrap_or_default();
+    if available < req.amount {
+        return Err(insufficient_funds(&available, &req.amount).into());
+    }
+
+    let transfer_id = Uuid::new_v4();
+
+    record_transaction(&mut tx, NewTransaction {
+        user_id,
+        savings_target_id: from.id,
+        transaction_type: TransactionType::Transfer,
+        amount: -req.amount.clone(),
+        description: Some(format!("Transfer ke {}", to.name)),
+        group_id: Some(transfer_id),
+    })
+    .await?;
+
+    record_transaction(&mut tx, NewTransaction {
+        user_id,
+        savings_target_id: to.id,
+        transaction_type: TransactionType::Transfer,
+        amount: req.amount.clone(),
+        description: Some(format!("Transfer dari {}", from.name)),
+        group_id: Some(transfer_id),
+    })
+    .await?;
+
+    let from_activity = create_transfer_activity(
+        &mut *tx,
+        user_id,
+        from.id,
+        format!("Transfer ke {}", to.name),
+        &req.amount,
+        req.note.as_deref(),
+        &from.currency,
+    ).await?;
+
+    let to_activity = create_transfer_activity(
+        &mut *tx,
+        user_id,
+        to.id,
+        format!("Transfer dari {}", from.name),
+        &req.amount,
+        req.note.as_deref(),
+        &from.currency,
+    ).await?;
+
+    // is_completed kedua target sudah dihitung ulang trigger ledger
+    let from_target = fetch_target(&mut tx, from.id, user_id).await?;
+    let to_target = fetch_target(&mut tx, to.id, user_id).await?;
+
+    if !to.is_completed.unwrap_or(false) && to_target.is_completed.unwrap_or(false) {
+        notify_target_completed(&mut *tx, user_id, &to_target.name).await?;
+        create_target_completed_activity(
+            &mut *tx,
+            user_id,
+            to_target.id,
+            to_target.name.clone(),
+        ).await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(Some(TransferResult {
+        transfer_id,
+        from_target,
+        to_target,
+        from_activity,
+        to_activity,
+    }))
 }
 
 pub async fn delete_savings_target(
@@ -214,10 +453,14 @@ pub async fn delete_savings_target(
     target_id: Uuid,
     user_id: Uuid,
 ) -> Result<bool> {
+    // Hanya owner yang boleh menghapus, anggota lain cukup keluar dari target
+    if authorize_member(pool, target_id, user_id, MemberRole::Owner).await?.is_none() {
+        return Ok(false);
+    }
+
     let result = sqlx::query!(
-        "DELETE FROM savings_targets WHERE id = $1 AND user_id = $2",
-        target_id,
-        user_id
+        "DELETE FROM savings_targets WHERE id = $1",
+        target_id
     )
     .execute(pool)
     .await?;
@@ -231,34 +474,101 @@ pub async fn update_savings_target(
     user_id: Uuid,
     req: UpdateSavingsTargetRequest,
 ) -> Result<Option<SavingsTarget>> {
+    let mut tx = pool.begin().await?;
+
+    if authorize_member(&mut *tx, target_id, user_id, MemberRole::Owner).await?.is_none() {
+        return Ok(None);
+    }
+
+    let existing = sqlx::query!(
+        r#"SELECT current_amount as "current_amount: Money", currency FROM savings_targets WHERE id = $1 FOR UPDATE"#,
+        target_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some(existing) = existing else {
+        return Ok(None);
+    };
+    if let Some(target_amount) = &req.target_amount {
+        check_minor_units("target_amount", target_amount, &existing.currency)?;
+    }
+    if let Some(current_amount) = &req.current_amount {
+        check_minor_units("current_amount", current_amount, &existing.currency)?;
+    }
+
+    // current_amount tidak di-set langsung, selisihnya dicatat sebagai adjustment di ledger
+    if let Some(new_amount) = req.current_amount {
+        let delta = new_amount - existing.current_amount.unwrap_or_default();
+        if !delta.is_zero() {
+            record_transaction(&mut tx, NewTransaction {
+                user_id,
+                savings_target_id: target_id,
+                transaction_type: TransactionType::Adjustment,
+                amount: delta,
+                description: Some("Penyesuaian saldo manual".to_string()),
+                group_id: None,
+            })
+            .await?;
+        }
+    }
+
+    // is_completed selalu mengikuti saldo ledger terhadap target_amount (yang baru kalau diubah)
     let target = sqlx::query_as!(
         SavingsTarget,
         r#"
         UPDATE savings_targets
         SET name = COALESCE($1, name),
             target_amount = COALESCE($2, target_amount),
-            current_amount = COALESCE($3, current_amount),
-            icon = COALESCE($4, icon),
-            icon_color = COALESCE($5, icon_color),
-            target_date = COALESCE($6, target_date),
-            is_completed = COALESCE($7, is_completed),
+            icon = COALESCE($3, icon),
+            icon_color = COALESCE($4, icon_color),
+            target_date = COALESCE($5, target_date),
+            is_completed = COALESCE(current_amount, 0) >= COALESCE($2, target_amount),
             updated_at = NOW()
-        WHERE id = $8 AND user_id = $9
-        RETURNING id, user_id, name, target_amount, current_amount, icon, icon_color,
+        WHERE id = $6
+        RETURNING id, user_id, name, target_amount, current_amount as "current_amount: Money", currency, icon, icon_color,
                   target_date, is_completed, created_at, updated_at
         "#,
         req.name,
-        req.target_amount.map(BigDecimal::try_from).transpose().map_err(|_| anyhow::anyhow!("Invalid amount"))?,
-        req.current_amount.map(BigDecimal::try_from).transpose().map_err(|_| anyhow::anyhow!("Invalid amount"))?,
+        req.target_amount.as_ref().map(Money::as_decimal),
         req.icon,
         req.icon_color,
         req.target_date,
-        req.is_completed,
+        target_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(target)
+}
+
+/// Saldo target tidak cukup untuk penarikan/transfer
+fn insufficient_funds(available: &Money, requested: &Money) -> AppError {
+    AppError::Unprocessable(format!("Insufficient funds: available {}, requested {}", available, requested))
+}
+
+async fn fetch_target(
+    conn: &mut PgConnection,
+    target_id: Uuid,
+    user_id: Uuid,
+) -> Result<SavingsTarget> {
+    let target = sqlx::query_as!(
+        SavingsTarget,
+        r#"
+        SELECT id, user_id, name, target_amount, current_amount as "current_amount: Money", currency, icon, icon_color,
+               target_date, is_completed, created_at, updated_at
+        FROM savings_targets
+        WHERE id = $1
+          AND EXISTS(SELECT 1 FROM savings_target_members WHERE savings_target_id = $1 AND user_id = $2)
+        "#,
         target_id,
         user_id
     )
-    .fetch_optional(pool)
-    .await?;
+    .fetch_optional(&mut *conn)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Savings target with ID {} not found", target_id)))?;
 
     Ok(target)
 }
@@ -1,13 +1,148 @@
+use anyhow::Result;
 use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::{SearchResponse, SearchResult, SearchResultType};
+use crate::utils::pagination::page_offset;
+
+pub const DEFAULT_PER_PAGE: i64 = 20;
+
+/// Pattern ILIKE "mengandung", karakter wildcard dari user di-escape
+fn contains_pattern(query: &str) -> String {
+    let escaped = query
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
+/// Cari di data milik user (target termasuk target bersama, activity, reminder, notifikasi). Admin juga mencari user dan testimoni.
+/// Hasil diurutkan berdasarkan skor full-text + kemiripan trigram per kata (toleran typo).
+pub async fn search(
+    pool: &PgPool,
+    user_id: Uuid,
+    is_admin: bool,
+    query: &str,
+    types: Option<&[SearchResultType]>,
+    page: i64,
+    per_page: i64,
+) -> Result<SearchResponse> {
+    let query = query.trim();
+    let pattern = contains_pattern(query);
+    let types: Option<Vec<String>> =
+        types.map(|types| types.iter().map(|t| t.as_str().to_string()).collect());
+    let offset = page_offset(page, per_page);
 
-pub async fn search_users(pool: &PgPool, query: &str) -> Result<Vec<(i64, String, String)>, sqlx::Error> {
-    let like_query = format!("%{}%", query);
     let rows = sqlx::query!(
-        "SELECT id, full_name, email FROM users WHERE full_name ILIKE $1 OR email ILIKE $1",
-        like_query
+        r#"
+        WITH q AS (
+            SELECT plainto_tsquery('simple', $1) AS tsq, $1::text AS raw, $2::text AS pattern
+        ),
+        hits AS (
+            SELECT 'savings_target' AS result_type, st.id::text AS id, st.name AS title,
+                   NULL::text AS body, st.created_at,
+                   ts_rank(to_tsvector('simple', st.name), q.tsq) + word_similarity(q.raw, st.name) AS rank
+            FROM savings_targets st, q
+            WHERE EXISTS (SELECT 1 FROM savings_target_members m WHERE m.savings_target_id = st.id AND m.user_id = $3)
+              AND (to_tsvector('simple', st.name) @@ q.tsq OR st.name ILIKE q.pattern OR q.raw <% st.name)
+
+            UNION ALL
+            SELECT 'activity', a.id::text, a.title, a.description, a.created_at,
+                   ts_rank(to_tsvector('simple', a.title || ' ' || COALESCE(a.description, '')), q.tsq)
+                       + word_similarity(q.raw, a.title)
+            FROM activities a, q
+            WHERE a.user_id = $3
+              AND (to_tsvector('simple', a.title || ' ' || COALESCE(a.description, '')) @@ q.tsq
+                   OR a.title ILIKE q.pattern OR q.raw <% a.title)
+
+            UNION ALL
+            SELECT 'reminder', r.id::text, r.title, r.description, r.created_at,
+                   ts_rank(to_tsvector('simple', r.title || ' ' || COALESCE(r.description, '')), q.tsq)
+                       + word_similarity(q.raw, r.title)
+            FROM reminders r, q
+            WHERE r.user_id = $3
+              AND (to_tsvector('simple', r.title || ' ' || COALESCE(r.description, '')) @@ q.tsq
+                   OR r.title ILIKE q.pattern OR q.raw <% r.title)
+
+            UNION ALL
+            SELECT 'notification', n.id::text, n.type, n.message, n.timestamp AT TIME ZONE 'UTC',
+                   ts_rank(to_tsvector('simple', n.message), q.tsq) + word_similarity(q.raw, n.message)
+            FROM notifications n, q
+            WHERE n.user_id = $3
+              AND (to_tsvector('simple', n.message) @@ q.tsq OR n.message ILIKE q.pattern OR q.raw <% n.message)
+
+            UNION ALL
+            SELECT 'user', u.id::text, u.full_name, u.email, u.created_at,
+                   ts_rank(to_tsvector('simple', u.full_name || ' ' || u.email), q.tsq)
+                       + word_similarity(q.raw, u.full_name || ' ' || u.email)
+            FROM users u, q
+            WHERE $4
+              AND (to_tsvector('simple', u.full_name || ' ' || u.email) @@ q.tsq
+                   OR (u.full_name || ' ' || u.email) ILIKE q.pattern
+                   OR q.raw <% (u.full_name || ' ' || u.email))
+
+            UNION ALL
+            SELECT 'testimoni', t.id::text, u.full_name, t.content, t.created_at,
+                   ts_rank(to_tsvector('simple', t.content), q.tsq) + word_similarity(q.raw, t.content)
+            FROM testimoni t
+            JOIN users u ON u.id = t.user_id, q
+            WHERE $4
+              AND (to_tsvector('simple', t.content) @@ q.tsq OR t.content ILIKE q.pattern OR q.raw <% t.content)
+        ),
+        filtered AS (
+            SELECT * FROM hits WHERE $5::text[] IS NULL OR hits.result_type = ANY($5)
+        ),
+        total AS (
+            SELECT COUNT(*) AS total FROM filtered
+        )
+        -- Selalu ada satu baris (berisi total) walaupun halaman yang diminta kosong
+        SELECT page.result_type as "result_type?", page.id as "id?", page.title as "title?",
+               CASE WHEN page.body IS NULL THEN NULL
+                    ELSE ts_headline('simple', page.body, q.tsq, 'MaxWords=25, MinWords=10, StartSel=<mark>, StopSel=</mark>')
+               END as snippet,
+               page.rank::real as "rank?",
+               page.created_at as "created_at?",
+               total.total as "total!"
+        FROM total
+        CROSS JOIN q
+        LEFT JOIN LATERAL (
+            SELECT * FROM filtered
+            ORDER BY filtered.rank DESC, filtered.created_at DESC NULLS LAST
+            LIMIT $6 OFFSET $7
+        ) page ON true
+        ORDER BY page.rank DESC, page.created_at DESC NULLS LAST
+        "#,
+        query,
+        pattern,
+        user_id,
+        is_admin,
+        types.as_deref(),
+        per_page,
+        offset
     )
     .fetch_all(pool)
     .await?;
-    Ok(rows.into_iter().map(|r| (r.id, r.full_name, r.email)).collect())
+
+    let total = rows.first().map(|row| row.total).unwrap_or(0);
+    let results = rows
+        .into_iter()
+        .filter_map(|row| {
+            Some(SearchResult {
+                result_type: row.result_type?,
+                id: row.id?,
+                title: row.title?,
+                snippet: row.snippet,
+                rank: row.rank?,
+                created_at: row.created_at,
+            })
+        })
+        .collect();
+
+    Ok(SearchResponse {
+        query: query.to_string(),
+        results,
+        page,
+        per_page,
+        total,
+    })
 }
-// Tambahkan fungsi search lain sesuai kebutuhan (transaksi, dsb)
@@ -0,0 +1,286 @@
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+use anyhow::{Result, anyhow};
+use chrono::{Utc, Duration};
+
+use crate::models::{User, AuthResponse, Role, StreamTicket};
+use crate::utils::jwt::{access_token_ttl, generate_jwt_token, Claims};
+use crate::utils::token::{generate_token, hash_token};
+use crate::errors::AppError;
+
+/// Umur refresh token, default 30 hari (bisa diatur lewat REFRESH_TOKEN_TTL_DAYS)
+fn refresh_token_ttl() -> Duration {
+    let days = std::env::var("REFRESH_TOKEN_TTL_DAYS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(30);
+
+    Duration::days(days)
+}
+
+fn build_auth_response(user: User, session_id: Uuid, refresh_token: String) -> Result<AuthResponse> {
+    let token = generate_jwt_token(user.id, &user.email, &user.role, session_id)?;
+
+    Ok(AuthResponse {
+        token,
+        refresh_token,
+        expires_in: access_token_ttl().num_seconds(),
+        user: user.into(),
+    })
+}
+
+/// Buat session baru setelah login/register dan terbitkan access + refresh token
+pub async fn create_session(pool: &PgPool, user: User) -> Result<AuthResponse> {
+    let refresh_token = generate_token();
+    let expires_at = Utc::now() + refresh_token_ttl();
+
+    let session_id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO user_sessions (user_id, refresh_token_hash, expires_at)
+        VALUES ($1, $2, $3)
+        RETURNING id
+        "#,
+        user.id,
+        hash_token(&refresh_token),
+        expires_at
+    )
+    .fetch_one(pool)
+    .await?;
+
+    build_auth_response(user, session_id, refresh_token)
+}
+
+/// Tukar refresh token dengan pasangan token baru (refresh token selalu dirotasi)
+pub async fn refresh_session(pool: &PgPool, refresh_token: &str) -> Result<AuthResponse> {
+    let token_hash = hash_token(refresh_token);
+    let mut tx = pool.begin().await?;
+
+    let session = sqlx::query!(
+        r#"
+        SELECT id, user_id, expires_at, revoked_at
+        FROM user_sessions
+        WHERE refresh_token_hash = $1
+        FOR UPDATE
+        "#,
+        token_hash
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let session = match session {
+        Some(session) => session,
+        None => {
+            // Refresh token lama dipakai lagi -> kemungkinan dicuri, revoke session-nya
+            let reused = sqlx::query!(
+                r#"
+                UPDATE user_sessions
+                SET revoked_at = NOW()
+                WHERE previous_token_hash = $1 AND revoked_at IS NULL
+                "#,
+                token_hash
+            )
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+            tx.commit().await?;
+
+            if reused > 0 {
+                return Err(AppError::Unauthorized("Refresh token reuse detected, session revoked".to_string()).into());
+            }
+            return Err(AppError::Unauthorized("Invalid refresh token".to_string()).into());
+        }
+    };
+
+    if session.revoked_at.is_some() {
+        return Err(AppError::Unauthorized("Session has been revoked".to_string()).into());
+    }
+    if session.expires_at <= Utc::now() {
+        return Err(AppError::Unauthorized("Refresh token expired".to_string()).into());
+    }
+
+    let new_refresh_token = generate_token();
+    sqlx::query!(
+        r#"
+        UPDATE user_sessions
+        SET previous_token_hash = refresh_token_hash,
+            refresh_token_hash = $1,
+            expires_at = $2,
+            last_used_at = NOW()
+        WHERE id = $3
+        "#,
+        hash_token(&new_refresh_token),
+        Utc::now() + refresh_token_ttl(),
+        session.id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1")
+        .bind(session.user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    build_auth_response(user, session.id, new_refresh_token)
+}
+
+pub async fn revoke_session(pool: &PgPool, session_id: Uuid, user_id: Uuid) -> Result<bool> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE user_sessions
+        SET revoked_at = NOW()
+        WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL
+        "#,
+        session_id,
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn revoke_all_sessions<'e, E>(executor: E, user_id: Uuid) -> Result<u64>
+where
+    E: PgExecutor<'e>,
+{
+    let result = sqlx::query!(
+        r#"
+        UPDATE user_sessions
+        SET revoked_at = NOW()
+        WHERE user_id = $1 AND revoked_at IS NULL
+        "#,
+        user_id
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// Pastikan session dari access token masih aktif dan token diterbitkan
+/// setelah password terakhir diganti. Role dikembalikan dari database supaya
+/// perubahan role langsung berlaku tanpa menunggu token expired.
+pub async fn validate_session(pool: &PgPool, claims: &Claims) -> Result<Role> {
+    let session = sqlx::query!(
+        r#"
+        SELECT s.revoked_at, s.expires_at, u.password_changed_at, u.role
+        FROM user_sessions s
+        JOIN users u ON u.id = s.user_id
+        WHERE s.id = $1 AND s.user_id = $2
+        "#,
+        claims.sid,
+        claims.user_id
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::Unauthorized("Session not found".to_string()))?;
+
+    if session.revoked_at.is_some() || session.expires_at <= Utc::now() {
+        return Err(AppError::Unauthorized("Session has been revoked".to_string()).into());
+    }
+
+    if let Some(changed_at) = session.password_changed_at {
+        if (claims.iat as i64) < changed_at.timestamp() {
+            return Err(AppError::Unauthorized("Token issued before last password change".to_string()).into());
+        }
+    }
+
+    Role::from_name(&session.role).ok_or_else(|| anyhow!("Unknown role {}", session.role))
+}
+
+/// Cek ulang session untuk koneksi yang berumur panjang (stream SSE). Ganti password dan
+/// logout sama-sama mencabut session, jadi cukup melihat `revoked_at` dan `expires_at`.
+pub async fn is_session_active(pool: &PgPool, session_id: Uuid) -> Result<bool> {
+    let active = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM user_sessions
+            WHERE id = $1 AND revoked_at IS NULL AND expires_at > NOW()
+        ) as "active!"
+        "#,
+        session_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(active)
+}
+
+/// Umur tiket stream SSE, cukup untuk membuka koneksi EventSource
+const STREAM_TICKET_TTL_SECS: i64 = 60;
+
+/// Terbitkan tiket sekali pakai untuk stream SSE, terikat ke session access token yang meminta
+pub async fn create_stream_ticket(pool: &PgPool, user_id: Uuid, session_id: Uuid) -> Result<StreamTicket> {
+    sqlx::query!(
+        "DELETE FROM stream_tickets WHERE user_id = $1 AND expires_at <= NOW()",
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    let ticket = generate_token();
+    sqlx::query!(
+        r#"
+        INSERT INTO stream_tickets (user_id, session_id, ticket_hash, expires_at)
+        VALUES ($1, $2, $3, $4)
+        "#,
+        user_id,
+        session_id,
+        hash_token(&ticket),
+        Utc::now() + Duration::seconds(STREAM_TICKET_TTL_SECS)
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(StreamTicket {
+        ticket,
+        expires_in: STREAM_TICKET_TTL_SECS,
+    })
+}
+
+/// User pemilik tiket stream
+pub struct StreamTicketOwner {
+    pub user_id: Uuid,
+    pub email: String,
+    pub role: Role,
+    pub session_id: Uuid,
+}
+
+/// Tukar tiket stream (langsung dihapus, jadi hanya bisa dipakai sekali). Session-nya harus masih aktif.
+pub async fn redeem_stream_ticket(pool: &PgPool, ticket: &str) -> Result<StreamTicketOwner> {
+    let owner = sqlx::query!(
+        r#"
+        WITH redeemed AS (
+            DELETE FROM stream_tickets
+            WHERE ticket_hash = $1
+            RETURNING user_id, session_id, expires_at
+        )
+        SELECT r.user_id, r.session_id, r.expires_at, s.revoked_at, s.expires_at as session_expires_at,
+               u.email, u.role
+        FROM redeemed r
+        JOIN user_sessions s ON s.id = r.session_id
+        JOIN users u ON u.id = r.user_id
+        "#,
+        hash_token(ticket)
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::Unauthorized("Invalid stream ticket".to_string()))?;
+
+    if owner.expires_at <= Utc::now() {
+        return Err(AppError::Unauthorized("Stream ticket expired".to_string()).into());
+    }
+    if owner.revoked_at.is_some() || owner.session_expires_at <= Utc::now() {
+        return Err(AppError::Unauthorized("Session has been revoked".to_string()).into());
+    }
+
+    Ok(StreamTicketOwner {
+        user_id: owner.user_id,
+        email: owner.email,
+        role: Role::from_name(&owner.role).ok_or_else(|| anyhow!("Unknown role {}", owner.role))?,
+        session_id: owner.session_id,
+    })
+}
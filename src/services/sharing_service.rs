@@ -0,0 +1,584 @@
+use anyhow::{anyhow, Result};
+use sqlx::{PgConnection, PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::{
+    CreateInvitationRequest, MemberRole, NotificationType, TargetActivity, TargetInvitation, TargetMember,
+};
+use crate::services::notification_service::create_notification;
+use crate::utils::money::Money;
+use crate::utils::pagination::{Cursor, Page, PageRequest};
+
+/// Undangan yang tidak dijawab dalam 7 hari tidak bisa diterima lagi
+pub const INVITATION_TTL_DAYS: i64 = 7;
+
+fn parse_role(role: &str) -> Result<MemberRole> {
+    MemberRole::from_name(role).ok_or_else(|| anyhow!("Unknown member role '{}'", role))
+}
+
+fn target_not_found(target_id: Uuid) -> AppError {
+    AppError::NotFound(format!("Savings target with ID {} not found", target_id))
+}
+
+fn invitation_not_found(invitation_id: Uuid) -> AppError {
+    AppError::NotFound(format!("Invitation with ID {} not found", invitation_id))
+}
+
+/// Role user di target, None kalau bukan anggota
+pub async fn member_role<'e, E: PgExecutor<'e>>(
+    executor: E,
+    target_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<MemberRole>> {
+    let role = sqlx::query_scalar!(
+        "SELECT role FROM savings_target_members WHERE savings_target_id = $1 AND user_id = $2",
+        target_id,
+        user_id
+    )
+    .fetch_optional(executor)
+    .await?;
+
+    role.as_deref().map(parse_role).transpose()
+}
+
+/// Cek akses user ke target. None kalau bukan anggota (caller yang menentukan 404/403),
+/// Forbidden kalau anggota tapi role-nya di bawah `required`.
+pub async fn authorize_member<'e, E: PgExecutor<'e>>(
+    executor: E,
+    target_id: Uuid,
+    user_id: Uuid,
+    required: MemberRole,
+) -> Result<Option<MemberRole>> {
+    let Some(role) = member_role(executor, target_id, user_id).await? else {
+        return Ok(None);
+    };
+
+    if role < required {
+        return Err(AppError::Forbidden(format!(
+            "Access denied: requires {} role on this target",
+            required.as_str()
+        ))
+        .into());
+    }
+
+    Ok(Some(role))
+}
+
+/// Sama dengan authorize_member, tapi bukan anggota langsung jadi NotFound
+async fn require_member<'e, E: PgExecutor<'e>>(
+    executor: E,
+    target_id: Uuid,
+    user_id: Uuid,
+    required: MemberRole,
+) -> Result<MemberRole> {
+    authorize_member(executor, target_id, user_id, required)
+        .await?
+        .ok_or_else(|| target_not_found(target_id).into())
+}
+
+/// Pembuat target otomatis jadi owner
+pub async fn add_owner<'e, E: PgExecutor<'e>>(executor: E, target_id: Uuid, user_id: Uuid) -> Result<()> {
+    sqlx::query!(
+        "INSERT INTO savings_target_members (savings_target_id, user_id, role) VALUES ($1, $2, 'owner')",
+        target_id,
+        user_id
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+async fn fetch_members<'e, E: PgExecutor<'e>>(executor: E, target_id: Uuid) -> Result<Vec<TargetMember>> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT m.user_id, u.full_name, u.email, m.role, m.created_at as joined_at,
+               COALESCE(t.contributed, 0) as "contributed!: Money",
+               COALESCE(t.deposit_count, 0) as "deposit_count!",
+               COALESCE(st.current_amount, 0) as "balance!: Money"
+        FROM savings_target_members m
+        JOIN users u ON u.id = m.user_id
+        JOIN savings_targets st ON st.id = m.savings_target_id
+        LEFT JOIN (
+            SELECT user_id, SUM(amount) as contributed,
+                   COUNT(*) FILTER (WHERE transaction_type = 'deposit') as deposit_count
+            FROM transactions
+            WHERE savings_target_id = $1
+            GROUP BY user_id
+        ) t ON t.user_id = m.user_id
+        WHERE m.savings_target_id = $1
+        ORDER BY CASE m.role WHEN 'owner' THEN 0 WHEN 'contributor' THEN 1 ELSE 2 END, m.created_at, m.user_id
+        "#,
+        target_id
+    )
+    .fetch_all(executor)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| TargetMember {
+            user_id: row.user_id,
+            full_name: row.full_name,
+            email: row.email,
+            role: row.role,
+            share: row.contributed.percent_of(&row.balance),
+            contributed: row.contributed,
+            deposit_count: row.deposit_count,
+            joined_at: row.joined_at,
+        })
+        .collect())
+}
+
+/// Daftar anggota beserta kontribusinya, bisa dilihat semua anggota
+pub async fn get_members(pool: &PgPool, target_id: Uuid, user_id: Uuid) -> Result<Vec<TargetMember>> {
+    require_member(pool, target_id, user_id, MemberRole::Viewer).await?;
+    fetch_members(pool, target_id).await
+}
+
+/// Lock target supaya perubahan anggota (terutama owner terakhir) tidak balapan
+async fn lock_target(conn: &mut PgConnection, target_id: Uuid) -> Result<()> {
+    sqlx::query_scalar!("SELECT id FROM savings_targets WHERE id = $1 FOR UPDATE", target_id)
+        .fetch_optional(&mut *conn)
+        .await?
+        .ok_or_else(|| target_not_found(target_id))?;
+
+    Ok(())
+}
+
+async fn ensure_other_owner(conn: &mut PgConnection, target_id: Uuid, member_id: Uuid) -> Result<()> {
+    let other_owners = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) as "count!"
+        FROM savings_target_members
+        WHERE savings_target_id = $1 AND role = 'owner' AND user_id <> $2
+        "#,
+        target_id,
+        member_id
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    if other_owners == 0 {
+        return Err(AppError::Unprocessable("A shared target must keep at least one owner".to_string()).into());
+    }
+
+    Ok(())
+}
+
+/// Setoran otomatis anggota yang tidak lagi boleh setor di-pause, bukan dihapus
+async fn pause_recurring_deposits(conn: &mut PgConnection, target_id: Uuid, member_id: Uuid) -> Result<()> {
+    sqlx::query!(
+        r#"
+        UPDATE recurring_deposits
+        SET is_paused = true, updated_at = NOW()
+        WHERE savings_target_id = $1 AND user_id = $2 AND NOT is_paused
+        "#,
+        target_id,
+        member_id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn update_member_role(
+    pool: &PgPool,
+    target_id: Uuid,
+    user_id: Uuid,
+    member_id: Uuid,
+    role: MemberRole,
+) -> Result<TargetMember> {
+    let mut tx = pool.begin().await?;
+    lock_target(&mut tx, target_id).await?;
+    require_member(&mut *tx, target_id, user_id, MemberRole::Owner).await?;
+
+    let current = member_role(&mut *tx, target_id, member_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Member not found".to_string()))?;
+
+    if current == MemberRole::Owner && role != MemberRole::Owner {
+        ensure_other_owner(&mut tx, target_id, member_id).await?;
+    }
+
+    sqlx::query!(
+        "UPDATE savings_target_members SET role = $3 WHERE savings_target_id = $1 AND user_id = $2",
+        target_id,
+        member_id,
+        role.as_str()
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if role == MemberRole::Viewer {
+        pause_recurring_deposits(&mut tx, target_id, member_id).await?;
+    }
+
+    let member = fetch_members(&mut *tx, target_id)
+        .await?
+        .into_iter()
+        .find(|member| member.user_id == member_id)
+        .ok_or_else(|| AppError::NotFound("Member not found".to_string()))?;
+
+    tx.commit().await?;
+
+    Ok(member)
+}
+
+/// Owner bisa mengeluarkan anggota lain, setiap anggota bisa keluar sendiri.
+/// Riwayat setoran anggota tetap ada di ledger.
+pub async fn remove_member(pool: &PgPool, target_id: Uuid, user_id: Uuid, member_id: Uuid) -> Result<()> {
+    let mut tx = pool.begin().await?;
+    lock_target(&mut tx, target_id).await?;
+
+    let required = if member_id == user_id { MemberRole::Viewer } else { MemberRole::Owner };
+    require_member(&mut *tx, target_id, user_id, required).await?;
+
+    let current = member_role(&mut *tx, target_id, member_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Member not found".to_string()))?;
+
+    if current == MemberRole::Owner {
+        ensure_other_owner(&mut tx, target_id, member_id).await?;
+    }
+
+    sqlx::query!(
+        "DELETE FROM savings_target_members WHERE savings_target_id = $1 AND user_id = $2",
+        target_id,
+        member_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    pause_recurring_deposits(&mut tx, target_id, member_id).await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+async fn fetch_invitation<'e, E: PgExecutor<'e>>(executor: E, invitation_id: Uuid) -> Result<TargetInvitation> {
+    let invitation = sqlx::query_as!(
+        TargetInvitation,
+        r#"
+        SELECT i.id, i.savings_target_id, st.name as target_name, i.email, i.role, i.invited_by,
+               u.full_name as invited_by_name, i.status, i.expires_at, i.responded_at, i.created_at
+        FROM savings_target_invitations i
+        JOIN savings_targets st ON st.id = i.savings_target_id
+        JOIN users u ON u.id = i.invited_by
+        WHERE i.id = $1
+        "#,
+        invitation_id
+    )
+    .fetch_optional(executor)
+    .await?
+    .ok_or_else(|| invitation_not_found(invitation_id))?;
+
+    Ok(invitation)
+}
+
+/// Undang email ke target. Kalau email sudah terdaftar, user juga dapat notifikasi in-app.
+/// Email undangan dikirim oleh handler.
+pub async fn create_invitation(
+    pool: &PgPool,
+    target_id: Uuid,
+    user_id: Uuid,
+    req: &CreateInvitationRequest,
+) -> Result<TargetInvitation> {
+    let email = req.email.trim().to_lowercase();
+    let role = req.role.unwrap_or(MemberRole::Contributor);
+
+    let mut tx = pool.begin().await?;
+    lock_target(&mut tx, target_id).await?;
+    require_member(&mut *tx, target_id, user_id, MemberRole::Owner).await?;
+
+    let already_member = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM savings_target_members m
+            JOIN users u ON u.id = m.user_id
+            WHERE m.savings_target_id = $1 AND LOWER(u.email) = $2
+        ) as "exists!"
+        "#,
+        target_id,
+        email
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if already_member {
+        return Err(AppError::Conflict(format!("{} is already a member of this target", email)).into());
+    }
+
+    // Undangan pending yang sudah kedaluwarsa tidak menghalangi undangan baru
+    sqlx::query!(
+        r#"
+        UPDATE savings_target_invitations
+        SET status = 'revoked', responded_at = NOW()
+        WHERE savings_target_id = $1 AND LOWER(email) = $2 AND status = 'pending' AND expires_at <= NOW()
+        "#,
+        target_id,
+        email
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let pending = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM savings_target_invitations
+            WHERE savings_target_id = $1 AND LOWER(email) = $2 AND status = 'pending'
+        ) as "exists!"
+        "#,
+        target_id,
+        email
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if pending {
+        return Err(AppError::Conflict(format!("{} already has a pending invitation", email)).into());
+    }
+
+    let invitation_id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO savings_target_invitations (savings_target_id, email, role, invited_by, expires_at)
+        VALUES ($1, $2, $3, $4, NOW() + make_interval(days => $5))
+        RETURNING id
+        "#,
+        target_id,
+        email,
+        role.as_str(),
+        user_id,
+        INVITATION_TTL_DAYS as i32
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let invitation = fetch_invitation(&mut *tx, invitation_id).await?;
+
+    let invitee = sqlx::query_scalar!("SELECT id FROM users WHERE LOWER(email) = $1", email)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+    if let Some(invitee) = invitee {
+        let message = format!(
+            "{} mengundang kamu bergabung ke target \"{}\" sebagai {}.",
+            invitation.invited_by_name, invitation.target_name, invitation.role
+        );
+        create_notification(&mut *tx, invitee, NotificationType::Invitation, &message).await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(invitation)
+}
+
+/// Semua undangan target (termasuk yang sudah dijawab), hanya untuk owner
+pub async fn get_target_invitations(pool: &PgPool, target_id: Uuid, user_id: Uuid) -> Result<Vec<TargetInvitation>> {
+    require_member(pool, target_id, user_id, MemberRole::Owner).await?;
+
+    let invitations = sqlx::query_as!(
+        TargetInvitation,
+        r#"
+        SELECT i.id, i.savings_target_id, st.name as target_name, i.email, i.role, i.invited_by,
+               u.full_name as invited_by_name, i.status, i.expires_at, i.responded_at, i.created_at
+        FROM savings_target_invitations i
+        JOIN savings_targets st ON st.id = i.savings_target_id
+        JOIN users u ON u.id = i.invited_by
+        WHERE i.savings_target_id = $1
+        ORDER BY i.created_at DESC, i.id DESC
+        "#,
+        target_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(invitations)
+}
+
+/// Batalkan undangan yang masih pending. false kalau tidak ada undangan pending dengan id itu.
+pub async fn revoke_invitation(pool: &PgPool, target_id: Uuid, invitation_id: Uuid, user_id: Uuid) -> Result<bool> {
+    require_member(pool, target_id, user_id, MemberRole::Owner).await?;
+
+    let result = sqlx::query!(
+        r#"
+        UPDATE savings_target_invitations
+        SET status = 'revoked', responded_at = NOW()
+        WHERE id = $1 AND savings_target_id = $2 AND status = 'pending'
+        "#,
+        invitation_id,
+        target_id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Undangan pending yang belum kedaluwarsa untuk email user yang login
+pub async fn get_my_invitations(pool: &PgPool, user_id: Uuid) -> Result<Vec<TargetInvitation>> {
+    let invitations = sqlx::query_as!(
+        TargetInvitation,
+        r#"
+        SELECT i.id, i.savings_target_id, st.name as target_name, i.email, i.role, i.invited_by,
+               u.full_name as invited_by_name, i.status, i.expires_at, i.responded_at, i.created_at
+        FROM savings_target_invitations i
+        JOIN savings_targets st ON st.id = i.savings_target_id
+        JOIN users u ON u.id = i.invited_by
+        JOIN users invitee ON LOWER(invitee.email) = LOWER(i.email)
+        WHERE invitee.id = $1 AND i.status = 'pending' AND i.expires_at > NOW()
+        ORDER BY i.created_at DESC, i.id DESC
+        "#,
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(invitations)
+}
+
+/// Terima atau tolak undangan. Hanya pemilik email undangan yang bisa menjawab.
+pub async fn respond_to_invitation(
+    pool: &PgPool,
+    invitation_id: Uuid,
+    user_id: Uuid,
+    accept: bool,
+) -> Result<TargetInvitation> {
+    let mut tx = pool.begin().await?;
+
+    let invitation = sqlx::query!(
+        r#"
+        SELECT i.savings_target_id, i.role, i.status, i.invited_by, i.expires_at > NOW() as "active!",
+               invitee.full_name
+        FROM savings_target_invitations i
+        JOIN users invitee ON LOWER(invitee.email) = LOWER(i.email)
+        WHERE i.id = $1 AND invitee.id = $2
+        FOR UPDATE OF i
+        "#,
+        invitation_id,
+        user_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| invitation_not_found(invitation_id))?;
+
+    if invitation.status != "pending" {
+        return Err(AppError::Conflict(format!("Invitation has already been {}", invitation.status)).into());
+    }
+    if !invitation.active {
+        return Err(AppError::Unprocessable("Invitation has expired".to_string()).into());
+    }
+
+    if accept {
+        if member_role(&mut *tx, invitation.savings_target_id, user_id).await?.is_some() {
+            return Err(AppError::Conflict("You are already a member of this target".to_string()).into());
+        }
+
+        sqlx::query!(
+            "INSERT INTO savings_target_members (savings_target_id, user_id, role) VALUES ($1, $2, $3)",
+            invitation.savings_target_id,
+            user_id,
+            invitation.role
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    sqlx::query!(
+        "UPDATE savings_target_invitations SET status = $2, responded_at = NOW() WHERE id = $1",
+        invitation_id,
+        if accept { "accepted" } else { "declined" }
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let updated = fetch_invitation(&mut *tx, invitation_id).await?;
+
+    let message = if accept {
+        format!("{} bergabung ke target \"{}\".", invitation.full_name, updated.target_name)
+    } else {
+        format!("{} menolak undangan ke target \"{}\".", invitation.full_name, updated.target_name)
+    };
+    create_notification(&mut *tx, invitation.invited_by, NotificationType::Invitation, &message).await?;
+
+    tx.commit().await?;
+
+    Ok(updated)
+}
+
+/// Feed activity satu target dari semua anggota, cursor (created_at, id)
+pub async fn get_target_activities(
+    pool: &PgPool,
+    target_id: Uuid,
+    user_id: Uuid,
+    page: &PageRequest,
+) -> Result<Page<TargetActivity>> {
+    require_member(pool, target_id, user_id, MemberRole::Viewer).await?;
+
+    let activities = sqlx::query!(
+        r#"
+        SELECT a.id, a.user_id, u.full_name as user_name, a.activity_type, a.title, a.description,
+               COALESCE(a.amount, 0) as "amount!: Money", a.created_at as "created_at!"
+        FROM activities a
+        JOIN users u ON u.id = a.user_id
+        WHERE a.savings_target_id = $1
+          AND ($2::timestamptz IS NULL OR CASE
+                WHEN $4 THEN (a.created_at, a.id) < ($2, $3::uuid)
+                ELSE (a.created_at, a.id) > ($2, $3::uuid)
+              END)
+        ORDER BY CASE WHEN $4 THEN a.created_at END DESC, CASE WHEN $4 THEN a.id END DESC,
+                 a.created_at ASC, a.id ASC
+        LIMIT $5
+        "#,
+        target_id,
+        page.cursor_key(),
+        page.cursor_id(),
+        page.sort.descending,
+        page.fetch_limit()
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let total = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!" FROM activities WHERE savings_target_id = $1"#,
+        target_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    let activities = activities
+        .into_iter()
+        .map(|row| TargetActivity {
+            id: row.id,
+            user_id: row.user_id,
+            user_name: row.user_name,
+            activity_type: row.activity_type,
+            title: row.title,
+            description: row.description,
+            amount: row.amount,
+            created_at: row.created_at,
+        })
+        .collect();
+
+    Ok(Page::from_rows(activities, page, Some(total), |activity| Cursor {
+        key: activity.created_at,
+        id: activity.id,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roles_are_ordered_by_access() {
+        assert!(MemberRole::Viewer < MemberRole::Contributor);
+        assert!(MemberRole::Contributor < MemberRole::Owner);
+        for role in [MemberRole::Viewer, MemberRole::Contributor, MemberRole::Owner] {
+            assert_eq!(parse_role(role.as_str()).unwrap(), role);
+        }
+        assert!(parse_role("admin").is_err());
+    }
+}
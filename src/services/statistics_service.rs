@@ -1,21 +1,29 @@
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 use anyhow::Result;
 use serde::{Serialize, Deserialize};
-use bigdecimal::{BigDecimal, FromPrimitive};
+use utoipa::ToSchema;
+use bigdecimal::BigDecimal;
 
 use crate::models::{UserStatistics, Achievement};
+use crate::services::currency_service::converted_savings_total;
+use crate::services::notification_service::notify_achievement;
+use crate::utils::money::Money;
 
-#[derive(Debug, Serialize, Deserialize)]
+/// `total_saved` dan `daily_average` dalam mata uang utama user (`currency`)
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UserStatisticsResponse {
-    pub total_saved: f64,
+    pub total_saved: Money,
     pub streak_days: i32,
-    pub daily_average: f64,
+    pub daily_average: Money,
     pub achievements_count: i32,
     pub last_deposit_date: Option<chrono::NaiveDate>,
+    pub currency: String,
+    /// Mata uang target yang belum punya kurs ke `currency` sehingga tidak ikut dijumlah
+    pub unconverted_currencies: Vec<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct AchievementResponse {
     pub id: Uuid,
     pub title: String,
@@ -25,16 +33,36 @@ pub struct AchievementResponse {
     pub earned_at: chrono::DateTime<chrono::Utc>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl From<Achievement> for AchievementResponse {
+    fn from(a: Achievement) -> Self {
+        Self {
+            id: a.id,
+            title: a.title,
+            description: a.description,
+            icon: a.icon,
+            icon_color: a.icon_color,
+            earned_at: a.earned_at.unwrap_or_else(chrono::Utc::now),
+        }
+    }
+}
+
+/// Statistik terbaru setelah deposit beserta achievement yang baru didapat
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DepositStatisticsUpdate {
+    pub statistics: UserStatisticsResponse,
+    pub new_achievements: Vec<AchievementResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct StreakDayData {
     pub date: chrono::NaiveDate,
     pub has_deposit: bool,
-    pub deposit_amount: Option<f64>,
+    pub deposit_amount: Option<Money>,
     pub is_today: bool,
     pub is_part_of_streak: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct StreakDataResponse {
     pub current_streak: i32,
     pub days: Vec<StreakDayData>,
@@ -43,34 +71,58 @@ pub struct StreakDataResponse {
 pub async fn get_user_statistics(
     pool: &PgPool,
     user_id: Uuid,
+) -> Result<UserStatisticsResponse> {
+    let mut conn = pool.acquire().await?;
+    fetch_user_statistics(&mut conn, user_id).await
+}
+
+async fn fetch_user_statistics(
+    conn: &mut PgConnection,
+    user_id: Uuid,
 ) -> Result<UserStatisticsResponse> {
     let stats = sqlx::query_as!(
         UserStatistics,
         r#"
-        SELECT id, user_id, total_saved, streak_days, daily_average, achievements_count, 
+        SELECT id, user_id, streak_days, achievements_count,
                last_deposit_date, created_at, updated_at
         FROM user_statistics 
         WHERE user_id = $1
         "#,
         user_id
     )
-    .fetch_optional(pool)
+    .fetch_optional(&mut *conn)
     .await?;
 
     let stats = match stats {
         Some(s) => s,
         None => {
             // Create default statistics if none exist
-            create_default_user_statistics(pool, user_id).await?
+            create_default_user_statistics(&mut *conn, user_id).await?
         }
     };
 
+    // Total dan rata-rata harian dihitung dari saldo target milik user dalam mata uang utamanya
+    let user = sqlx::query!(
+        r#"
+        SELECT currency, GREATEST(CURRENT_DATE - created_at::date, 1) as "account_days!"
+        FROM users
+        WHERE id = $1
+        "#,
+        user_id
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+    let saved = converted_savings_total(&mut *conn, &user.currency, Some(user_id)).await?;
+    let daily_average = Money::from(saved.total.as_decimal() / BigDecimal::from(user.account_days));
+
     Ok(UserStatisticsResponse {
-        total_saved: stats.total_saved.as_ref().map(|v| v.to_string().parse().unwrap_or(0.0)).unwrap_or(0.0),
+        total_saved: saved.total,
         streak_days: stats.streak_days.unwrap_or(0),
-        daily_average: stats.daily_average.as_ref().map(|v| v.to_string().parse().unwrap_or(0.0)).unwrap_or(0.0),
+        daily_average,
         achievements_count: stats.achievements_count.unwrap_or(0),
         last_deposit_date: stats.last_deposit_date,
+        currency: saved.currency,
+        unconverted_currencies: saved.unconverted_currencies,
     })
 }
 
@@ -93,14 +145,7 @@ pub async fn get_user_achievements(
 
     let responses: Vec<AchievementResponse> = achievements
         .into_iter()
-        .map(|a| AchievementResponse {
-            id: a.id,
-            title: a.title,
-            description: a.description,
-            icon: a.icon,
-            icon_color: a.icon_color,
-            earned_at: a.earned_at.unwrap_or_else(|| chrono::Utc::now()),
-        })
+        .map(AchievementResponse::from)
         .collect();
 
     Ok(responses)
@@ -114,44 +159,39 @@ pub async fn get_user_streak_data(
     // Get current streak from database (for reference)
     let _stats = get_user_statistics(pool, user_id).await?;
     
-    // Get deposit activities for the specified number of days FROM TODAY
-    // Always use current date as reference point
+    // Hari dihitung di zona waktu Indonesia (UTC+7), baik "hari ini" maupun tanggal deposit,
+    // supaya streak tidak bergeser antara jam 17:00-24:00 UTC
+    let today = chrono::Utc::now()
+        .with_timezone(&chrono::FixedOffset::east_opt(7 * 3600).unwrap())
+        .date_naive();
+
     let deposit_dates = sqlx::query!(
         r#"
-        SELECT DATE(created_at) as deposit_date, SUM(amount) as total_amount
+        SELECT (created_at AT TIME ZONE 'Asia/Jakarta')::date as deposit_date, SUM(amount) as total_amount
         FROM activities 
         WHERE user_id = $1 
         AND activity_type = 'deposit'
-        AND DATE(created_at) >= CURRENT_DATE - INTERVAL '1 day' * ($2 - 1)
-        AND DATE(created_at) <= CURRENT_DATE
-        GROUP BY DATE(created_at)
+        AND (created_at AT TIME ZONE 'Asia/Jakarta')::date >= $3::date - ($2 - 1)::integer
+        AND (created_at AT TIME ZONE 'Asia/Jakarta')::date <= $3::date
+        GROUP BY 1
         ORDER BY deposit_date ASC
         "#,
         user_id,
-        days as i64
+        days,
+        today
     )
     .fetch_all(pool)
     .await?;
 
-    println!("Deposit dates from DB: {:?}", deposit_dates); // Debug log
-
     // Create deposit map for quick lookup
     let mut deposit_map = std::collections::HashMap::new();
     for record in deposit_dates {
         if let (Some(date), Some(amount)) = (record.deposit_date, record.total_amount) {
-            let amount_f64 = amount.to_string().parse::<f64>().unwrap_or(0.0);
-            deposit_map.insert(date, amount_f64);
+            deposit_map.insert(date, Money::from(amount));
         }
     }
 
-    println!("Deposit map: {:?}", deposit_map); // Debug log
-
     // Calculate actual consecutive streak from today backwards
-    // Force use current date in Indonesia timezone (UTC+7)
-    let today = chrono::Utc::now()
-        .with_timezone(&chrono::FixedOffset::east_opt(7 * 3600).unwrap())
-        .date_naive();
-    println!("Today in backend: {}", today); // Debug log
     let mut actual_streak = 0;
     let mut check_date = today;
     
@@ -159,7 +199,7 @@ pub async fn get_user_streak_data(
     loop {
         if deposit_map.contains_key(&check_date) {
             actual_streak += 1;
-            check_date = check_date - chrono::Duration::days(1);
+            check_date -= chrono::Duration::days(1);
         } else {
             break;
         }
@@ -174,7 +214,7 @@ pub async fn get_user_streak_data(
     for i in 0..days {
         let date = start_date + chrono::Duration::days(i as i64);
         let has_deposit = deposit_map.contains_key(&date);
-        let deposit_amount = deposit_map.get(&date).copied();
+        let deposit_amount = deposit_map.get(&date).cloned();
         let is_today = date == today;
         
         // A day is part of streak if:
@@ -199,20 +239,15 @@ pub async fn get_user_streak_data(
 }
 
 pub async fn update_user_statistics_after_deposit(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     user_id: Uuid,
-    deposit_amount: f64,
-) -> Result<()> {
-    let decimal_amount = BigDecimal::from_f64(deposit_amount)
-        .ok_or_else(|| anyhow::anyhow!("Invalid deposit amount"))?;
-
-    // Update statistics
+) -> Result<DepositStatisticsUpdate> {
+    // Total dan rata-rata harian dihitung saat dibaca, di sini cukup streak
     sqlx::query!(
         r#"
-        INSERT INTO user_statistics (user_id, total_saved, streak_days, daily_average, achievements_count, last_deposit_date)
-        VALUES ($1, $2, 1, $2, 0, CURRENT_DATE)
+        INSERT INTO user_statistics (user_id, streak_days, achievements_count, last_deposit_date)
+        VALUES ($1, 1, 0, CURRENT_DATE)
         ON CONFLICT (user_id) DO UPDATE SET
-            total_saved = user_statistics.total_saved + $2,
             last_deposit_date = CURRENT_DATE,
             streak_days = CASE 
                 WHEN user_statistics.last_deposit_date = CURRENT_DATE - INTERVAL '1 day' 
@@ -221,72 +256,73 @@ pub async fn update_user_statistics_after_deposit(
                 THEN user_statistics.streak_days
                 ELSE 1
             END,
-            daily_average = (user_statistics.total_saved + $2) / GREATEST(
-                (CURRENT_DATE - (SELECT created_at::date FROM users WHERE id = $1))::integer,
-                1
-            ),
             updated_at = NOW()
         "#,
-        user_id,
-        decimal_amount
+        user_id
     )
-    .execute(pool)
+    .execute(&mut *conn)
     .await?;
 
     // Check for new achievements
-    check_and_award_achievements(pool, user_id).await?;
+    let new_achievements = check_and_award_achievements(&mut *conn, user_id).await?;
+    let statistics = fetch_user_statistics(&mut *conn, user_id).await?;
 
-    Ok(())
+    Ok(DepositStatisticsUpdate {
+        statistics,
+        new_achievements,
+    })
 }
 
 async fn create_default_user_statistics(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     user_id: Uuid,
 ) -> Result<UserStatistics> {
     let stats = sqlx::query_as!(
         UserStatistics,
         r#"
-        INSERT INTO user_statistics (user_id, total_saved, streak_days, daily_average, achievements_count, last_deposit_date)
-        VALUES ($1, 0, 0, 0, 0, NULL)
-        RETURNING id, user_id, total_saved, streak_days, daily_average, achievements_count, 
+        INSERT INTO user_statistics (user_id, streak_days, achievements_count, last_deposit_date)
+        VALUES ($1, 0, 0, NULL)
+        RETURNING id, user_id, streak_days, achievements_count,
                   last_deposit_date, created_at, updated_at
         "#,
         user_id
     )
-    .fetch_one(pool)
+    .fetch_one(&mut *conn)
     .await?;
 
     Ok(stats)
 }
 
 async fn check_and_award_achievements(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     user_id: Uuid,
-) -> Result<()> {
-    let stats = get_user_statistics(pool, user_id).await?;
+) -> Result<Vec<AchievementResponse>> {
+    let stats = fetch_user_statistics(&mut *conn, user_id).await?;
+    let mut awarded = Vec::new();
 
     // Check for streak achievements
     if stats.streak_days >= 10 {
-        award_achievement_if_not_exists(
-            pool,
+        awarded.extend(award_achievement_if_not_exists(
+            &mut *conn,
             user_id,
             "Streak 10 Hari!".to_string(),
             "Konsisten menabung 10 hari berturut-turut".to_string(),
             "🏆".to_string(),
             "bg-yellow-500".to_string(),
-        ).await?;
+        ).await?);
     }
 
-    // Check for total saved achievements
-    if stats.total_saved >= 10_000_000.0 {
-        award_achievement_if_not_exists(
-            pool,
+    // Check for total saved achievements, ambangnya dalam rupiah apa pun mata uang utama user
+    let saved_idr = converted_savings_total(&mut *conn, "IDR", Some(user_id)).await?;
+    if saved_idr.total >= Money::from(BigDecimal::from(10_000_000)) {
+        awarded.extend(award_achievement_if_not_exists(
+            &mut *conn,
             user_id,
             "RP 10M+".to_string(),
             "Total tabungan yang terkumpul mencapai 10M+".to_string(),
             "💰".to_string(),
             "bg-green-500".to_string(),
-        ).await?;
+        ).await?);
     }
 
     // Check for completed targets achievement
@@ -294,67 +330,73 @@ async fn check_and_award_achievements(
         "SELECT COUNT(*) FROM savings_targets WHERE user_id = $1 AND is_completed = true",
         user_id
     )
-    .fetch_one(pool)
+    .fetch_one(&mut *conn)
     .await?;
 
     if completed_targets.unwrap_or(0) >= 3 {
-        award_achievement_if_not_exists(
-            pool,
+        awarded.extend(award_achievement_if_not_exists(
+            &mut *conn,
             user_id,
             "Target Master".to_string(),
             "Berhasil mencapai 3 target tabungan".to_string(),
             "🎯".to_string(),
             "bg-red-500".to_string(),
-        ).await?;
+        ).await?);
     }
 
-    Ok(())
+    Ok(awarded)
 }
 
 async fn award_achievement_if_not_exists(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     user_id: Uuid,
     title: String,
     description: String,
     icon: String,
     icon_color: String,
-) -> Result<()> {
+) -> Result<Option<AchievementResponse>> {
     // Check if achievement already exists
     let exists = sqlx::query_scalar!(
         "SELECT EXISTS(SELECT 1 FROM achievements WHERE user_id = $1 AND title = $2)",
         user_id,
         title
     )
-    .fetch_one(pool)
+    .fetch_one(&mut *conn)
     .await?;
 
-    if !exists.unwrap_or(false) {
-        sqlx::query!(
-            r#"
-            INSERT INTO achievements (user_id, title, description, icon, icon_color)
-            VALUES ($1, $2, $3, $4, $5)
-            "#,
-            user_id,
-            title,
-            description,
-            icon,
-            icon_color
-        )
-        .execute(pool)
-        .await?;
-
-        // Update achievements count
-        sqlx::query!(
-            r#"
-            UPDATE user_statistics 
-            SET achievements_count = achievements_count + 1
-            WHERE user_id = $1
-            "#,
-            user_id
-        )
-        .execute(pool)
-        .await?;
+    if exists.unwrap_or(false) {
+        return Ok(None);
     }
 
-    Ok(())
+    let achievement = sqlx::query_as!(
+        Achievement,
+        r#"
+        INSERT INTO achievements (user_id, title, description, icon, icon_color)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, user_id, title, description, icon, icon_color, earned_at
+        "#,
+        user_id,
+        title,
+        description,
+        icon,
+        icon_color
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    notify_achievement(&mut *conn, user_id, &achievement.title).await?;
+
+    // Update achievements count
+    sqlx::query!(
+        r#"
+        UPDATE user_statistics 
+        SET achievements_count = achievements_count + 1
+        WHERE user_id = $1
+        "#,
+        user_id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(Some(AchievementResponse::from(achievement)))
 }
@@ -6,8 +6,8 @@ pub async fn get_testimoni_by_id(
         r#"
         SELECT 
             t.id, t.content, t.rating, t.is_approved, t.created_at,
-            u.id as user_id, u.full_name, u.email, u.avatar, u.is_admin, u.created_at as user_created_at,
-            u.nomor_telepon, u.alamat, u.posisi_jabatan
+            u.id as user_id, u.full_name, u.email, u.avatar, u.role, u.created_at as user_created_at,
+            u.nomor_telepon, u.alamat, u.posisi_jabatan, u.currency
         FROM testimoni t
         JOIN users u ON t.user_id = u.id
         WHERE t.id = $1
@@ -16,35 +16,39 @@ pub async fn get_testimoni_by_id(
     )
     .fetch_optional(pool)
     .await?
-    .ok_or_else(|| anyhow!("Testimoni not found"))?;
+    .ok_or_else(|| AppError::NotFound("Testimoni not found".to_string()))?;
 
     Ok(TestimoniWithUser {
         id: row.id,
         content: row.content,
         rating: row.rating,
         is_approved: row.is_approved.unwrap_or(false),
-        created_at: row.created_at.unwrap_or_else(|| chrono::Utc::now()),
+        created_at: row.created_at.unwrap_or_else(chrono::Utc::now),
         user: UserResponse {
             id: row.user_id,
             full_name: row.full_name,
             email: row.email,
             avatar: row.avatar,
-            is_admin: row.is_admin.unwrap_or(false),
+            is_admin: row.role == Role::Admin.as_str(),
+            role: row.role,
             nomor_telepon: row.nomor_telepon,
             alamat: row.alamat,
             posisi_jabatan: row.posisi_jabatan,
-            created_at: row.user_created_at.unwrap_or_else(|| chrono::Utc::now()),
+            currency: row.currency,
+            created_at: row.user_created_at.unwrap_or_else(chrono::Utc::now),
         },
     })
 }
 use sqlx::PgPool;
 use uuid::Uuid;
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 
 use crate::models::{
     Testimoni, TestimoniWithUser, CreateTestimoniRequest, 
-    UpdateTestimoniRequest, UserResponse
+    UpdateTestimoniRequest, UserResponse, Role
 };
+use crate::utils::pagination::{Cursor, Page, PageRequest};
+use crate::errors::AppError;
 
 pub async fn create_testimoni(
     pool: &PgPool,
@@ -70,21 +74,36 @@ pub async fn create_testimoni(
     Ok(testimoni)
 }
 
-pub async fn get_all_testimoni(pool: &PgPool) -> Result<Vec<TestimoniWithUser>> {
+/// List semua testimoni (moderator) dengan cursor (created_at, id)
+pub async fn get_all_testimoni(pool: &PgPool, page: &PageRequest) -> Result<Page<TestimoniWithUser>> {
     let testimoni = sqlx::query!(
         r#"
         SELECT 
             t.id, t.content, t.rating, t.is_approved, t.created_at,
-            u.id as user_id, u.full_name, u.email, u.avatar, u.is_admin, u.created_at as user_created_at,
-            u.nomor_telepon, u.alamat, u.posisi_jabatan
+            u.id as user_id, u.full_name, u.email, u.avatar, u.role, u.created_at as user_created_at,
+            u.nomor_telepon, u.alamat, u.posisi_jabatan, u.currency
         FROM testimoni t
         JOIN users u ON t.user_id = u.id
-        ORDER BY t.created_at DESC
-        "#
+        WHERE $1::timestamptz IS NULL OR CASE
+            WHEN $3 THEN (t.created_at, t.id) < ($1, $2::uuid)
+            ELSE (t.created_at, t.id) > ($1, $2::uuid)
+        END
+        ORDER BY CASE WHEN $3 THEN t.created_at END DESC, CASE WHEN $3 THEN t.id END DESC,
+                 t.created_at ASC, t.id ASC
+        LIMIT $4
+        "#,
+        page.cursor_key(),
+        page.cursor_id(),
+        page.sort.descending,
+        page.fetch_limit()
     )
     .fetch_all(pool)
     .await?;
 
+    let total = sqlx::query_scalar!(r#"SELECT COUNT(*) as "count!" FROM testimoni"#)
+        .fetch_one(pool)
+        .await?;
+
     let result = testimoni
         .into_iter()
         .map(|row| TestimoniWithUser {
@@ -92,22 +111,27 @@ pub async fn get_all_testimoni(pool: &PgPool) -> Result<Vec<TestimoniWithUser>>
             content: row.content,
             rating: row.rating,
             is_approved: row.is_approved.unwrap_or(false),
-            created_at: row.created_at.unwrap_or_else(|| chrono::Utc::now()),
+            created_at: row.created_at.unwrap_or_else(chrono::Utc::now),
             user: UserResponse {
                 id: row.user_id,
                 full_name: row.full_name,
                 email: row.email,
                 avatar: row.avatar,
-                is_admin: row.is_admin.unwrap_or(false),
+                is_admin: row.role == Role::Admin.as_str(),
+            role: row.role,
                 nomor_telepon: row.nomor_telepon,
                 alamat: row.alamat,
                 posisi_jabatan: row.posisi_jabatan,
-                created_at: row.user_created_at.unwrap_or_else(|| chrono::Utc::now()),
+                currency: row.currency,
+                created_at: row.user_created_at.unwrap_or_else(chrono::Utc::now),
             },
         })
-        .collect();
+        .collect::<Vec<_>>();
 
-    Ok(result)
+    Ok(Page::from_rows(result, page, Some(total), |testimoni| Cursor {
+        key: testimoni.created_at,
+        id: testimoni.id,
+    }))
 }
 
 pub async fn get_approved_testimoni(pool: &PgPool) -> Result<Vec<TestimoniWithUser>> {
@@ -115,8 +139,8 @@ pub async fn get_approved_testimoni(pool: &PgPool) -> Result<Vec<TestimoniWithUs
         r#"
         SELECT 
             t.id, t.content, t.rating, t.is_approved, t.created_at,
-            u.id as user_id, u.full_name, u.email, u.avatar, u.is_admin, u.created_at as user_created_at,
-            u.nomor_telepon, u.alamat, u.posisi_jabatan
+            u.id as user_id, u.full_name, u.email, u.avatar, u.role, u.created_at as user_created_at,
+            u.nomor_telepon, u.alamat, u.posisi_jabatan, u.currency
         FROM testimoni t
         JOIN users u ON t.user_id = u.id
         WHERE t.is_approved = true
@@ -133,17 +157,19 @@ pub async fn get_approved_testimoni(pool: &PgPool) -> Result<Vec<TestimoniWithUs
             content: row.content,
             rating: row.rating,
             is_approved: row.is_approved.unwrap_or(false),
-            created_at: row.created_at.unwrap_or_else(|| chrono::Utc::now()),
+            created_at: row.created_at.unwrap_or_else(chrono::Utc::now),
             user: UserResponse {
                 id: row.user_id,
                 full_name: row.full_name,
                 email: row.email,
                 avatar: row.avatar,
-                is_admin: row.is_admin.unwrap_or(false),
+                is_admin: row.role == Role::Admin.as_str(),
+            role: row.role,
                 nomor_telepon: row.nomor_telepon,
                 alamat: row.alamat,
                 posisi_jabatan: row.posisi_jabatan,
-                created_at: row.user_created_at.unwrap_or_else(|| chrono::Utc::now()),
+                currency: row.currency,
+                created_at: row.user_created_at.unwrap_or_else(chrono::Utc::now),
             },
         })
         .collect();
@@ -169,7 +195,7 @@ pub async fn update_testimoni(
     pool: &PgPool,
     testimoni_id: Uuid,
     user_id: Uuid,
-    is_admin: bool,
+    role: Role,
     request: &UpdateTestimoniRequest,
 ) -> Result<Testimoni> {
     // Check if testimoni exists and belongs to user (or user is admin)
@@ -179,11 +205,23 @@ pub async fn update_testimoni(
     )
     .fetch_optional(pool)
     .await?
-    .ok_or_else(|| anyhow!("Testimoni not found"))?;
+    .ok_or_else(|| AppError::NotFound("Testimoni not found".to_string()))?;
 
-    // Allow update if owner or admin
-    if existing.user_id != user_id && !is_admin {
-        return Err(anyhow!("Access denied"));
+    // Allow update if owner, admin or moderator
+    let is_owner = existing.user_id == user_id;
+    if !is_owner && !role.can_moderate_testimoni() {
+        return Err(AppError::Forbidden("Access denied".to_string()).into());
+    }
+
+    // Isi testimoni hanya boleh diubah pemilik atau admin
+    let edits_content = request.content.is_some() || request.rating.is_some();
+    if edits_content && !is_owner && !role.is_admin() {
+        return Err(AppError::Forbidden("Access denied".to_string()).into());
+    }
+
+    // Approval hanya boleh diubah admin atau moderator
+    if request.is_approved.is_some() && !role.can_moderate_testimoni() {
+        return Err(AppError::Forbidden("Access denied".to_string()).into());
     }
 
     let mut query = String::from("UPDATE testimoni SET updated_at = NOW()");
@@ -202,7 +240,7 @@ pub async fn update_testimoni(
         param_count += 1;
     }
 
-    if let Some(is_approved) = &request.is_approved {
+    if request.is_approved.is_some() {
         query.push_str(&format!(", is_approved = ${}", param_count));
         param_count += 1;
     }
@@ -210,26 +248,22 @@ pub async fn update_testimoni(
     query.push_str(&format!(" WHERE id = ${} RETURNING *", param_count));
 
     let mut query_builder = sqlx::query_as::<_, Testimoni>(&query);
-    
-    let mut param_index = 0;
+
     if let Some(content) = &request.content {
         query_builder = query_builder.bind(content);
-        param_index += 1;
     }
     if let Some(rating) = &request.rating {
         query_builder = query_builder.bind(rating);
-        param_index += 1;
     }
     if let Some(is_approved) = &request.is_approved {
         query_builder = query_builder.bind(*is_approved);
-        param_index += 1;
     }
     query_builder = query_builder.bind(testimoni_id);
 
     let testimoni = query_builder
         .fetch_optional(pool)
         .await?
-        .ok_or_else(|| anyhow!("Testimoni not found"))?;
+        .ok_or_else(|| AppError::NotFound("Testimoni not found".to_string()))?;
 
     Ok(testimoni)
 }
@@ -238,7 +272,7 @@ pub async fn delete_testimoni(
     pool: &PgPool,
     testimoni_id: Uuid,
     user_id: Uuid,
-    is_admin: bool,
+    role: Role,
 ) -> Result<()> {
     // Check if testimoni exists and belongs to user (or user is admin)
     let existing = sqlx::query!(
@@ -247,11 +281,11 @@ pub async fn delete_testimoni(
     )
     .fetch_optional(pool)
     .await?
-    .ok_or_else(|| anyhow!("Testimoni not found"))?;
+    .ok_or_else(|| AppError::NotFound("Testimoni not found".to_string()))?;
 
     // Allow delete if owner or admin
-    if existing.user_id != user_id && !is_admin {
-        return Err(anyhow!("Access denied"));
+    if existing.user_id != user_id && !role.is_admin() {
+        return Err(AppError::Forbidden("Access denied".to_string()).into());
     }
 
     sqlx::query!(
@@ -1,8 +1,10 @@
 use sqlx::PgPool;
 use uuid::Uuid;
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 
-use crate::models::{User, UserResponse, UpdateUserRequest};
+use crate::models::{User, UserResponse, UpdateUserRequest, Role};
+use crate::utils::pagination::{Cursor, Page, PageRequest, SortField};
+use crate::errors::AppError;
 
 pub async fn get_user_profile(
     pool: &PgPool,
@@ -14,7 +16,7 @@ pub async fn get_user_profile(
     .bind(user_id)
     .fetch_optional(pool)
     .await?
-    .ok_or_else(|| anyhow!("User not found"))?;
+    .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
     Ok(user.into())
 }
@@ -53,6 +55,11 @@ pub async fn update_user_profile(
         params.push(posisi_jabatan.clone());
         param_count += 1;
     }
+    if let Some(currency) = &request.currency {
+        query.push_str(&format!(", currency = ${}", param_count));
+        params.push(currency.clone());
+        param_count += 1;
+    }
     query.push_str(&format!(" WHERE id = ${} RETURNING *", param_count));
 
     let mut query_builder = sqlx::query_as::<_, User>(&query);
@@ -64,17 +71,65 @@ pub async fn update_user_profile(
     let user = query_builder
         .fetch_optional(pool)
         .await?
-        .ok_or_else(|| anyhow!("User not found"))?;
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
     Ok(user.into())
 }
 
-pub async fn get_all_users(pool: &PgPool) -> Result<Vec<UserResponse>> {
+/// List semua user (admin) dengan cursor, sort berdasarkan created_at atau updated_at
+pub async fn get_all_users(pool: &PgPool, page: &PageRequest) -> Result<Page<UserResponse>> {
     let users = sqlx::query_as::<_, User>(
-        "SELECT * FROM users ORDER BY created_at DESC"
+        r#"
+        SELECT * FROM (
+            SELECT *, CASE WHEN $3 = 'updated_at' THEN updated_at ELSE created_at END AS sort_key
+            FROM users
+        ) u
+        WHERE $1::timestamptz IS NULL OR CASE
+            WHEN $4 THEN (sort_key, id) < ($1, $2::uuid)
+            ELSE (sort_key, id) > ($1, $2::uuid)
+        END
+        ORDER BY CASE WHEN $4 THEN sort_key END DESC, CASE WHEN $4 THEN id END DESC,
+                 sort_key ASC, id ASC
+        LIMIT $5
+        "#
     )
+    .bind(page.cursor_key())
+    .bind(page.cursor_id())
+    .bind(page.sort.field.as_str())
+    .bind(page.sort.descending)
+    .bind(page.fetch_limit())
     .fetch_all(pool)
     .await?;
 
-    Ok(users.into_iter().map(|user| user.into()).collect())
+    let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(pool)
+        .await?;
+
+    let sort_field = page.sort.field;
+    let page = Page::from_rows(users, page, Some(total), |user| Cursor {
+        key: match sort_field {
+            SortField::UpdatedAt => user.updated_at,
+            _ => user.created_at,
+        },
+        id: user.id,
+    });
+
+    Ok(page.map(UserResponse::from))
+}
+
+pub async fn update_user_role(
+    pool: &PgPool,
+    user_id: Uuid,
+    role: Role,
+) -> Result<UserResponse> {
+    let user = sqlx::query_as::<_, User>(
+        "UPDATE users SET role = $1, updated_at = NOW() WHERE id = $2 RETURNING *"
+    )
+    .bind(role.as_str())
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+    Ok(user.into())
 }
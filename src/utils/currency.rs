@@ -0,0 +1,182 @@
+use bigdecimal::{BigDecimal, RoundingMode};
+use serde::Serialize;
+use utoipa::ToSchema;
+use validator::{ValidationError, ValidationErrors};
+
+use crate::errors::AppError;
+use crate::utils::money::Money;
+
+/// Mata uang default untuk user dan target baru
+pub const DEFAULT_CURRENCY: &str = "IDR";
+
+/// Metadata mata uang ISO 4217 yang didukung. `minor_units` adalah jumlah digit desimal
+/// saat ditampilkan; kolom nominal di database DECIMAL(15,2), jadi mata uang dengan
+/// 3 digit minor (KWD, BHD, ...) sengaja tidak didukung.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, ToSchema)]
+pub struct Currency {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub symbol: &'static str,
+    pub minor_units: u32,
+    #[serde(skip)]
+    pub thousands_separator: char,
+    #[serde(skip)]
+    pub decimal_separator: char,
+}
+
+const fn currency(
+    code: &'static str,
+    name: &'static str,
+    symbol: &'static str,
+    minor_units: u32,
+    thousands_separator: char,
+    decimal_separator: char,
+) -> Currency {
+    Currency { code, name, symbol, minor_units, thousands_separator, decimal_separator }
+}
+
+// IDR secara ISO punya 2 digit minor, tapi sen tidak dipakai lagi sehingga ditampilkan tanpa desimal
+pub const CURRENCIES: &[Currency] = &[
+    currency("IDR", "Rupiah Indonesia", "Rp", 0, '.', ','),
+    currency("USD", "US Dollar", "$", 2, ',', '.'),
+    currency("EUR", "Euro", "€", 2, '.', ','),
+    currency("SGD", "Dolar Singapura", "S$", 2, ',', '.'),
+    currency("MYR", "Ringgit Malaysia", "RM", 2, ',', '.'),
+    currency("AUD", "Dolar Australia", "A$", 2, ',', '.'),
+    currency("GBP", "Pound Sterling", "£", 2, ',', '.'),
+    currency("JPY", "Yen Jepang", "¥", 0, ',', '.'),
+    currency("CNY", "Yuan Tiongkok", "CN¥", 2, ',', '.'),
+    currency("KRW", "Won Korea", "₩", 0, ',', '.'),
+    currency("THB", "Baht Thailand", "฿", 2, ',', '.'),
+    currency("SAR", "Riyal Saudi", "SAR", 2, ',', '.'),
+];
+
+impl Currency {
+    /// Cari mata uang yang didukung berdasarkan kode ISO 4217 (harus huruf besar)
+    pub fn find(code: &str) -> Option<&'static Currency> {
+        CURRENCIES.iter().find(|c| c.code == code)
+    }
+
+    /// Apakah nominal bisa ditulis tanpa pembulatan dengan digit minor mata uang ini
+    pub fn fits_minor_units(&self, amount: &BigDecimal) -> bool {
+        let (_, exponent) = amount.normalized().as_bigint_and_exponent();
+        exponent <= self.minor_units as i64
+    }
+
+    /// Format nominal sesuai digit minor dan pemisah mata uang ini, misal "Rp 1.500.000"
+    /// atau "$1,500.50". Spasi setelah simbol hanya kalau simbolnya berupa huruf.
+    pub fn format(&self, amount: &BigDecimal) -> String {
+        let rounded = amount
+            .with_scale_round(self.minor_units as i64, RoundingMode::HalfUp)
+            .to_string();
+        let (sign, digits) = match rounded.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", rounded.as_str()),
+        };
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+        let mut grouped = String::new();
+        for (i, c) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                grouped.push(self.thousands_separator);
+            }
+            grouped.push(c);
+        }
+        if !fraction.is_empty() {
+            grouped.push(self.decimal_separator);
+            grouped.push_str(fraction);
+        }
+
+        let separator = match self.symbol.chars().last() {
+            Some(c) if c.is_alphabetic() => " ",
+            _ => "",
+        };
+        format!("{}{}{}{}", sign, self.symbol, separator, grouped)
+    }
+}
+
+/// Format nominal dengan kode mata uang dari database. Kode yang tidak ada di daftar
+/// (seharusnya tidak terjadi) ditampilkan apa adanya dengan 2 desimal.
+pub fn format_money(amount: &BigDecimal, currency_code: &str) -> String {
+    match Currency::find(currency_code) {
+        Some(currency) => currency.format(amount),
+        None => format!("{} {}", currency_code, amount.with_scale_round(2, RoundingMode::HalfUp)),
+    }
+}
+
+/// Validator untuk field `currency` di request: kode ISO 4217 yang didukung aplikasi
+pub fn validate_currency(code: &str) -> Result<(), ValidationError> {
+    if Currency::find(code).is_some() {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("currency");
+        error.message = Some(format!("Unsupported currency code: {}", code).into());
+        Err(error)
+    }
+}
+
+/// Tolak nominal yang desimalnya melebihi digit minor mata uang target (misal Rp 250000.50),
+/// sebagai error validasi pada `field` seperti error validator lainnya. Hanya bisa dicek di
+/// service karena mata uangnya ikut target, bukan request.
+pub fn check_minor_units(field: &'static str, amount: &Money, currency_code: &str) -> Result<(), AppError> {
+    let Some(currency) = Currency::find(currency_code) else {
+        return Ok(());
+    };
+    if currency.fits_minor_units(amount.as_decimal()) {
+        return Ok(());
+    }
+
+    let mut error = ValidationError::new("minor_units");
+    error.message = Some(match currency.minor_units {
+        0 => format!("{} amounts cannot have decimal places", currency.code),
+        n => format!("{} amounts must have at most {} decimal places", currency.code, n),
+    }.into());
+    let mut errors = ValidationErrors::new();
+    errors.add(field, error);
+    Err(AppError::Validation(errors))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn amount(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn formats_with_minor_units_and_separators() {
+        assert_eq!(format_money(&amount("1500000"), "IDR"), "Rp 1.500.000");
+        assert_eq!(format_money(&amount("1234.5"), "USD"), "$1,234.50");
+        assert_eq!(format_money(&amount("1234567.891"), "EUR"), "€1.234.567,89");
+        assert_eq!(format_money(&amount("980"), "MYR"), "RM 980.00");
+        assert_eq!(format_money(&amount("-12000"), "JPY"), "-¥12,000");
+        assert_eq!(format_money(&amount("0.2"), "USD"), "$0.20");
+    }
+
+    #[test]
+    fn rejects_amounts_finer_than_minor_units() {
+        let money = |value: &str| value.parse::<Money>().unwrap();
+
+        assert!(check_minor_units("amount", &money("250000"), "IDR").is_ok());
+        assert!(check_minor_units("amount", &money("250000.00"), "IDR").is_ok());
+        assert!(check_minor_units("amount", &money("12.5"), "USD").is_ok());
+
+        let error = check_minor_units("amount", &money("250000.50"), "IDR").unwrap_err();
+        let body = error.body();
+        assert_eq!(body.code, "validation_failed");
+        assert_eq!(body.fields.unwrap()["amount"][0].code, "minor_units");
+        assert!(check_minor_units("amount", &money("0.01"), "JPY").is_err());
+        assert!(check_minor_units("amount", &money("1000.1"), "KRW").is_err());
+    }
+
+    #[test]
+    fn unknown_currency_falls_back_to_code() {
+        assert_eq!(format_money(&amount("10"), "XYZ"), "XYZ 10.00");
+        assert!(validate_currency("XYZ").is_err());
+        assert!(validate_currency("usd").is_err());
+        assert!(validate_currency("USD").is_ok());
+    }
+}
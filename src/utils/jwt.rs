@@ -8,22 +8,34 @@ use chrono::{Utc, Duration};
 pub struct Claims {
     pub user_id: Uuid,
     pub email: String,
-    pub is_admin: bool,
+    pub role: String,
+    pub sid: Uuid, // ID session di tabel user_sessions
     pub exp: usize,
     pub iat: usize,
 }
 
-pub fn generate_jwt_token(user_id: Uuid, email: &str, is_admin: bool) -> Result<String> {
+/// Umur access token, default 15 menit (bisa diatur lewat ACCESS_TOKEN_TTL_MINUTES)
+pub fn access_token_ttl() -> Duration {
+    let minutes = std::env::var("ACCESS_TOKEN_TTL_MINUTES")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(15);
+
+    Duration::minutes(minutes)
+}
+
+pub fn generate_jwt_token(user_id: Uuid, email: &str, role: &str, session_id: Uuid) -> Result<String> {
     let secret = std::env::var("JWT_SECRET")
         .map_err(|_| anyhow!("JWT_SECRET not found in environment"))?;
 
     let now = Utc::now();
-    let exp = now + Duration::hours(24); // Token expires in 24 hours
+    let exp = now + access_token_ttl();
 
     let claims = Claims {
         user_id,
         email: email.to_owned(),
-        is_admin,
+        role: role.to_owned(),
+        sid: session_id,
         exp: exp.timestamp() as usize,
         iat: now.timestamp() as usize,
     };
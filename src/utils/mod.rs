@@ -1,2 +1,6 @@
 pub mod jwt;
 pub mod response;
+pub mod token;
+pub mod pagination;
+pub mod currency;
+pub mod money;
@@ -0,0 +1,267 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Neg, Sub};
+use std::str::FromStr;
+
+use bigdecimal::{BigDecimal, RoundingMode, ToPrimitive, Zero};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
+use utoipa::ToSchema;
+use validator::ValidationError;
+
+/// Digit desimal kolom nominal (DECIMAL(15,2))
+pub const SCALE: i64 = 2;
+/// Digit maksimal sebelum koma (15 - 2)
+pub const MAX_INTEGER_DIGITS: u32 = 13;
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum MoneyError {
+    #[error("invalid amount '{0}'")]
+    Invalid(String),
+    #[error("amount must have at most {SCALE} decimal places")]
+    TooManyDecimals,
+    #[error("amount must be less than 10^{MAX_INTEGER_DIGITS}")]
+    TooLarge,
+}
+
+/// Nominal uang exact di atas `BigDecimal`, selalu dengan skala 2 seperti kolom DECIMAL(15,2).
+/// Di JSON ditulis sebagai string ("250000.00"); input boleh string atau angka, nilai dengan
+/// lebih dari 2 desimal atau di luar batas kolom ditolak, bukan dibulatkan.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, ToSchema)]
+#[schema(value_type = String, example = "250000.00")]
+pub struct Money(BigDecimal);
+
+impl Money {
+    /// Validasi skala dan besar nominal dari input user
+    pub fn new(value: BigDecimal) -> Result<Self, MoneyError> {
+        let (_, exponent) = value.normalized().as_bigint_and_exponent();
+        if exponent > SCALE {
+            return Err(MoneyError::TooManyDecimals);
+        }
+        if value.abs() >= BigDecimal::from(10u64.pow(MAX_INTEGER_DIGITS)) {
+            return Err(MoneyError::TooLarge);
+        }
+        Ok(Self(value.with_scale(SCALE)))
+    }
+
+    pub fn zero() -> Self {
+        Self(BigDecimal::zero().with_scale(SCALE))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.0 > BigDecimal::zero()
+    }
+
+    pub fn as_decimal(&self) -> &BigDecimal {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> BigDecimal {
+        self.0
+    }
+
+    /// Nominal dalam satuan terkecil (sen), misal 1500.25 -> 150025
+    pub fn to_minor_units(&self) -> i64 {
+        (&self.0 * BigDecimal::from(100)).to_i64().unwrap_or_default()
+    }
+
+    /// Persentase `self` terhadap `total`, 0 kalau total nol. Hanya untuk tampilan.
+    pub fn percent_of(&self, total: &Money) -> f64 {
+        if total.is_zero() {
+            return 0.0;
+        }
+        (&self.0 * BigDecimal::from(100) / &total.0).to_f64().unwrap_or_default()
+    }
+}
+
+impl Default for Money {
+    fn default() -> Self {
+        Money::zero()
+    }
+}
+
+/// Untuk nilai yang sudah pasti dalam batas kolom (hasil query atau hitungan dari nominal
+/// yang valid), dibulatkan ke 2 desimal tanpa validasi
+impl From<BigDecimal> for Money {
+    fn from(value: BigDecimal) -> Self {
+        Self(value.with_scale_round(SCALE, RoundingMode::HalfUp))
+    }
+}
+
+impl From<Money> for BigDecimal {
+    fn from(money: Money) -> Self {
+        money.0
+    }
+}
+
+impl sqlx::Type<Postgres> for Money {
+    fn type_info() -> PgTypeInfo {
+        <BigDecimal as sqlx::Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <BigDecimal as sqlx::Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl sqlx::Encode<'_, Postgres> for Money {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <BigDecimal as sqlx::Encode<Postgres>>::encode_by_ref(&self.0, buf)
+    }
+}
+
+// Hasil hitungan SQL (SUM, perkalian kurs) bisa punya skala lain, disamakan ke 2 desimal
+impl sqlx::Decode<'_, Postgres> for Money {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(<BigDecimal as sqlx::Decode<Postgres>>::decode(value)?.into())
+    }
+}
+
+impl FromStr for Money {
+    type Err = MoneyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = BigDecimal::from_str(s.trim()).map_err(|_| MoneyError::Invalid(s.to_string()))?;
+        Self::new(value)
+    }
+}
+
+// Presisi eksplisit: BigDecimal menulis nol sebagai "0" walaupun skalanya 2
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.*}", SCALE as usize, self.0)
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, rhs: Money) -> Money {
+        Money(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, rhs: Money) -> Money {
+        Money(self.0 - rhs.0)
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money(-self.0)
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Self {
+        iter.fold(Money::zero(), Add::add)
+    }
+}
+
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MoneyVisitor;
+
+        impl Visitor<'_> for MoneyVisitor {
+            type Value = Money;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a decimal amount as string or number")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Money, E> {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Money, E> {
+                Money::new(BigDecimal::from(v)).map_err(E::custom)
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Money, E> {
+                Money::new(BigDecimal::from(v)).map_err(E::custom)
+            }
+
+            // Angka JSON pecahan dibaca dari representasi desimal terpendeknya (0.1 -> "0.1"),
+            // bukan nilai binernya
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Money, E> {
+                if !v.is_finite() {
+                    return Err(E::custom(MoneyError::Invalid(v.to_string())));
+                }
+                v.to_string().parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(MoneyVisitor)
+    }
+}
+
+/// Validator `#[validate(custom(function = "validate_positive"))]` untuk nominal di request
+pub fn validate_positive(amount: &Money) -> Result<(), ValidationError> {
+    if amount.is_positive() {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("positive");
+        error.message = Some("Amount must be greater than 0".into());
+        Err(error)
+    }
+}
+
+/// Validator untuk nominal yang boleh nol tapi tidak negatif
+pub fn validate_non_negative(amount: &Money) -> Result<(), ValidationError> {
+    if amount.is_positive() || amount.is_zero() {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("non_negative");
+        error.message = Some("Amount cannot be negative".into());
+        Err(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn accepts_strings_and_numbers_with_two_decimals() {
+        let from_string: Money = serde_json::from_value(json!("1500.5")).unwrap();
+        let from_integer: Money = serde_json::from_value(json!(1500)).unwrap();
+        let from_float: Money = serde_json::from_value(json!(0.1)).unwrap();
+
+        assert_eq!(serde_json::to_value(&from_string).unwrap(), json!("1500.50"));
+        assert_eq!(serde_json::to_value(&from_integer).unwrap(), json!("1500.00"));
+        assert_eq!(from_float.to_string(), "0.10");
+        assert_eq!(from_string.to_minor_units(), 150050);
+        assert_eq!("1.230".parse::<Money>().unwrap().to_string(), "1.23");
+        assert_eq!(Money::from(BigDecimal::zero()).to_string(), "0.00");
+    }
+
+    #[test]
+    fn rejects_extra_decimals_and_overflow() {
+        assert_eq!("10.005".parse::<Money>(), Err(MoneyError::TooManyDecimals));
+        assert_eq!("10000000000000".parse::<Money>(), Err(MoneyError::TooLarge));
+        assert!("9999999999999.99".parse::<Money>().is_ok());
+        assert!(matches!("abc".parse::<Money>(), Err(MoneyError::Invalid(_))));
+        assert!(serde_json::from_value::<Money>(json!(1e20)).is_err());
+        assert!(serde_json::from_value::<Money>(json!(true)).is_err());
+    }
+}
@@ -0,0 +1,228 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::ValidationError;
+
+use crate::errors::AppError;
+
+pub const DEFAULT_LIMIT: i64 = 20;
+pub const MAX_LIMIT: i64 = 100;
+
+/// Halaman terjauh untuk endpoint berbasis offset (`page`/`per_page`). Dengan `per_page` maksimal
+/// MAX_LIMIT, offset-nya jauh dari overflow i64.
+pub const MAX_PAGE: i64 = 1000;
+
+/// Validator `#[validate(custom(function = "validate_page"))]` untuk `page` di endpoint berbasis offset
+pub fn validate_page(page: i64) -> Result<(), ValidationError> {
+    if (1..=MAX_PAGE).contains(&page) {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("range");
+        error.message = Some(format!("Page must be between 1 and {}", MAX_PAGE).into());
+        Err(error)
+    }
+}
+
+/// OFFSET SQL untuk `page` yang sudah lolos validate_page
+pub fn page_offset(page: i64, per_page: i64) -> i64 {
+    (page - 1) * per_page
+}
+
+/// Kolom yang bisa dipakai untuk sorting list. Setiap endpoint menentukan kolom yang didukung.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortField {
+    CreatedAt,
+    UpdatedAt,
+    ReminderDate,
+}
+
+impl SortField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortField::CreatedAt => "created_at",
+            SortField::UpdatedAt => "updated_at",
+            SortField::ReminderDate => "reminder_date",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "created_at" => Some(SortField::CreatedAt),
+            "updated_at" => Some(SortField::UpdatedAt),
+            "reminder_date" => Some(SortField::ReminderDate),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sort {
+    pub field: SortField,
+    pub descending: bool,
+}
+
+impl Sort {
+    pub const fn desc(field: SortField) -> Self {
+        Sort { field, descending: true }
+    }
+
+    pub const fn asc(field: SortField) -> Self {
+        Sort { field, descending: false }
+    }
+}
+
+/// Posisi terakhir di list: nilai kolom sort + id sebagai tie-breaker
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cursor {
+    pub key: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl Cursor {
+    /// Token opaque untuk client (hex dari "key|id")
+    pub fn encode(&self) -> String {
+        hex::encode(format!("{}|{}", self.key.to_rfc3339(), self.id))
+    }
+
+    pub fn decode(token: &str) -> Result<Self, AppError> {
+        let invalid = || AppError::BadRequest("Invalid cursor".to_string());
+        let raw = String::from_utf8(hex::decode(token).map_err(|_| invalid())?).map_err(|_| invalid())?;
+        let (key, id) = raw.split_once('|').ok_or_else(invalid)?;
+
+        Ok(Cursor {
+            key: DateTime::parse_from_rfc3339(key).map_err(|_| invalid())?.with_timezone(&Utc),
+            id: Uuid::parse_str(id).map_err(|_| invalid())?,
+        })
+    }
+}
+
+/// Query string bersama untuk endpoint list: `?cursor=...&limit=20&sort=-created_at`.
+/// Prefix `-` pada sort berarti descending.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListQuery {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    pub sort: Option<String>,
+}
+
+/// ListQuery yang sudah divalidasi, siap dipakai di query SQL
+#[derive(Debug, Clone, Copy)]
+pub struct PageRequest {
+    pub cursor: Option<Cursor>,
+    pub limit: i64,
+    pub sort: Sort,
+}
+
+impl ListQuery {
+    pub fn parse(&self, allowed: &[SortField], default_sort: Sort) -> Result<PageRequest, AppError> {
+        let sort = match self.sort.as_deref().map(str::trim).filter(|sort| !sort.is_empty()) {
+            None => default_sort,
+            Some(sort) => {
+                let (name, descending) = match sort.strip_prefix('-') {
+                    Some(name) => (name, true),
+                    None => (sort, false),
+                };
+                let field = SortField::from_name(name)
+                    .filter(|field| allowed.contains(field))
+                    .ok_or_else(|| {
+                        let allowed: Vec<&str> = allowed.iter().map(SortField::as_str).collect();
+                        AppError::BadRequest(format!(
+                            "Unsupported sort field '{}', expected one of: {}",
+                            name,
+                            allowed.join(", ")
+                        ))
+                    })?;
+                Sort { field, descending }
+            }
+        };
+
+        let cursor = self.cursor.as_deref().map(Cursor::decode).transpose()?;
+
+        Ok(PageRequest {
+            cursor,
+            limit: self.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT),
+            sort,
+        })
+    }
+}
+
+impl PageRequest {
+    pub fn cursor_key(&self) -> Option<DateTime<Utc>> {
+        self.cursor.map(|cursor| cursor.key)
+    }
+
+    pub fn cursor_id(&self) -> Option<Uuid> {
+        self.cursor.map(|cursor| cursor.id)
+    }
+
+    /// Ambil satu baris lebih untuk tahu masih ada halaman berikutnya atau tidak
+    pub fn fetch_limit(&self) -> i64 {
+        self.limit + 1
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// None = sudah halaman terakhir
+    pub next_cursor: Option<String>,
+    pub total: Option<i64>,
+    pub limit: i64,
+}
+
+impl<T> Page<T> {
+    /// `rows` harus diambil dengan LIMIT `fetch_limit()`, `cursor_of` mengambil posisi item
+    /// sesuai kolom sort yang dipakai.
+    pub fn from_rows(
+        mut rows: Vec<T>,
+        request: &PageRequest,
+        total: Option<i64>,
+        cursor_of: impl Fn(&T) -> Cursor,
+    ) -> Self {
+        let has_more = rows.len() as i64 > request.limit;
+        rows.truncate(request.limit as usize);
+
+        let next_cursor = if has_more {
+            rows.last().map(|row| cursor_of(row).encode())
+        } else {
+            None
+        };
+
+        Page {
+            items: rows,
+            next_cursor,
+            total,
+            limit: request.limit,
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+            total: self.total,
+            limit: self.limit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_is_bounded_so_the_offset_cannot_overflow() {
+        assert!(validate_page(1).is_ok());
+        assert!(validate_page(MAX_PAGE).is_ok());
+        for page in [0, -1, MAX_PAGE + 1, i64::MAX, i64::MIN] {
+            let error = validate_page(page).unwrap_err();
+            assert_eq!(error.code, "range");
+        }
+
+        assert_eq!(page_offset(1, 20), 0);
+        assert_eq!(page_offset(3, 20), 40);
+        assert_eq!(page_offset(MAX_PAGE, MAX_LIMIT), (MAX_PAGE - 1) * MAX_LIMIT);
+    }
+}
@@ -1,14 +1,13 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ApiResponse<T> {
     pub success: bool,
     pub message: String,
     pub data: Option<T>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ErrorResponse {
-    pub error: String,
-    pub message: String,
-}
+/// Isi `data` untuk response yang tidak mengembalikan data (selalu `null`)
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Empty;
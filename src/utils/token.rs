@@ -0,0 +1,14 @@
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// Generate random opaque token (hex, 64 chars) untuk dikirim ke user
+pub fn generate_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// Hash token sebelum disimpan ke database, supaya token asli tidak pernah tersimpan
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
@@ -0,0 +1,210 @@
+mod common;
+
+use std::time::Duration;
+
+use actix_web::http::{Method, StatusCode};
+use actix_web::test;
+use serde_json::json;
+use sqlx::PgPool;
+use tokio::time::timeout;
+
+use tabungin_api::services::session_service::redeem_stream_ticket;
+
+use common::factories::{create_user, PASSWORD};
+use common::TestApp;
+
+#[sqlx::test(migrations = "./migrations")]
+async fn register_login_and_fetch_profile(pool: PgPool) {
+    let app = TestApp::new(pool);
+
+    let register = app
+        .request(
+            Method::POST,
+            "/api/v1/auth/register",
+            None,
+            Some(json!({
+                "full_name": "Budi Santoso",
+                "email": "budi@example.com",
+                "password": PASSWORD,
+                "confirm_password": PASSWORD,
+            })),
+        )
+        .await;
+    assert_eq!(register.status, StatusCode::CREATED, "{}", register.body);
+    assert_eq!(register.data()["user"]["email"], "budi@example.com");
+    assert!(register.data()["refresh_token"].is_string());
+
+    // Email dicocokkan case-insensitive
+    let login = app
+        .request(
+            Method::POST,
+            "/api/v1/auth/login",
+            None,
+            Some(json!({ "email": "BUDI@example.com", "password": PASSWORD })),
+        )
+        .await;
+    assert_eq!(login.status, StatusCode::OK, "{}", login.body);
+    let token = login.data()["token"].as_str().unwrap().to_string();
+
+    let profile = app.get("/api/v1/users/profile", &token).await;
+    assert_eq!(profile.status, StatusCode::OK, "{}", profile.body);
+    assert_eq!(profile.data()["full_name"], "Budi Santoso");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn register_rejects_invalid_and_duplicate_input(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let existing = create_user(&pool).await;
+
+    let invalid = app
+        .request(
+            Method::POST,
+            "/api/v1/auth/register",
+            None,
+            Some(json!({
+                "full_name": "B",
+                "email": "not-an-email",
+                "password": PASSWORD,
+                "confirm_password": PASSWORD,
+            })),
+        )
+        .await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST);
+    assert_eq!(invalid.error_code(), "validation_failed");
+    assert!(invalid.body["fields"]["email"].is_array());
+    assert!(invalid.body["fields"]["full_name"].is_array());
+
+    let duplicate = app
+        .request(
+            Method::POST,
+            "/api/v1/auth/register",
+            None,
+            Some(json!({
+                "full_name": "Someone Else",
+                "email": existing.email.to_uppercase(),
+                "password": PASSWORD,
+                "confirm_password": PASSWORD,
+            })),
+        )
+        .await;
+    assert_eq!(duplicate.status, StatusCode::CONFLICT);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn login_with_wrong_password_is_unauthorized(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+
+    let login = app
+        .request(
+            Method::POST,
+            "/api/v1/auth/login",
+            None,
+            Some(json!({ "email": user.email, "password": "salah-password" })),
+        )
+        .await;
+    assert_eq!(login.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(login.error_code(), "unauthorized");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn protected_routes_require_a_valid_token(pool: PgPool) {
+    let app = TestApp::new(pool);
+
+    let missing = app.request(Method::GET, "/api/v1/users/profile", None, None).await;
+    assert_eq!(missing.status, StatusCode::UNAUTHORIZED);
+
+    let garbage = app.get("/api/v1/users/profile", "not-a-jwt").await;
+    assert_eq!(garbage.status, StatusCode::UNAUTHORIZED);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn refresh_rotates_token_and_rejects_reuse(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+
+    let refreshed = app
+        .request(
+            Method::POST,
+            "/api/v1/auth/refresh",
+            None,
+            Some(json!({ "refresh_token": user.refresh_token })),
+        )
+        .await;
+    assert_eq!(refreshed.status, StatusCode::OK, "{}", refreshed.body);
+    assert_ne!(refreshed.data()["refresh_token"], user.refresh_token.as_str());
+
+    let reused = app
+        .request(
+            Method::POST,
+            "/api/v1/auth/refresh",
+            None,
+            Some(json!({ "refresh_token": user.refresh_token })),
+        )
+        .await;
+    assert_eq!(reused.status, StatusCode::UNAUTHORIZED);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn logout_revokes_the_session(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+
+    let logout = app.post("/api/v1/auth/logout", &user.token, json!({})).await;
+    assert_eq!(logout.status, StatusCode::OK, "{}", logout.body);
+
+    let profile = app.get("/api/v1/users/profile", &user.token).await;
+    assert_eq!(profile.status, StatusCode::UNAUTHORIZED);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn stream_ticket_is_single_use_and_replaces_token_in_query(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+
+    // Access token tidak lagi diterima di URL
+    let in_query = app
+        .request(Method::GET, &format!("/api/v1/notifications/stream?access_token={}", user.token), None, None)
+        .await;
+    assert_eq!(in_query.status, StatusCode::UNAUTHORIZED);
+
+    let issued = app.post("/api/v1/notifications/stream-ticket", &user.token, json!({})).await;
+    assert_eq!(issued.status, StatusCode::CREATED, "{}", issued.body);
+    assert_eq!(issued.data()["expires_in"], 60);
+    let ticket = issued.data()["ticket"].as_str().unwrap().to_string();
+
+    let owner = redeem_stream_ticket(&pool, &ticket).await.unwrap();
+    assert_eq!(owner.user_id, user.id);
+    assert!(redeem_stream_ticket(&pool, &ticket).await.is_err());
+
+    // Tiket ikut tidak berlaku setelah session-nya logout
+    let issued = app.post("/api/v1/notifications/stream-ticket", &user.token, json!({})).await;
+    let ticket = issued.data()["ticket"].as_str().unwrap().to_string();
+    app.post("/api/v1/auth/logout", &user.token, json!({})).await;
+    let revoked = app
+        .request(Method::GET, &format!("/api/v1/notifications/stream?ticket={}", ticket), None, None)
+        .await;
+    assert_eq!(revoked.status, StatusCode::UNAUTHORIZED);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn notification_stream_closes_after_session_is_revoked(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+
+    // Session aktif: stream tetap terbuka
+    let open = app.open_stream("/api/v1/notifications/stream", &user.token).await;
+    assert_eq!(open.status(), StatusCode::OK);
+    assert!(timeout(Duration::from_millis(300), test::read_body(open)).await.is_err());
+
+    // Setelah logout, heartbeat berikutnya menutup stream yang sudah terbuka
+    let stream = app.open_stream("/api/v1/notifications/stream", &user.token).await;
+    assert_eq!(stream.status(), StatusCode::OK);
+    let logout = app.post("/api/v1/auth/logout", &user.token, json!({})).await;
+    assert_eq!(logout.status, StatusCode::OK, "{}", logout.body);
+
+    let body = timeout(Duration::from_secs(5), test::read_body(stream))
+        .await
+        .expect("stream stayed open after logout");
+    assert!(body.is_empty());
+}
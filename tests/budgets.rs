@@ -0,0 +1,171 @@
+mod common;
+
+use actix_web::http::{Method, StatusCode};
+use serde_json::{json, Value};
+use sqlx::PgPool;
+
+use common::factories::create_user;
+use common::{TestApp, TestResponse};
+
+async fn create_category(app: &TestApp, token: &str, name: &str) -> Value {
+    let created = app.post("/api/v1/budgets/categories", token, json!({ "name": name, "icon": "🍜" })).await;
+    assert_eq!(created.status, StatusCode::CREATED, "{}", created.body);
+    created.data()["id"].clone()
+}
+
+async fn spend(app: &TestApp, token: &str, category_id: &Value, amount: &str, spent_on: &str) -> TestResponse {
+    app.post(
+        "/api/v1/expenses",
+        token,
+        json!({ "category_id": category_id, "amount": amount, "spent_on": spent_on }),
+    )
+    .await
+}
+
+async fn budget_notifications(app: &TestApp, token: &str) -> Vec<Value> {
+    let notifications = app.get("/api/v1/notifications", token).await;
+    notifications.data()["notifications"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|n| n["type"] == "budget_exceeded")
+        .cloned()
+        .collect()
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn summary_shows_planned_actual_and_remaining(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let food = create_category(&app, &user.token, "Makan").await;
+    let transport = create_category(&app, &user.token, "Transport").await;
+
+    let budget = app
+        .put(&format!("/api/v1/budgets/2025-03/categories/{}", food.as_str().unwrap()), &user.token, json!({ "amount": 1000000 }))
+        .await;
+    assert_eq!(budget.status, StatusCode::OK, "{}", budget.body);
+    assert_eq!(budget.data()["month"], "2025-03-01");
+
+    for (category, amount, date) in [
+        (&food, "250000", "2025-03-02"),
+        (&food, "150001", "2025-03-31"),
+        (&food, "999999", "2025-04-01"),
+        (&transport, "50000", "2025-03-10"),
+    ] {
+        let expense = spend(&app, &user.token, category, amount, date).await;
+        assert_eq!(expense.status, StatusCode::CREATED, "{}", expense.body);
+    }
+
+    let summary = app.get("/api/v1/budgets/2025-03", &user.token).await;
+    assert_eq!(summary.status, StatusCode::OK, "{}", summary.body);
+    let data = summary.data();
+    assert_eq!(data["month"], "2025-03");
+    assert_eq!(data["currency"], "IDR");
+    assert_eq!(data["total_planned"], "1000000.00");
+    assert_eq!(data["total_spent"], "450001.00");
+    assert_eq!(data["total_remaining"], "549999.00");
+
+    let categories = data["categories"].as_array().unwrap();
+    assert_eq!(categories.len(), 2);
+    assert_eq!(categories[0]["name"], "Makan");
+    assert_eq!(categories[0]["planned"], "1000000.00");
+    assert_eq!(categories[0]["spent"], "400001.00");
+    assert_eq!(categories[0]["remaining"], "599999.00");
+    assert_eq!(categories[0]["is_overspent"], false);
+    assert_eq!(categories[1]["name"], "Transport");
+    assert!(categories[1]["planned"].is_null());
+    assert_eq!(categories[1]["spent"], "50000.00");
+
+    let listed = app.get("/api/v1/expenses?month=2025-03", &user.token).await;
+    assert_eq!(listed.data().as_array().unwrap().len(), 3);
+
+    let invalid = app.get("/api/v1/budgets/2025-13", &user.token).await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST, "{}", invalid.body);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn crossing_the_budget_sends_one_notification(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let food = create_category(&app, &user.token, "Makan").await;
+    let uri = format!("/api/v1/budgets/2025-03/categories/{}", food.as_str().unwrap());
+    app.put(&uri, &user.token, json!({ "amount": "100000" })).await;
+
+    let first = spend(&app, &user.token, &food, "100000", "2025-03-05").await;
+    assert_eq!(first.status, StatusCode::CREATED, "{}", first.body);
+    assert!(budget_notifications(&app, &user.token).await.is_empty());
+
+    let second = spend(&app, &user.token, &food, "20000", "2025-03-06").await;
+    assert_eq!(second.status, StatusCode::CREATED, "{}", second.body);
+    let notifications = budget_notifications(&app, &user.token).await;
+    assert_eq!(notifications.len(), 1);
+    assert!(notifications[0]["message"].as_str().unwrap().contains("Rp 120.000"));
+
+    // Sudah lewat budget, pengeluaran berikutnya tidak mengirim notifikasi lagi
+    let third = spend(&app, &user.token, &food, "5000", "2025-03-07").await;
+    assert_eq!(third.status, StatusCode::CREATED, "{}", third.body);
+    assert_eq!(budget_notifications(&app, &user.token).await.len(), 1);
+
+    let summary = app.get("/api/v1/budgets/2025-03", &user.token).await;
+    assert_eq!(summary.data()["categories"][0]["is_overspent"], true);
+    assert_eq!(summary.data()["categories"][0]["remaining"], "-25000.00");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn categories_are_private_and_protected(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let owner = create_user(&pool).await;
+    let other = create_user(&pool).await;
+    let food = create_category(&app, &owner.token, "Makan").await;
+
+    let duplicate = app.post("/api/v1/budgets/categories", &owner.token, json!({ "name": "makan" })).await;
+    assert_eq!(duplicate.status, StatusCode::CONFLICT, "{}", duplicate.body);
+    let transport = create_category(&app, &owner.token, "Transport").await;
+    let renamed = app
+        .put(&format!("/api/v1/budgets/categories/{}", transport.as_str().unwrap()), &owner.token, json!({ "name": " MAKAN " }))
+        .await;
+    assert_eq!(renamed.status, StatusCode::CONFLICT, "{}", renamed.body);
+    // Nama yang sama boleh dipakai user lain
+    create_category(&app, &other.token, "Makan").await;
+
+    let foreign = spend(&app, &other.token, &food, "1000", "2025-03-01").await;
+    assert_eq!(foreign.status, StatusCode::NOT_FOUND, "{}", foreign.body);
+
+    let foreign_budget = app
+        .put(&format!("/api/v1/budgets/2025-03/categories/{}", food.as_str().unwrap()), &other.token, json!({ "amount": 1000 }))
+        .await;
+    assert_eq!(foreign_budget.status, StatusCode::NOT_FOUND, "{}", foreign_budget.body);
+
+    let invalid = spend(&app, &owner.token, &food, "0", "2025-03-01").await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST, "{}", invalid.body);
+    assert_eq!(invalid.error_code(), "validation_failed");
+
+    let expense = spend(&app, &owner.token, &food, "1000", "2025-03-01").await;
+    assert_eq!(expense.status, StatusCode::CREATED, "{}", expense.body);
+    let category_uri = format!("/api/v1/budgets/categories/{}", food.as_str().unwrap());
+
+    let in_use = app.request(Method::DELETE, &category_uri, Some(&owner.token), None).await;
+    assert_eq!(in_use.status, StatusCode::CONFLICT, "{}", in_use.body);
+
+    let expense_uri = format!("/api/v1/expenses/{}", expense.data()["id"].as_str().unwrap());
+    let deleted = app.request(Method::DELETE, &expense_uri, Some(&owner.token), None).await;
+    assert_eq!(deleted.status, StatusCode::OK, "{}", deleted.body);
+
+    let removed = app.request(Method::DELETE, &category_uri, Some(&owner.token), None).await;
+    assert_eq!(removed.status, StatusCode::OK, "{}", removed.body);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn concurrent_duplicate_category_is_a_conflict(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+
+    let (first, second) = tokio::join!(
+        app.post("/api/v1/budgets/categories", &user.token, json!({ "name": "Belanja" })),
+        app.post("/api/v1/budgets/categories", &user.token, json!({ "name": "belanja" })),
+    );
+
+    let mut statuses = [first.status, second.status];
+    statuses.sort();
+    assert_eq!(statuses, [StatusCode::CREATED, StatusCode::CONFLICT], "{} / {}", first.body, second.body);
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use actix_web::http::StatusCode;
+use serde_json::{json, Value};
+use sqlx::PgPool;
+
+use common::factories::create_user;
+use common::TestApp;
+
+async fn create_category(app: &TestApp, token: &str, name: &str, kind: &str) -> Value {
+    let created = app.post("/api/v1/budgets/categories", token, json!({ "name": name, "kind": kind })).await;
+    assert_eq!(created.status, StatusCode::CREATED, "{}", created.body);
+    assert_eq!(created.data()["kind"], kind);
+    created.data()["id"].clone()
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn cashflow_report_groups_by_month_and_category(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let salary = create_category(&app, &user.token, "Gaji", "income").await;
+    let food = create_category(&app, &user.token, "Makan", "expense").await;
+    let rent = create_category(&app, &user.token, "Kos", "expense").await;
+
+    for (received_on, amount) in [("2025-01-25", "4000000"), ("2025-03-25", "5000000")] {
+        let income = app
+            .post(
+                "/api/v1/incomes",
+                &user.token,
+                json!({ "category_id": salary, "amount": amount, "payee": "PT Maju", "received_on": received_on }),
+            )
+            .await;
+        assert_eq!(income.status, StatusCode::CREATED, "{}", income.body);
+        assert_eq!(income.data()["payee"], "PT Maju");
+    }
+
+    for (category, amount, spent_on) in [
+        (&rent, "1500000", "2025-01-01"),
+        (&food, "1500000", "2025-01-20"),
+        (&food, "750000", "2025-02-14"),
+        (&rent, "1500000", "2025-03-01"),
+        (&food, "999999", "2025-04-01"),
+    ] {
+        let expense = app
+            .post(
+                "/api/v1/expenses",
+                &user.token,
+                json!({ "category_id": category, "amount": amount, "payee": "Warung", "spent_on": spent_on }),
+            )
+            .await;
+        assert_eq!(expense.status, StatusCode::CREATED, "{}", expense.body);
+    }
+
+    let report = app.get("/api/v1/reports/cashflow?from=2025-01-01&to=2025-03-31", &user.token).await;
+    assert_eq!(report.status, StatusCode::OK, "{}", report.body);
+    let data = report.data();
+    assert_eq!(data["group_by"], "month");
+    assert_eq!(data["total_income"], "9000000.00");
+    assert_eq!(data["total_expense"], "5250000.00");
+    assert_eq!(data["net"], "3750000.00");
+
+    let months = data["groups"].as_array().unwrap();
+    assert_eq!(months.len(), 3);
+    assert_eq!(months[0]["month"], "2025-01");
+    assert_eq!(months[0]["savings_rate"], 25.0);
+    // Bulan tanpa pemasukan tetap muncul, savings rate kosong
+    assert_eq!(months[1]["month"], "2025-02");
+    assert_eq!(months[1]["income"], "0.00");
+    assert_eq!(months[1]["net"], "-750000.00");
+    assert!(months[1]["savings_rate"].is_null());
+    assert_eq!(months[2]["savings_rate"], 70.0);
+
+    let by_category = app
+        .get("/api/v1/reports/cashflow?from=2025-01-01&to=2025-03-31&group_by=category", &user.token)
+        .await;
+    assert_eq!(by_category.status, StatusCode::OK, "{}", by_category.body);
+    let groups = by_category.data()["groups"].as_array().unwrap();
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups[0]["category_name"], "Gaji");
+    assert_eq!(groups[0]["income"], "9000000.00");
+    assert_eq!(groups[1]["category_name"], "Kos");
+    assert_eq!(groups[1]["expense"], "3000000.00");
+    assert_eq!(groups[2]["category_name"], "Makan");
+    assert_eq!(by_category.data()["total_expense"], "5250000.00");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn income_and_expense_categories_are_not_interchangeable(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let salary = create_category(&app, &user.token, "Gaji", "income").await;
+    let food = create_category(&app, &user.token, "Makan", "expense").await;
+
+    let wrong_income = app.post("/api/v1/incomes", &user.token, json!({ "category_id": food, "amount": 1000 })).await;
+    assert_eq!(wrong_income.status, StatusCode::NOT_FOUND, "{}", wrong_income.body);
+
+    let wrong_expense = app.post("/api/v1/expenses", &user.token, json!({ "category_id": salary, "amount": 1000 })).await;
+    assert_eq!(wrong_expense.status, StatusCode::NOT_FOUND, "{}", wrong_expense.body);
+
+    let no_budget = app
+        .put(&format!("/api/v1/budgets/2025-03/categories/{}", salary.as_str().unwrap()), &user.token, json!({ "amount": 1000 }))
+        .await;
+    assert_eq!(no_budget.status, StatusCode::NOT_FOUND, "{}", no_budget.body);
+
+    let incomes = app.get("/api/v1/budgets/categories?kind=income", &user.token).await;
+    assert_eq!(incomes.data().as_array().unwrap().len(), 1);
+
+    let invalid = app.get("/api/v1/reports/cashflow?from=2025-03-01&to=2025-01-01", &user.token).await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST, "{}", invalid.body);
+
+    let bad_group = app.get("/api/v1/reports/cashflow?group_by=week", &user.token).await;
+    assert_eq!(bad_group.status, StatusCode::BAD_REQUEST, "{}", bad_group.body);
+}
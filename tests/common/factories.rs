@@ -0,0 +1,205 @@
+//! Factory data test. User dan target dibuat lewat service (hash password, session, trigger
+//! reminder ikut jalan), activity, entry ledger dan reminder di-insert langsung supaya tanggalnya bisa diatur.
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use tabungin_api::models::{CreateSavingsTargetRequest, RegisterRequest, SavingsTarget};
+use tabungin_api::services::{auth_service, savings_service};
+use tabungin_api::utils::money::Money;
+
+pub const PASSWORD: &str = "rahasia123";
+
+pub struct TestUser {
+    pub id: Uuid,
+    pub email: String,
+    pub token: String,
+    pub refresh_token: String,
+}
+
+/// User baru dengan email unik, sudah login (punya access + refresh token)
+pub async fn create_user(pool: &PgPool) -> TestUser {
+    let email = format!("user-{}@example.com", Uuid::new_v4().simple());
+    let request = RegisterRequest {
+        full_name: "Test User".to_string(),
+        email: email.clone(),
+        password: PASSWORD.to_string(),
+        confirm_password: PASSWORD.to_string(),
+    };
+
+    let auth = auth_service::register_user(pool, &request)
+        .await
+        .expect("failed to create user");
+
+    TestUser {
+        id: auth.user.id,
+        email,
+        token: auth.token,
+        refresh_token: auth.refresh_token,
+    }
+}
+
+/// User dengan role admin
+pub async fn create_admin(pool: &PgPool) -> TestUser {
+    let user = create_user(pool).await;
+    sqlx::query("UPDATE users SET role = 'admin' WHERE id = $1")
+        .bind(user.id)
+        .execute(pool)
+        .await
+        .expect("failed to promote user to admin");
+    user
+}
+
+/// Target tanpa deadline (tidak ada reminder otomatis)
+pub async fn create_target(pool: &PgPool, user_id: Uuid, target_amount: &str) -> SavingsTarget {
+    create_target_with_date(pool, user_id, target_amount, None).await
+}
+
+/// Target dengan deadline; trigger `create_target_reminders` ikut membuat reminder
+pub async fn create_target_with_date(
+    pool: &PgPool,
+    user_id: Uuid,
+    target_amount: &str,
+    target_date: Option<NaiveDate>,
+) -> SavingsTarget {
+    create_target_full(pool, user_id, target_amount, target_date, None).await
+}
+
+/// Target dalam mata uang tertentu (default-nya mata uang utama user)
+pub async fn create_target_in_currency(
+    pool: &PgPool,
+    user_id: Uuid,
+    target_amount: &str,
+    currency: &str,
+) -> SavingsTarget {
+    create_target_full(pool, user_id, target_amount, None, Some(currency.to_string())).await
+}
+
+async fn create_target_full(
+    pool: &PgPool,
+    user_id: Uuid,
+    target_amount: &str,
+    target_date: Option<NaiveDate>,
+    currency: Option<String>,
+) -> SavingsTarget {
+    let request = CreateSavingsTargetRequest {
+        name: format!("Target {}", &Uuid::new_v4().simple().to_string()[..8]),
+        target_amount: target_amount.parse::<Money>().expect("invalid target amount"),
+        icon: None,
+        icon_color: None,
+        target_date,
+        currency,
+    };
+
+    let mut conn = pool.acquire().await.expect("failed to acquire connection");
+    savings_service::create_savings_target(&mut conn, user_id, request)
+        .await
+        .expect("failed to create savings target")
+}
+
+/// Tambahkan user sebagai anggota target tanpa alur undangan
+pub async fn add_member(pool: &PgPool, target_id: Uuid, user_id: Uuid, role: &str) {
+    sqlx::query("INSERT INTO savings_target_members (savings_target_id, user_id, role) VALUES ($1, $2, $3)")
+        .bind(target_id)
+        .bind(user_id)
+        .bind(role)
+        .execute(pool)
+        .await
+        .expect("failed to add target member");
+}
+
+/// Activity dengan waktu `created_at` tertentu, dipakai untuk data streak di hari-hari sebelumnya
+pub async fn create_activity(
+    pool: &PgPool,
+    user_id: Uuid,
+    target_id: Option<Uuid>,
+    activity_type: &str,
+    amount: i64,
+    created_at: DateTime<Utc>,
+) -> Uuid {
+    sqlx::query_scalar(
+        r#"
+        INSERT INTO activities (user_id, savings_target_id, activity_type, title, amount, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id
+        "#,
+    )
+    .bind(user_id)
+    .bind(target_id)
+    .bind(activity_type)
+    .bind(format!("Test {}", activity_type))
+    .bind(BigDecimal::from(amount))
+    .bind(created_at)
+    .fetch_one(pool)
+    .await
+    .expect("failed to create activity")
+}
+
+/// Entry ledger dengan waktu `created_at` tertentu; saldo target ikut berubah lewat trigger ledger
+pub async fn create_transaction(
+    pool: &PgPool,
+    user_id: Uuid,
+    target_id: Uuid,
+    transaction_type: &str,
+    amount: i64,
+    created_at: DateTime<Utc>,
+) -> Uuid {
+    sqlx::query_scalar(
+        r#"
+        INSERT INTO transactions (user_id, savings_target_id, transaction_type, amount, created_at)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id
+        "#,
+    )
+    .bind(user_id)
+    .bind(target_id)
+    .bind(transaction_type)
+    .bind(BigDecimal::from(amount))
+    .bind(created_at)
+    .fetch_one(pool)
+    .await
+    .expect("failed to create transaction")
+}
+
+pub async fn create_reminder(
+    pool: &PgPool,
+    user_id: Uuid,
+    target_id: Uuid,
+    reminder_date: NaiveDate,
+) -> Uuid {
+    sqlx::query_scalar(
+        r#"
+        INSERT INTO reminders (user_id, savings_target_id, reminder_date, reminder_type, title, description)
+        VALUES ($1, $2, $3, 'weekly_reminder', 'Jangan lupa menabung', 'Reminder dari test')
+        RETURNING id
+        "#,
+    )
+    .bind(user_id)
+    .bind(target_id)
+    .bind(reminder_date)
+    .fetch_one(pool)
+    .await
+    .expect("failed to create reminder")
+}
+
+/// Tanggal hari ini menurut database (acuan query reminder)
+pub async fn db_today(pool: &PgPool) -> NaiveDate {
+    sqlx::query_scalar("SELECT CURRENT_DATE")
+        .fetch_one(pool)
+        .await
+        .expect("failed to read CURRENT_DATE")
+}
+
+/// Tanggal hari ini di UTC+7 (acuan perhitungan streak)
+pub fn jakarta_today() -> NaiveDate {
+    Utc::now()
+        .with_timezone(&FixedOffset::east_opt(7 * 3600).unwrap())
+        .date_naive()
+}
+
+/// Jam 12:00 WIB pada `days_ago` hari sebelum hari ini (WIB)
+pub fn jakarta_noon(days_ago: i64) -> DateTime<Utc> {
+    let date = jakarta_today() - Duration::days(days_ago);
+    date.and_hms_opt(5, 0, 0).unwrap().and_utc()
+}
@@ -0,0 +1,214 @@
+//! Harness integration test: setiap test dapat database baru dari `#[sqlx::test]` (migrasi
+//! `./migrations` sudah dijalankan), lalu App dibangun dengan `AppState::configure` yang sama
+//! dengan main.rs. Butuh Postgres lokal lewat `DATABASE_URL`.
+#![allow(dead_code)]
+
+pub mod factories;
+
+use std::sync::Once;
+
+use actix_web::http::header::HeaderMap;
+use actix_web::http::{Method, StatusCode};
+use actix_web::dev::ServiceResponse;
+use actix_web::{test, App};
+use serde_json::Value;
+use sqlx::PgPool;
+
+use tabungin_api::app::AppState;
+use tabungin_api::config::{Config, JobsConfig, MailBackend, MailConfig, SmtpTls};
+use tabungin_api::mailer::build_mailer;
+use tabungin_api::mailer::templates::Locale;
+use tabungin_api::services::notification_hub::NotificationHub;
+use tabungin_api::utils::money::Money;
+
+pub const JWT_SECRET: &str = "integration-test-secret";
+
+static ENV: Once = Once::new();
+
+/// `utils::jwt` membaca JWT_SECRET langsung dari env
+fn init_env() {
+    ENV.call_once(|| std::env::set_var("JWT_SECRET", JWT_SECRET));
+}
+
+fn test_config() -> Config {
+    Config {
+        database_url: String::new(),
+        jwt_secret: JWT_SECRET.to_string(),
+        host: "127.0.0.1".to_string(),
+        port: 0,
+        frontend_url: "http://localhost:3000".to_string(),
+        mail: MailConfig {
+            backend: MailBackend::File,
+            from: "Tabungin <no-reply@tabungin.test>".to_string(),
+            smtp_host: String::new(),
+            smtp_port: 0,
+            smtp_username: None,
+            smtp_password: None,
+            smtp_tls: SmtpTls::None,
+            spool_dir: std::env::temp_dir()
+                .join("tabungin-test-mail")
+                .to_string_lossy()
+                .into_owned(),
+            default_locale: Locale::Id,
+        },
+        // Worker tidak dijalankan di test, job dipanggil langsung kalau perlu
+        jobs: JobsConfig {
+            enabled: false,
+            recurring_deposit_interval_secs: 300,
+            reminder_dispatch_interval_secs: 300,
+            reminder_email_enabled: false,
+        },
+    }
+}
+
+/// Response yang sudah di-parse: status, header dan body JSON (`Value::Null` kalau body kosong)
+pub struct TestResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Value,
+}
+
+impl TestResponse {
+    /// Isi `data` dari `ApiResponse`
+    pub fn data(&self) -> &Value {
+        &self.body["data"]
+    }
+
+    /// `error.code` dari `ErrorBody`
+    pub fn error_code(&self) -> &str {
+        self.body["code"].as_str().unwrap_or_default()
+    }
+}
+
+pub struct TestApp {
+    pub pool: PgPool,
+    state: AppState,
+}
+
+impl TestApp {
+    pub fn new(pool: PgPool) -> Self {
+        init_env();
+        let config = test_config();
+        let mailer = build_mailer(&config.mail).expect("failed to build spool mailer");
+        let notification_hub = NotificationHub::start(pool.clone());
+
+        Self {
+            pool: pool.clone(),
+            state: AppState {
+                pool,
+                config,
+                mailer,
+                notification_hub,
+            },
+        }
+    }
+
+    pub async fn request(
+        &self,
+        method: Method,
+        uri: &str,
+        token: Option<&str>,
+        body: Option<Value>,
+    ) -> TestResponse {
+        let mut req = test::TestRequest::default().method(method).uri(uri);
+        if let Some(token) = token {
+            req = req.insert_header(("Authorization", format!("Bearer {}", token)));
+        }
+        if let Some(body) = body {
+            req = req.set_json(body);
+        }
+
+        self.send(req, uri).await
+    }
+
+    /// POST JSON dengan header `Idempotency-Key`
+    pub async fn post_idempotent(&self, uri: &str, token: &str, key: &str, body: Value) -> TestResponse {
+        let req = test::TestRequest::post()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .insert_header(("Idempotency-Key", key))
+            .set_json(body);
+
+        self.send(req, uri).await
+    }
+
+    /// Request dengan body mentah (bukan JSON), misal upload CSV
+    pub async fn post_text(&self, uri: &str, token: &str, content_type: &str, body: &str) -> TestResponse {
+        let req = test::TestRequest::post()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .insert_header(("Content-Type", content_type))
+            .set_payload(body.to_string());
+
+        self.send(req, uri).await
+    }
+
+    /// Kirim request kosong (tanpa token dan body) ke router asli dan kembalikan pattern route yang
+    /// melayaninya, misal `/api/v1/savings/targets/{id}`. None kalau tidak ada route untuk method + path
+    /// itu: actix menjawab 404/405 dengan body kosong, sedangkan 404 dari `AppError` selalu berisi JSON.
+    pub async fn probe(&self, method: Method, uri: &str) -> Option<String> {
+        let app = test::init_service(App::new().configure(|cfg| self.state.configure(cfg))).await;
+        let req = test::TestRequest::default().method(method).uri(uri).to_request();
+        let resp = test::call_service(&app, req).await;
+        let status = resp.status();
+        let pattern = resp.request().match_pattern();
+        let bytes = test::read_body(resp).await;
+
+        let unrouted = matches!(status, StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED) && bytes.is_empty();
+        pattern.filter(|_| !unrouted)
+    }
+
+    /// GET tanpa membaca body, untuk response streaming (SSE) yang tidak pernah selesai sendiri
+    pub async fn open_stream(&self, uri: &str, token: &str) -> ServiceResponse {
+        let app = test::init_service(App::new().configure(|cfg| self.state.configure(cfg))).await;
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)));
+
+        test::call_service(&app, req.to_request()).await
+    }
+
+    async fn send(&self, req: test::TestRequest, uri: &str) -> TestResponse {
+        let app = test::init_service(App::new().configure(|cfg| self.state.configure(cfg))).await;
+        let resp = test::call_service(&app, req.to_request()).await;
+        let status = resp.status();
+        let headers = resp.headers().clone();
+        let bytes = test::read_body(resp).await;
+        let body = if bytes.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_slice(&bytes).unwrap_or_else(|_| {
+                panic!("{} {} returned non-JSON body: {}", status, uri, String::from_utf8_lossy(&bytes))
+            })
+        };
+
+        TestResponse { status, headers, body }
+    }
+
+    pub async fn get(&self, uri: &str, token: &str) -> TestResponse {
+        self.request(Method::GET, uri, Some(token), None).await
+    }
+
+    pub async fn post(&self, uri: &str, token: &str, body: Value) -> TestResponse {
+        self.request(Method::POST, uri, Some(token), Some(body)).await
+    }
+
+    pub async fn put(&self, uri: &str, token: &str, body: Value) -> TestResponse {
+        self.request(Method::PUT, uri, Some(token), Some(body)).await
+    }
+}
+
+/// Nominal `Money` diserialisasi sebagai string dengan tepat 2 desimal ("150000.00"). Skala lain
+/// berarti format response berubah, jadi langsung gagal sebelum dibandingkan sebagai `Money`.
+pub fn amount(value: &Value) -> Money {
+    let raw = value
+        .as_str()
+        .unwrap_or_else(|| panic!("expected decimal string, got {}", value));
+    let decimals = raw.split_once('.').map(|(_, decimals)| decimals.len());
+    assert_eq!(decimals, Some(2), "expected two decimal places, got {}", raw);
+    money(raw)
+}
+
+pub fn money(value: &str) -> Money {
+    value.parse().unwrap_or_else(|e| panic!("invalid amount {}: {}", value, e))
+}
@@ -0,0 +1,296 @@
+mod common;
+
+use actix_web::http::{Method, StatusCode};
+use serde_json::{json, Value};
+use sqlx::PgPool;
+
+use common::factories::{create_admin, create_target, create_target_in_currency, create_user};
+use common::TestApp;
+
+#[sqlx::test(migrations = "./migrations")]
+async fn currency_codes_are_validated_and_default_to_home_currency(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+
+    let list = app.request(Method::GET, "/api/v1/currencies", None, None).await;
+    assert_eq!(list.status, StatusCode::OK, "{}", list.body);
+    let idr = list.data().as_array().unwrap().iter().find(|c| c["code"] == "IDR").cloned().unwrap();
+    assert_eq!(idr["minor_units"], 0);
+
+    let profile = app.get("/api/v1/users/profile", &user.token).await;
+    assert_eq!(profile.data()["currency"], "IDR");
+
+    let invalid = app.put("/api/v1/users/profile", &user.token, json!({ "currency": "XYZ" })).await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST, "{}", invalid.body);
+    assert_eq!(invalid.error_code(), "validation_failed");
+    assert!(invalid.body["fields"]["currency"].is_array());
+
+    let updated = app.put("/api/v1/users/profile", &user.token, json!({ "currency": "USD" })).await;
+    assert_eq!(updated.status, StatusCode::OK, "{}", updated.body);
+    assert_eq!(updated.data()["currency"], "USD");
+
+    let unsupported = app
+        .post(
+            "/api/v1/savings/targets",
+            &user.token,
+            json!({ "name": "Liburan", "target_amount": 1000, "currency": "usd" }),
+        )
+        .await;
+    assert_eq!(unsupported.status, StatusCode::BAD_REQUEST, "{}", unsupported.body);
+
+    let default = app
+        .post("/api/v1/savings/targets", &user.token, json!({ "name": "Laptop", "target_amount": 1500 }))
+        .await;
+    assert_eq!(default.status, StatusCode::CREATED, "{}", default.body);
+    assert_eq!(default.data()["currency"], "USD");
+
+    let explicit = app
+        .post(
+            "/api/v1/savings/targets",
+            &user.token,
+            json!({ "name": "Mudik", "target_amount": 3000000, "currency": "IDR" }),
+        )
+        .await;
+    assert_eq!(explicit.status, StatusCode::CREATED, "{}", explicit.body);
+    assert_eq!(explicit.data()["currency"], "IDR");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn statistics_are_reported_in_home_currency(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let admin = create_admin(&pool).await;
+    let user = create_user(&pool).await;
+    let rupiah = create_target(&pool, user.id, "10000000").await;
+    let dollar = create_target_in_currency(&pool, user.id, "1000", "USD").await;
+
+    let deposit = app
+        .post(&format!("/api/v1/savings/targets/{}/deposit", rupiah.id), &user.token, json!({ "amount": 1000000 }))
+        .await;
+    assert_eq!(deposit.status, StatusCode::OK, "{}", deposit.body);
+    assert_eq!(deposit.data()["activity"]["description"], "Setoran sebesar Rp 1.000.000");
+
+    let deposit = app
+        .post(&format!("/api/v1/savings/targets/{}/deposit", dollar.id), &user.token, json!({ "amount": 100 }))
+        .await;
+    assert_eq!(deposit.status, StatusCode::OK, "{}", deposit.body);
+    assert_eq!(deposit.data()["activity"]["description"], "Setoran sebesar $100.00");
+
+    // Belum ada kurs USD -> IDR: saldo dolar tidak ikut dijumlah dan dilaporkan
+    let stats = app.get("/api/v1/statistics", &user.token).await;
+    assert_eq!(stats.status, StatusCode::OK, "{}", stats.body);
+    assert_eq!(stats.data()["currency"], "IDR");
+    assert_eq!(stats.data()["total_saved"], "1000000.00");
+    assert_eq!(stats.data()["unconverted_currencies"], json!(["USD"]));
+
+    let rate = app
+        .put(
+            "/api/v1/currencies/rates",
+            &admin.token,
+            json!({ "base_currency": "USD", "quote_currency": "IDR", "rate": "16000" }),
+        )
+        .await;
+    assert_eq!(rate.status, StatusCode::OK, "{}", rate.body);
+
+    let stats = app.get("/api/v1/statistics", &user.token).await;
+    assert_eq!(stats.data()["total_saved"], "2600000.00");
+    assert_eq!(stats.data()["unconverted_currencies"], json!([]));
+
+    // Kurs kebalikannya dipakai untuk konversi IDR -> USD
+    let updated = app.put("/api/v1/users/profile", &user.token, json!({ "currency": "USD" })).await;
+    assert_eq!(updated.status, StatusCode::OK, "{}", updated.body);
+    let stats = app.get("/api/v1/statistics", &user.token).await;
+    assert_eq!(stats.data()["currency"], "USD");
+    assert_eq!(stats.data()["total_saved"], "162.50");
+
+    let analytics = app.get("/api/v1/dashboard/analytics", &user.token).await;
+    assert_eq!(analytics.status, StatusCode::OK, "{}", analytics.body);
+    assert_eq!(analytics.data()["total_saved"], "162.50");
+
+    let dashboard = app.get("/api/v1/dashboard/stats", &admin.token).await;
+    assert_eq!(dashboard.status, StatusCode::OK, "{}", dashboard.body);
+    assert_eq!(dashboard.data()["currency"], "IDR");
+    assert_eq!(dashboard.data()["total_saved_amount"], "2600000.00");
+
+    // Saldo beda mata uang tidak dianggap selisih oleh rekonsiliasi
+    let reconciliation = app.get("/api/v1/dashboard/reconciliation", &admin.token).await;
+    assert_eq!(reconciliation.status, StatusCode::OK, "{}", reconciliation.body);
+    assert_eq!(reconciliation.data()["is_consistent"], true);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn exchange_rates_import_is_all_or_nothing(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let admin = create_admin(&pool).await;
+    let user = create_user(&pool).await;
+
+    let forbidden = app
+        .put(
+            "/api/v1/currencies/rates",
+            &user.token,
+            json!({ "base_currency": "USD", "quote_currency": "IDR", "rate": 16000 }),
+        )
+        .await;
+    assert_eq!(forbidden.status, StatusCode::FORBIDDEN);
+
+    let invalid = app
+        .post_text(
+            "/api/v1/currencies/rates/import",
+            &admin.token,
+            "text/csv",
+            "base_currency,quote_currency,rate,effective_date\nUSD,IDR,16000,2025-01-01\nEUR,IDR,-1,2025-01-01\n",
+        )
+        .await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST, "{}", invalid.body);
+    assert!(invalid.body["message"].as_str().unwrap().starts_with("Line 3:"));
+
+    let rates = app.get("/api/v1/currencies/rates", &user.token).await;
+    assert_eq!(rates.data().as_array().unwrap().len(), 0);
+
+    let csv = "# kurs tengah\nUSD,IDR,15800,2025-01-01\nusd,idr,16100.5,2025-02-01\nEUR,IDR,17000\n";
+    let imported = app.post_text("/api/v1/currencies/rates/import", &admin.token, "text/csv", csv).await;
+    assert_eq!(imported.status, StatusCode::OK, "{}", imported.body);
+    assert_eq!(imported.data()["imported"], 3);
+
+    let latest = app.get("/api/v1/currencies/rates?base=USD", &user.token).await;
+    let latest = latest.data().as_array().unwrap().clone();
+    assert_eq!(latest.len(), 1);
+    assert_eq!(latest[0]["quote_currency"], "IDR");
+    assert_eq!(latest[0]["effective_date"], "2025-02-01");
+    assert_eq!(latest[0]["source"], "import");
+
+    let historical = app.get("/api/v1/currencies/rates?base=USD&date=2025-01-15", &user.token).await;
+    assert_eq!(historical.data()[0]["effective_date"], "2025-01-01");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn transfer_between_currencies_is_rejected(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let rupiah = create_target(&pool, user.id, "1000000").await;
+    let dollar = create_target_in_currency(&pool, user.id, "1000", "USD").await;
+
+    let deposit = app
+        .post(&format!("/api/v1/savings/targets/{}/deposit", rupiah.id), &user.token, json!({ "amount": 50000 }))
+        .await;
+    assert_eq!(deposit.status, StatusCode::OK, "{}", deposit.body);
+
+    let transfer = app
+        .post(
+            "/api/v1/savings/transfers",
+            &user.token,
+            json!({ "from_target_id": rupiah.id, "to_target_id": dollar.id, "amount": 10000 }),
+        )
+        .await;
+    assert_eq!(transfer.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", transfer.body);
+    assert_eq!(transfer.error_code(), "unprocessable_entity");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn amounts_finer_than_minor_units_are_rejected(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let rupiah = create_target(&pool, user.id, "1000000").await;
+    let other = create_target(&pool, user.id, "1000000").await;
+    let target_uri = format!("/api/v1/savings/targets/{}", rupiah.id);
+
+    let deposit = app.post(&format!("{}/deposit", target_uri), &user.token, json!({ "amount": 50000 })).await;
+    assert_eq!(deposit.status, StatusCode::OK, "{}", deposit.body);
+
+    let rejected = [
+        (
+            "target_amount",
+            app.post("/api/v1/savings/targets", &user.token, json!({ "name": "Mudik", "target_amount": "250000.50" }))
+                .await,
+        ),
+        ("target_amount", app.put(&target_uri, &user.token, json!({ "target_amount": "250000.50" })).await),
+        ("current_amount", app.put(&target_uri, &user.token, json!({ "current_amount": "100.5" })).await),
+        ("amount", app.post(&format!("{}/deposit", target_uri), &user.token, json!({ "amount": "1000.50" })).await),
+        ("amount", app.post(&format!("{}/withdraw", target_uri), &user.token, json!({ "amount": "1000.50" })).await),
+        (
+            "amount",
+            app.post(
+                "/api/v1/savings/transfers",
+                &user.token,
+                json!({ "from_target_id": rupiah.id, "to_target_id": other.id, "amount": "1000.50" }),
+            )
+            .await,
+        ),
+        (
+            "amount",
+            app.post(
+                "/api/v1/recurring-deposits",
+                &user.token,
+                json!({ "savings_target_id": rupiah.id, "amount": "1000.50", "frequency": "weekly", "start_date": "2025-01-01" }),
+            )
+            .await,
+        ),
+    ];
+    for (field, response) in rejected {
+        assert_eq!(response.status, StatusCode::BAD_REQUEST, "{}", response.body);
+        assert_eq!(response.body["fields"][field][0]["code"], "minor_units", "{}", response.body);
+    }
+
+    // Tidak ada yang berubah di ledger
+    let target = app.get(&target_uri, &user.token).await;
+    assert_eq!(target.data()["current_amount"], "50000.00");
+    assert_eq!(target.data()["target_amount"], "1000000.00");
+
+    // Mata uang dengan 2 digit minor tetap menerima sen
+    let dollar = app
+        .post(
+            "/api/v1/savings/targets",
+            &user.token,
+            json!({ "name": "Liburan", "target_amount": "1500.50", "currency": "USD" }),
+        )
+        .await;
+    assert_eq!(dollar.status, StatusCode::CREATED, "{}", dollar.body);
+    assert_eq!(dollar.data()["target_amount"], "1500.50");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn cashflow_amounts_follow_the_home_currency_minor_units(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+
+    let category = |name: &str, kind: &str| {
+        app.post("/api/v1/budgets/categories", &user.token, json!({ "name": name, "kind": kind }))
+    };
+    let food = category("Makan", "expense").await.data()["id"].clone();
+    let salary = category("Gaji", "income").await.data()["id"].clone();
+    let id = |value: &Value| value.as_str().unwrap().to_string();
+
+    let expense = app
+        .post("/api/v1/expenses", &user.token, json!({ "category_id": food, "amount": "15000", "spent_on": "2025-03-01" }))
+        .await;
+    assert_eq!(expense.status, StatusCode::CREATED, "{}", expense.body);
+    let income = app
+        .post("/api/v1/incomes", &user.token, json!({ "category_id": salary, "amount": "5000000", "received_on": "2025-03-01" }))
+        .await;
+    assert_eq!(income.status, StatusCode::CREATED, "{}", income.body);
+
+    // Pengeluaran, pemasukan dan budget dicatat dalam IDR (tanpa digit minor)
+    let rejected = [
+        app.put(&format!("/api/v1/budgets/2025-03/categories/{}", id(&food)), &user.token, json!({ "amount": "1500.50" })).await,
+        app.post("/api/v1/expenses", &user.token, json!({ "category_id": food, "amount": "1500.50" })).await,
+        app.put(&format!("/api/v1/expenses/{}", id(&expense.data()["id"])), &user.token, json!({ "amount": "1500.50" })).await,
+        app.post("/api/v1/incomes", &user.token, json!({ "category_id": salary, "amount": "1500.50" })).await,
+        app.put(&format!("/api/v1/incomes/{}", id(&income.data()["id"])), &user.token, json!({ "amount": "1500.50" })).await,
+    ];
+    for response in rejected {
+        assert_eq!(response.status, StatusCode::BAD_REQUEST, "{}", response.body);
+        assert_eq!(response.body["fields"]["amount"][0]["code"], "minor_units", "{}", response.body);
+    }
+
+    let summary = app.get("/api/v1/budgets/2025-03", &user.token).await;
+    assert!(summary.data()["categories"][0]["planned"].is_null(), "{}", summary.body);
+    assert_eq!(summary.data()["categories"][0]["spent"], "15000.00");
+
+    // Setelah mata uang utama jadi USD, sen diterima
+    let updated = app.put("/api/v1/users/profile", &user.token, json!({ "currency": "USD" })).await;
+    assert_eq!(updated.status, StatusCode::OK, "{}", updated.body);
+    let budget = app
+        .put(&format!("/api/v1/budgets/2025-03/categories/{}", id(&food)), &user.token, json!({ "amount": "1500.50" }))
+        .await;
+    assert_eq!(budget.status, StatusCode::OK, "{}", budget.body);
+    assert_eq!(budget.data()["amount"], "1500.50");
+}
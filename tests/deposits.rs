@@ -0,0 +1,159 @@
+mod common;
+
+use actix_web::http::StatusCode;
+use serde_json::json;
+use sqlx::PgPool;
+
+use common::factories::{create_target, create_target_in_currency, create_user};
+use common::{amount, money, TestApp};
+
+#[sqlx::test(migrations = "./migrations")]
+async fn deposit_updates_balance_ledger_and_activity(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "1000000").await;
+
+    let deposit = app
+        .post(
+            &format!("/api/v1/savings/targets/{}/deposit", target.id),
+            &user.token,
+            json!({ "amount": 250000 }),
+        )
+        .await;
+    assert_eq!(deposit.status, StatusCode::OK, "{}", deposit.body);
+    let data = deposit.data();
+    assert_eq!(amount(&data["target"]["current_amount"]), money("250000"));
+    assert_eq!(data["target"]["is_completed"], false);
+    assert_eq!(data["activity"]["activity_type"], "deposit");
+    assert!(data["completed_activity"].is_null());
+    assert_eq!(data["statistics"]["total_saved"], "250000.00");
+
+    let ledger = app
+        .get(&format!("/api/v1/savings/targets/{}/transactions", target.id), &user.token)
+        .await;
+    assert_eq!(ledger.status, StatusCode::OK, "{}", ledger.body);
+    let entries = ledger.data().as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["transaction_type"], "deposit");
+    assert_eq!(amount(&entries[0]["amount"]), money("250000"));
+
+    let reconciliation = app.get("/api/v1/savings/reconciliation", &user.token).await;
+    assert_eq!(reconciliation.status, StatusCode::OK, "{}", reconciliation.body);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn deposit_reaching_the_target_completes_it(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "100000").await;
+    let uri = format!("/api/v1/savings/targets/{}/deposit", target.id);
+
+    let first = app.post(&uri, &user.token, json!({ "amount": 60000 })).await;
+    assert_eq!(first.status, StatusCode::OK, "{}", first.body);
+    assert!(first.data()["completed_activity"].is_null());
+
+    let second = app.post(&uri, &user.token, json!({ "amount": 40000 })).await;
+    assert_eq!(second.status, StatusCode::OK, "{}", second.body);
+    assert_eq!(second.data()["target"]["is_completed"], true);
+    assert_eq!(second.data()["completed_activity"]["activity_type"], "target_completed");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn deposit_rejects_invalid_amount_and_foreign_target(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let owner = create_user(&pool).await;
+    let other = create_user(&pool).await;
+    let target = create_target(&pool, owner.id, "100000").await;
+    let uri = format!("/api/v1/savings/targets/{}/deposit", target.id);
+
+    let zero = app.post(&uri, &owner.token, json!({ "amount": 0 })).await;
+    assert_eq!(zero.status, StatusCode::BAD_REQUEST);
+
+    // Nominal lebih dari 2 desimal ditolak, bukan dibulatkan
+    let fractional = app.post(&uri, &owner.token, json!({ "amount": "1000.005" })).await;
+    assert_eq!(fractional.status, StatusCode::BAD_REQUEST, "{}", fractional.body);
+    assert_eq!(fractional.error_code(), "bad_request");
+
+    // Rupiah tidak punya digit minor
+    let cents = app.post(&uri, &owner.token, json!({ "amount": 0.1 })).await;
+    assert_eq!(cents.status, StatusCode::BAD_REQUEST, "{}", cents.body);
+    assert_eq!(cents.body["fields"]["amount"][0]["code"], "minor_units");
+
+    let dollar = create_target_in_currency(&pool, owner.id, "100", "USD").await;
+    let exact = app
+        .post(&format!("/api/v1/savings/targets/{}/deposit", dollar.id), &owner.token, json!({ "amount": 0.1 }))
+        .await;
+    assert_eq!(exact.status, StatusCode::OK, "{}", exact.body);
+    assert_eq!(exact.data()["target"]["current_amount"], "0.10");
+
+    // Target user lain tidak bisa dibedakan dari target yang tidak ada
+    let foreign = app.post(&uri, &other.token, json!({ "amount": 1000 })).await;
+    assert_eq!(foreign.status, StatusCode::NOT_FOUND);
+
+    let missing = app
+        .post(
+            &format!("/api/v1/savings/targets/{}/deposit", uuid::Uuid::new_v4()),
+            &owner.token,
+            json!({ "amount": 1000 }),
+        )
+        .await;
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+    assert_eq!(missing.error_code(), "not_found");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn withdraw_more_than_balance_is_unprocessable(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "500000").await;
+
+    let deposit = app
+        .post(
+            &format!("/api/v1/savings/targets/{}/deposit", target.id),
+            &user.token,
+            json!({ "amount": 50000 }),
+        )
+        .await;
+    assert_eq!(deposit.status, StatusCode::OK, "{}", deposit.body);
+
+    let withdraw = app
+        .post(
+            &format!("/api/v1/savings/targets/{}/withdraw", target.id),
+            &user.token,
+            json!({ "amount": 75000 }),
+        )
+        .await;
+    assert_eq!(withdraw.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(withdraw.error_code(), "unprocessable_entity");
+    assert_eq!(withdraw.body["message"], "Insufficient funds: available 50000.00, requested 75000.00");
+
+    // Saldo tidak berubah setelah penarikan yang gagal
+    let current: bigdecimal::BigDecimal =
+        sqlx::query_scalar("SELECT current_amount FROM savings_targets WHERE id = $1")
+            .bind(target.id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(current, bigdecimal::BigDecimal::from(50000));
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn completion_follows_balance_when_target_is_updated(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "100000").await;
+    let uri = format!("/api/v1/savings/targets/{}", target.id);
+
+    let deposit = app.post(&format!("{}/deposit", uri), &user.token, json!({ "amount": 60000 })).await;
+    assert_eq!(deposit.status, StatusCode::OK, "{}", deposit.body);
+
+    // is_completed dari client diabaikan, status mengikuti saldo
+    let forced = app.put(&uri, &user.token, json!({ "is_completed": true })).await;
+    assert_eq!(forced.status, StatusCode::OK, "{}", forced.body);
+    assert_eq!(forced.data()["is_completed"], false);
+
+    let lowered = app.put(&uri, &user.token, json!({ "target_amount": 50000 })).await;
+    assert_eq!(lowered.data()["is_completed"], true);
+    let raised = app.put(&uri, &user.token, json!({ "target_amount": 80000 })).await;
+    assert_eq!(raised.data()["is_completed"], false);
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use actix_web::http::StatusCode;
+use chrono::Duration;
+use serde_json::json;
+use sqlx::PgPool;
+
+use common::factories::{
+    create_activity, create_target, create_target_with_date, create_transaction, create_user, jakarta_noon, jakarta_today,
+};
+use common::TestApp;
+
+#[sqlx::test(migrations = "./migrations")]
+async fn forecast_projects_completion_from_ledger_history(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let today = jakarta_today();
+    let target = create_target_with_date(&pool, user.id, "10000", Some(today + Duration::days(60))).await;
+    let uri = format!("/api/v1/savings/targets/{}/forecast", target.id);
+
+    let deposit = app
+        .post(&format!("/api/v1/savings/targets/{}/deposit", target.id), &user.token, json!({ "amount": "1000" }))
+        .await;
+    assert_eq!(deposit.status, StatusCode::OK, "{}", deposit.body);
+    // Entry lama (sebelum target tercatat dibuat) ikut memperpanjang window riwayat
+    create_transaction(&pool, user.id, target.id, "deposit", 1_500, jakarta_noon(29)).await;
+    create_transaction(&pool, user.id, target.id, "deposit", 1_500, jakarta_noon(15)).await;
+    create_transaction(&pool, user.id, target.id, "withdrawal", -500, jakarta_noon(10)).await;
+    // Activity bukan sumber proyeksi
+    create_activity(&pool, user.id, Some(target.id), "deposit", 5_000, jakarta_noon(5)).await;
+
+    let forecast = app.get(&uri, &user.token).await;
+    assert_eq!(forecast.status, StatusCode::OK, "{}", forecast.body);
+    let data = forecast.data();
+    assert_eq!(data["remaining"], "6500.00");
+    assert_eq!(data["days_remaining"], 60);
+
+    let history = &data["history"];
+    assert_eq!(history["window_start"], (today - Duration::days(29)).to_string());
+    assert_eq!(history["window_days"], 30);
+    assert_eq!(history["deposit_count"], 3);
+    assert_eq!(history["total_deposited"], "4000.00");
+    assert_eq!(history["total_withdrawn"], "500.00");
+    assert_eq!(history["net_flow"], "3500.00");
+    assert_eq!(history["daily_rate"], "116.67");
+    assert_eq!(history["last_deposit_date"], today.to_string());
+
+    // 6500 / 116.67 per hari = 56 hari, sebelum deadline
+    assert_eq!(data["projected_completion_date"], (today + Duration::days(56)).to_string());
+    assert_eq!(data["status"], "on_track");
+    assert_eq!(data["required"]["daily"], "106.56");
+    assert_eq!(data["required"]["weekly"], "722.23");
+    assert!(data["what_if"].is_null());
+
+    let what_if = app.get(&format!("{}?what_if_amount=1000&what_if_frequency=weekly", uri), &user.token).await;
+    assert_eq!(what_if.status, StatusCode::OK, "{}", what_if.body);
+    let scenario = &what_if.data()["what_if"];
+    assert_eq!(scenario["contributions_needed"], 7);
+    assert_eq!(scenario["projected_completion_date"], (today + Duration::days(42)).to_string());
+    assert_eq!(scenario["status"], "on_track");
+
+    let too_little = app.get(&format!("{}?what_if_amount=500&what_if_frequency=weekly", uri), &user.token).await;
+    assert_eq!(too_little.data()["what_if"]["status"], "behind");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn forecast_counts_withdrawals_against_deposits(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let today = jakarta_today();
+    let target = create_target_with_date(&pool, user.id, "10000", Some(today + Duration::days(60))).await;
+    let uri = format!("/api/v1/savings/targets/{}/forecast", target.id);
+
+    // Setiap bulan disetor lalu ditarik lagi
+    for days_ago in [60, 30] {
+        create_transaction(&pool, user.id, target.id, "deposit", 3_000, jakarta_noon(days_ago)).await;
+        create_transaction(&pool, user.id, target.id, "withdrawal", -2_900, jakarta_noon(days_ago - 15)).await;
+    }
+
+    let forecast = app.get(&uri, &user.token).await;
+    assert_eq!(forecast.status, StatusCode::OK, "{}", forecast.body);
+    let data = forecast.data();
+    let history = &data["history"];
+    assert_eq!(history["total_deposited"], "6000.00");
+    assert_eq!(history["total_withdrawn"], "5800.00");
+    assert_eq!(history["net_flow"], "200.00");
+    assert_eq!(data["remaining"], "9800.00");
+    assert_eq!(data["status"], "behind");
+
+    // Penarikan yang menghabiskan sisa saldo: arus bersih nol, tidak ada proyeksi
+    let withdraw = app
+        .post(&format!("/api/v1/savings/targets/{}/withdraw", target.id), &user.token, json!({ "amount": "200" }))
+        .await;
+    assert_eq!(withdraw.status, StatusCode::OK, "{}", withdraw.body);
+
+    let drained = app.get(&uri, &user.token).await;
+    let data = drained.data();
+    assert_eq!(data["history"]["net_flow"], "0.00");
+    assert_eq!(data["history"]["daily_rate"], "0.00");
+    assert!(data["projected_completion_date"].is_null());
+    assert_eq!(data["status"], "behind");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn forecast_handles_missing_deadline_and_invalid_input(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let other = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "10000").await;
+    let uri = format!("/api/v1/savings/targets/{}/forecast", target.id);
+
+    let forecast = app.get(&uri, &user.token).await;
+    assert_eq!(forecast.status, StatusCode::OK, "{}", forecast.body);
+    let data = forecast.data();
+    assert_eq!(data["status"], "no_deadline");
+    assert_eq!(data["history"]["deposit_count"], 0);
+    assert!(data["projected_completion_date"].is_null());
+    assert!(data["required"].is_null());
+
+    let what_if = app.get(&format!("{}?what_if_amount=2500", uri), &user.token).await;
+    assert_eq!(what_if.data()["what_if"]["frequency"], "monthly");
+    assert_eq!(what_if.data()["what_if"]["contributions_needed"], 4);
+
+    let zero = app.get(&format!("{}?what_if_amount=0", uri), &user.token).await;
+    assert_eq!(zero.status, StatusCode::BAD_REQUEST, "{}", zero.body);
+    let bad_frequency = app.get(&format!("{}?what_if_amount=100&what_if_frequency=yearly", uri), &user.token).await;
+    assert_eq!(bad_frequency.status, StatusCode::BAD_REQUEST, "{}", bad_frequency.body);
+
+    assert_eq!(app.get(&uri, &other.token).await.status, StatusCode::NOT_FOUND);
+}
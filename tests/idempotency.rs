@@ -0,0 +1,83 @@
+mod common;
+
+use actix_web::http::StatusCode;
+use serde_json::json;
+use sqlx::PgPool;
+
+use tabungin_api::middleware::idempotency::{begin_idempotent, IdempotencyKey, IdempotentStart};
+
+use common::factories::{create_target, create_user};
+use common::TestApp;
+
+async fn ledger_entries(pool: &PgPool, target_id: uuid::Uuid) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM transactions WHERE savings_target_id = $1")
+        .bind(target_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn repeated_deposit_with_same_key_is_recorded_once(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "1000000").await;
+    let uri = format!("/api/v1/savings/targets/{}/deposit", target.id);
+
+    let first = app.post_idempotent(&uri, &user.token, "deposit-1", json!({ "amount": "50000" })).await;
+    assert_eq!(first.status, StatusCode::OK, "{}", first.body);
+    assert!(first.headers.get("Idempotent-Replayed").is_none());
+
+    let retry = app.post_idempotent(&uri, &user.token, "deposit-1", json!({ "amount": "50000" })).await;
+    assert_eq!(retry.status, StatusCode::OK, "{}", retry.body);
+    assert_eq!(retry.headers.get("Idempotent-Replayed").unwrap(), "true");
+    assert_eq!(retry.body, first.body);
+    assert_eq!(ledger_entries(&pool, target.id).await, 1);
+
+    let reused = app.post_idempotent(&uri, &user.token, "deposit-1", json!({ "amount": "75000" })).await;
+    assert_eq!(reused.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", reused.body);
+
+    // Request kedua yang datang bersamaan menunggu request pertama lalu mendapat response yang sama
+    let body = json!({ "amount": "10000" });
+    let (a, b) = futures::join!(
+        app.post_idempotent(&uri, &user.token, "deposit-2", body.clone()),
+        app.post_idempotent(&uri, &user.token, "deposit-2", body.clone()),
+    );
+    assert_eq!(a.status, StatusCode::OK, "{}", a.body);
+    assert_eq!(b.status, StatusCode::OK, "{}", b.body);
+    assert_eq!(a.body, b.body);
+    assert_eq!(ledger_entries(&pool, target.id).await, 2);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn unfinished_request_does_not_block_retries(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "100000").await;
+    let uri = format!("/api/v1/savings/targets/{}/withdraw", target.id);
+
+    // Error bisnis ikut disimpan dan di-replay
+    let overdraw = app.post_idempotent(&uri, &user.token, "withdraw-1", json!({ "amount": "5000" })).await;
+    assert_eq!(overdraw.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", overdraw.body);
+    let replayed = app.post_idempotent(&uri, &user.token, "withdraw-1", json!({ "amount": "5000" })).await;
+    assert_eq!(replayed.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(replayed.headers.get("Idempotent-Replayed").unwrap(), "true");
+
+    // Proses yang mati sebelum commit tidak meninggalkan key yang menggantung
+    let deposit_uri = format!("/api/v1/savings/targets/{}/deposit", target.id);
+    let body = json!({ "amount": "20000" });
+    let key = IdempotencyKey {
+        key: Some("deposit-crash".to_string()),
+        method: "POST".to_string(),
+        path: deposit_uri.clone(),
+    };
+    match begin_idempotent(&pool, user.id, &key, &body).await.unwrap() {
+        IdempotentStart::Proceed(tx) => drop(tx),
+        IdempotentStart::Replay(_) => panic!("new key must not be replayed"),
+    }
+
+    let retry = app.post_idempotent(&deposit_uri, &user.token, "deposit-crash", body).await;
+    assert_eq!(retry.status, StatusCode::OK, "{}", retry.body);
+    assert_eq!(retry.data()["target"]["current_amount"], "20000.00");
+    assert_eq!(ledger_entries(&pool, target.id).await, 1);
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use actix_web::http::StatusCode;
+use serde_json::json;
+use sqlx::PgPool;
+
+use common::factories::{create_target, create_user};
+use common::TestApp;
+
+#[sqlx::test(migrations = "./migrations")]
+async fn notifications_are_paginated_with_bounded_page(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "1000000").await;
+
+    let deposit = app
+        .post(&format!("/api/v1/savings/targets/{}/deposit", target.id), &user.token, json!({ "amount": 50000 }))
+        .await;
+    assert_eq!(deposit.status, StatusCode::OK, "{}", deposit.body);
+
+    let list = app.get("/api/v1/notifications?page=1&per_page=20", &user.token).await;
+    assert_eq!(list.status, StatusCode::OK, "{}", list.body);
+    assert_eq!(list.data()["total"], 1);
+    assert_eq!(list.data()["notifications"].as_array().unwrap().len(), 1);
+
+    // Batas page dari validate_page harus terpasang di query endpoint ini
+    let huge = app.get(&format!("/api/v1/notifications?page={}&per_page=100", i64::MAX), &user.token).await;
+    assert_eq!(huge.status, StatusCode::BAD_REQUEST, "{}", huge.body);
+    assert_eq!(huge.error_code(), "validation_failed");
+    assert!(huge.body["fields"]["page"].is_array());
+
+    let last = app.get("/api/v1/notifications?page=1000&per_page=100", &user.token).await;
+    assert_eq!(last.status, StatusCode::OK, "{}", last.body);
+    assert_eq!(last.data()["notifications"], json!([]));
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use actix_web::http::Method;
+use sqlx::PgPool;
+use utoipa::OpenApi;
+
+use tabungin_api::openapi::ApiDoc;
+
+use common::TestApp;
+
+/// Semua (method, path) yang ada di spesifikasi OpenAPI
+fn documented_routes() -> BTreeSet<(String, String)> {
+    let mut routes = BTreeSet::new();
+    for (path, item) in ApiDoc::openapi().paths.paths {
+        let operations = [
+            (Method::GET, item.get.is_some()),
+            (Method::POST, item.post.is_some()),
+            (Method::PUT, item.put.is_some()),
+            (Method::PATCH, item.patch.is_some()),
+            (Method::DELETE, item.delete.is_some()),
+        ];
+        for (method, present) in operations {
+            if present {
+                routes.insert((method.to_string(), path.clone()));
+            }
+        }
+    }
+    routes
+}
+
+/// Isi parameter path dengan UUID supaya bisa dikirim ke router, misal `/targets/{id}` -> `/targets/<uuid>`
+fn concrete_uri(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if segment.starts_with('{') {
+                uuid::Uuid::nil().to_string()
+            } else {
+                segment.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn every_documented_route_is_served_by_the_router(pool: PgPool) {
+    let app = TestApp::new(pool);
+    let documented = documented_routes();
+    assert!(documented.len() > 50, "spec has only {} operations", documented.len());
+
+    // Harus dilayani resource dengan pattern yang sama persis, bukan route lain yang kebetulan cocok
+    let mut unserved = Vec::new();
+    for (method, path) in &documented {
+        let pattern = app.probe(method.parse().unwrap(), &concrete_uri(path)).await;
+        if pattern.as_deref() != Some(path.as_str()) {
+            unserved.push(format!("{} {} -> {:?}", method, path, pattern));
+        }
+    }
+    assert!(unserved.is_empty(), "documented routes not served by the router: {:#?}", unserved);
+}
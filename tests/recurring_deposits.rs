@@ -0,0 +1,98 @@
+mod common;
+
+use actix_web::http::StatusCode;
+use chrono::{Duration, NaiveDate};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use tabungin_api::services::recurring_deposit_service::process_due_recurring_deposits;
+
+use common::factories::{create_target, create_user, db_today};
+use common::TestApp;
+
+/// Rule harian langsung lewat SQL supaya bisa membuat rule yang pasti gagal diproses
+async fn create_rule(pool: &PgPool, user_id: Uuid, target_id: Uuid, next_run_date: NaiveDate) -> Uuid {
+    sqlx::query_scalar(
+        r#"
+        INSERT INTO recurring_deposits (user_id, savings_target_id, amount, frequency, start_date, next_run_date)
+        VALUES ($1, $2, 1000, 'daily', $3, $3)
+        RETURNING id
+        "#,
+    )
+    .bind(user_id)
+    .bind(target_id)
+    .bind(next_run_date)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+async fn rule_state(pool: &PgPool, rule_id: Uuid) -> (NaiveDate, bool, i32, Option<String>) {
+    sqlx::query_as(
+        "SELECT next_run_date, is_paused, failure_count, last_error FROM recurring_deposits WHERE id = $1",
+    )
+    .bind(rule_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn failing_rules_do_not_block_healthy_rules(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let owner = create_user(&pool).await;
+    let stranger = create_user(&pool).await;
+    let target = create_target(&pool, owner.id, "100000").await;
+    let full = create_target(&pool, owner.id, "100000").await;
+    let today = db_today(&pool).await;
+
+    // Saldo di batas DECIMAL(15,2): setoran berikutnya gagal di database (bukan error bisnis)
+    sqlx::query(
+        "INSERT INTO transactions (user_id, savings_target_id, transaction_type, amount) VALUES ($1, $2, 'adjustment', 9999999999999)",
+    )
+    .bind(owner.id)
+    .bind(full.id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // Rule yang gagal lebih dulu jatuh tempo dari rule yang sehat
+    let forbidden = create_rule(&pool, stranger.id, target.id, today - Duration::days(3)).await;
+    let overflowing = create_rule(&pool, owner.id, full.id, today - Duration::days(2)).await;
+    let healthy = create_rule(&pool, owner.id, target.id, today).await;
+
+    assert_eq!(process_due_recurring_deposits(&pool).await.unwrap(), 1);
+
+    let (next_run_date, is_paused, failure_count, _) = rule_state(&pool, healthy).await;
+    assert_eq!(next_run_date, today + Duration::days(1));
+    assert!(!is_paused);
+    assert_eq!(failure_count, 0);
+
+    // Bukan anggota target: tidak akan pernah berhasil, langsung di-pause
+    let paused = app.get(&format!("/api/v1/recurring-deposits/{}", forbidden), &stranger.token).await;
+    assert_eq!(paused.status, StatusCode::OK, "{}", paused.body);
+    assert_eq!(paused.data()["is_paused"], true);
+    assert_eq!(paused.data()["failure_count"], 1);
+    assert!(paused.data()["last_error"].is_string());
+
+    // Error lain dicoba lagi besok
+    let (next_run_date, is_paused, failure_count, _) = rule_state(&pool, overflowing).await;
+    assert_eq!(next_run_date, today + Duration::days(1));
+    assert!(!is_paused);
+    assert_eq!(failure_count, 1);
+
+    assert_eq!(process_due_recurring_deposits(&pool).await.unwrap(), 0);
+    assert_eq!(rule_state(&pool, overflowing).await.2, 1);
+
+    // Setelah 5 kali gagal berturut-turut rule di-pause
+    sqlx::query("UPDATE recurring_deposits SET failure_count = 4, next_run_date = $2 WHERE id = $1")
+        .bind(overflowing)
+        .bind(today)
+        .execute(&pool)
+        .await
+        .unwrap();
+    process_due_recurring_deposits(&pool).await.unwrap();
+    let (_, is_paused, failure_count, _) = rule_state(&pool, overflowing).await;
+    assert!(is_paused);
+    assert_eq!(failure_count, 5);
+}
@@ -0,0 +1,197 @@
+mod common;
+
+use actix_web::http::StatusCode;
+use chrono::{Duration, NaiveDate};
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use tabungin_api::services::reminder_service::{ReminderService, DISPATCH_WINDOW_DAYS};
+
+use common::factories::{create_reminder, create_target, create_target_with_date, create_user, db_today};
+use common::TestApp;
+
+async fn is_notified(pool: &PgPool, reminder_id: Uuid) -> bool {
+    sqlx::query_scalar("SELECT is_notified FROM reminders WHERE id = $1")
+        .bind(reminder_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+fn ids(data: &Value) -> Vec<String> {
+    data.as_array()
+        .unwrap()
+        .iter()
+        .map(|reminder| reminder["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn target_with_deadline_gets_reminders(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let deadline = db_today(&pool).await + Duration::days(60);
+    let target = create_target_with_date(&pool, user.id, "1000000", Some(deadline)).await;
+
+    let list = app.get("/api/v1/reminders", &user.token).await;
+    assert_eq!(list.status, StatusCode::OK, "{}", list.body);
+
+    // 30 hari sebelum, 7 hari sebelum dan tepat di deadline, urut reminder_date
+    let items = list.data()["items"].as_array().unwrap();
+    let dates: Vec<&str> = items.iter().map(|r| r["reminder_date"].as_str().unwrap()).collect();
+    let expected = [
+        (deadline - Duration::days(30)).to_string(),
+        (deadline - Duration::days(7)).to_string(),
+        deadline.to_string(),
+    ];
+    assert_eq!(dates, expected);
+    assert!(items.iter().all(|r| r["target_name"] == target.name.as_str()));
+    assert_eq!(items[2]["reminder_type"], "target_deadline");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn today_and_upcoming_skip_completed_and_out_of_range(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "500000").await;
+    let today = db_today(&pool).await;
+
+    let due_today = create_reminder(&pool, user.id, target.id, today).await;
+    let completed_today = create_reminder(&pool, user.id, target.id, today).await;
+    let in_five_days = create_reminder(&pool, user.id, target.id, today + Duration::days(5)).await;
+    let in_twenty_days = create_reminder(&pool, user.id, target.id, today + Duration::days(20)).await;
+    let past = create_reminder(&pool, user.id, target.id, today - Duration::days(1)).await;
+
+    let complete = app
+        .put(&format!("/api/v1/reminders/{}/complete", completed_today), &user.token, json!({}))
+        .await;
+    assert_eq!(complete.status, StatusCode::OK, "{}", complete.body);
+
+    let todays = app.get("/api/v1/reminders/today", &user.token).await;
+    assert_eq!(todays.status, StatusCode::OK, "{}", todays.body);
+    assert_eq!(ids(todays.data()), vec![due_today.to_string()]);
+
+    let week = app.get("/api/v1/reminders/upcoming?days=7", &user.token).await;
+    assert_eq!(week.status, StatusCode::OK, "{}", week.body);
+    assert_eq!(ids(week.data()), vec![due_today.to_string(), in_five_days.to_string()]);
+
+    // Default 30 hari
+    let month = app.get("/api/v1/reminders/upcoming", &user.token).await;
+    let upcoming = ids(month.data());
+    assert_eq!(upcoming.len(), 3);
+    assert!(upcoming.contains(&in_twenty_days.to_string()));
+    assert!(!upcoming.contains(&past.to_string()));
+
+    let invalid = app.get("/api/v1/reminders/upcoming?days=abc", &user.token).await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn calendar_returns_reminders_of_the_requested_month(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "500000").await;
+
+    let in_march = create_reminder(&pool, user.id, target.id, NaiveDate::from_ymd_opt(2030, 3, 31).unwrap()).await;
+    create_reminder(&pool, user.id, target.id, NaiveDate::from_ymd_opt(2030, 4, 1).unwrap()).await;
+    let in_december = create_reminder(&pool, user.id, target.id, NaiveDate::from_ymd_opt(2030, 12, 31).unwrap()).await;
+
+    let march = app.get("/api/v1/reminders/calendar?month=3&year=2030", &user.token).await;
+    assert_eq!(march.status, StatusCode::OK, "{}", march.body);
+    assert_eq!(ids(march.data()), vec![in_march.to_string()]);
+
+    let december = app.get("/api/v1/reminders/calendar?month=12&year=2030", &user.token).await;
+    assert_eq!(ids(december.data()), vec![in_december.to_string()]);
+
+    let invalid = app.get("/api/v1/reminders/calendar?month=13&year=2030", &user.token).await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST);
+
+    // Tanpa parameter pakai bulan berjalan
+    let current = app.get("/api/v1/reminders/calendar", &user.token).await;
+    assert_eq!(current.status, StatusCode::OK, "{}", current.body);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn reminders_are_scoped_to_their_owner(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let owner = create_user(&pool).await;
+    let other = create_user(&pool).await;
+    let target = create_target(&pool, owner.id, "500000").await;
+    let reminder = create_reminder(&pool, owner.id, target.id, db_today(&pool).await).await;
+
+    let list = app.get("/api/v1/reminders", &other.token).await;
+    assert_eq!(list.status, StatusCode::OK, "{}", list.body);
+    assert!(list.data()["items"].as_array().unwrap().is_empty());
+
+    let complete = app
+        .put(&format!("/api/v1/reminders/{}/complete", reminder), &other.token, json!({}))
+        .await;
+    assert_eq!(complete.status, StatusCode::NOT_FOUND);
+
+    let unknown = app
+        .put(&format!("/api/v1/reminders/{}/complete", Uuid::new_v4()), &owner.token, json!({}))
+        .await;
+    assert_eq!(unknown.status, StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn dispatch_skips_reminders_older_than_the_window(pool: PgPool) {
+    let user = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "500000").await;
+    let today = db_today(&pool).await;
+    let window = Duration::days(DISPATCH_WINDOW_DAYS as i64);
+
+    let due_today = create_reminder(&pool, user.id, target.id, today).await;
+    let last_in_window = create_reminder(&pool, user.id, target.id, today - window + Duration::days(1)).await;
+    let stale = create_reminder(&pool, user.id, target.id, today - window).await;
+    let ancient = create_reminder(&pool, user.id, target.id, today - Duration::days(400)).await;
+
+    let due = ReminderService::new(pool.clone()).dispatch_due_reminders(100).await.unwrap();
+    let mut dispatched: Vec<Uuid> = due.iter().map(|reminder| reminder.id).collect();
+    dispatched.sort();
+    let mut expected = vec![due_today, last_in_window];
+    expected.sort();
+    assert_eq!(dispatched, expected);
+
+    // Reminder basi tidak dikirim dan tidak ditandai terkirim
+    assert!(!is_notified(&pool, stale).await);
+    assert!(!is_notified(&pool, ancient).await);
+
+    let notifications: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM notifications WHERE user_id = $1")
+        .bind(user.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(notifications, 2);
+
+    // Putaran berikutnya tidak mengirim ulang
+    let again = ReminderService::new(pool.clone()).dispatch_due_reminders(100).await.unwrap();
+    assert!(again.is_empty());
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn dispatch_skips_reminders_of_completed_targets(pool: PgPool) {
+    let user = create_user(&pool).await;
+    let reached = create_target(&pool, user.id, "100000").await;
+    let open = create_target(&pool, user.id, "100000").await;
+    let today = db_today(&pool).await;
+
+    // Trigger ledger menandai target tercapai begitu saldonya mencapai target_amount
+    sqlx::query(
+        "INSERT INTO transactions (user_id, savings_target_id, transaction_type, amount) VALUES ($1, $2, 'deposit', 100000)",
+    )
+    .bind(user.id)
+    .bind(reached.id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let reached_reminder = create_reminder(&pool, user.id, reached.id, today).await;
+    let open_reminder = create_reminder(&pool, user.id, open.id, today).await;
+
+    let due = ReminderService::new(pool.clone()).dispatch_due_reminders(100).await.unwrap();
+    let dispatched: Vec<Uuid> = due.iter().map(|reminder| reminder.id).collect();
+    assert_eq!(dispatched, vec![open_reminder]);
+    assert!(!is_notified(&pool, reached_reminder).await);
+}
@@ -0,0 +1,171 @@
+mod common;
+
+use actix_web::http::StatusCode;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use common::factories::{add_member, create_admin, create_reminder, create_target, create_user, db_today};
+use common::{TestApp, TestResponse};
+
+#[sqlx::test(migrations = "./migrations")]
+async fn search_finds_targets_and_bounds_page(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "1000000").await;
+
+    let found = app.get("/api/v1/search?q=Target", &user.token).await;
+    assert_eq!(found.status, StatusCode::OK, "{}", found.body);
+    assert!(found.data()["results"].as_array().unwrap().iter().any(|r| r["id"] == target.id.to_string()));
+
+    // Batas page dari validate_page harus terpasang di query endpoint ini
+    let huge = app.get(&format!("/api/v1/search?q=target&page={}&per_page=50", i64::MAX), &user.token).await;
+    assert_eq!(huge.status, StatusCode::BAD_REQUEST, "{}", huge.body);
+    assert_eq!(huge.error_code(), "validation_failed");
+    assert!(huge.body["fields"]["page"].is_array());
+
+    let last = app.get("/api/v1/search?q=target&page=1000&per_page=50", &user.token).await;
+    assert_eq!(last.status, StatusCode::OK, "{}", last.body);
+    assert_eq!(last.data()["results"], serde_json::json!([]));
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn search_includes_targets_shared_with_the_user(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let owner = create_user(&pool).await;
+    let member = create_user(&pool).await;
+    let stranger = create_user(&pool).await;
+    let target = create_target(&pool, owner.id, "1000000").await;
+    add_member(&pool, target.id, member.id, "viewer").await;
+
+    let uri = format!("/api/v1/search?q={}&types=savings_target", target.name.replace(' ', "+"));
+    let found = app.get(&uri, &member.token).await;
+    assert_eq!(found.status, StatusCode::OK, "{}", found.body);
+    let results = found.data()["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1, "{}", found.body);
+    assert_eq!(results[0]["id"], target.id.to_string());
+
+    let hidden = app.get(&uri, &stranger.token).await;
+    assert_eq!(hidden.data()["total"], 0, "{}", hidden.body);
+}
+
+/// Target dengan nama tertentu (factory memberi nama acak)
+async fn named_target(pool: &PgPool, user_id: Uuid, name: &str) -> Uuid {
+    let target = create_target(pool, user_id, "1000000").await;
+    sqlx::query("UPDATE savings_targets SET name = $1 WHERE id = $2")
+        .bind(name)
+        .bind(target.id)
+        .execute(pool)
+        .await
+        .unwrap();
+    target.id
+}
+
+fn result_ids(response: &TestResponse) -> Vec<String> {
+    response.data()["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| result["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+fn result_types(response: &TestResponse) -> Vec<String> {
+    let mut types: Vec<String> = response.data()["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| result["result_type"].as_str().unwrap().to_string())
+        .collect();
+    types.sort();
+    types.dedup();
+    types
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn search_tolerates_typos(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let trip = named_target(&pool, user.id, "Liburan ke Bali").await;
+    named_target(&pool, user.id, "Dana Darurat").await;
+
+    let typo = app.get("/api/v1/search?q=liburn&types=savings_target", &user.token).await;
+    assert_eq!(typo.status, StatusCode::OK, "{}", typo.body);
+    assert_eq!(result_ids(&typo), vec![trip.to_string()]);
+
+    let unrelated = app.get("/api/v1/search?q=kendaraan&types=savings_target", &user.token).await;
+    assert_eq!(unrelated.data()["total"], 0, "{}", unrelated.body);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn search_ranks_exact_matches_above_partial_ones(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let exact = named_target(&pool, user.id, "Motor").await;
+    // Dibuat belakangan: kalau skor sama, yang terbaru akan muncul duluan
+    let partial = named_target(&pool, user.id, "Motorik anak").await;
+
+    let found = app.get("/api/v1/search?q=motor", &user.token).await;
+    assert_eq!(found.status, StatusCode::OK, "{}", found.body);
+    assert_eq!(result_ids(&found), vec![exact.to_string(), partial.to_string()]);
+
+    let results = found.data()["results"].as_array().unwrap();
+    assert!(results[0]["rank"].as_f64().unwrap() > results[1]["rank"].as_f64().unwrap());
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn search_filters_by_result_type(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let target = named_target(&pool, user.id, "Rajin menabung").await;
+    let reminder = create_reminder(&pool, user.id, target, db_today(&pool).await).await;
+
+    let all = app.get("/api/v1/search?q=menabung", &user.token).await;
+    assert_eq!(all.status, StatusCode::OK, "{}", all.body);
+    assert_eq!(result_types(&all), vec!["reminder", "savings_target"]);
+
+    let reminders = app.get("/api/v1/search?q=menabung&types=reminder", &user.token).await;
+    assert_eq!(result_ids(&reminders), vec![reminder.to_string()]);
+
+    let both = app.get("/api/v1/search?q=menabung&types=reminder,savings_target", &user.token).await;
+    assert_eq!(both.data()["total"], 2, "{}", both.body);
+
+    let unknown = app.get("/api/v1/search?q=menabung&types=reminder,bogus", &user.token).await;
+    assert_eq!(unknown.status, StatusCode::BAD_REQUEST, "{}", unknown.body);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn users_and_testimoni_are_only_searchable_by_admins(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let admin = create_admin(&pool).await;
+    let author = create_user(&pool).await;
+    sqlx::query("UPDATE users SET full_name = 'Siti Rahmawati' WHERE id = $1")
+        .bind(author.id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let testimoni: Uuid = sqlx::query_scalar(
+        "INSERT INTO testimoni (id, user_id, content, rating) VALUES ($1, $2, 'Rahmawati sangat terbantu menabung', 5) RETURNING id",
+    )
+    .bind(Uuid::new_v4())
+    .bind(author.id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    // User biasa tidak melihat user lain maupun testimoni, juga kalau diminta lewat types
+    for uri in ["/api/v1/search?q=rahmawati", "/api/v1/search?q=rahmawati&types=user,testimoni"] {
+        let hidden = app.get(uri, &user.token).await;
+        assert_eq!(hidden.status, StatusCode::OK, "{}", hidden.body);
+        assert_eq!(hidden.data()["total"], 0, "{}", hidden.body);
+    }
+    let by_email = app.get(&format!("/api/v1/search?q={}&types=user", author.email), &user.token).await;
+    assert_eq!(by_email.data()["total"], 0, "{}", by_email.body);
+
+    let visible = app.get("/api/v1/search?q=rahmawati&types=user,testimoni", &admin.token).await;
+    assert_eq!(visible.status, StatusCode::OK, "{}", visible.body);
+    assert_eq!(result_types(&visible), vec!["testimoni", "user"]);
+    let ids = result_ids(&visible);
+    assert!(ids.contains(&author.id.to_string()));
+    assert!(ids.contains(&testimoni.to_string()));
+}
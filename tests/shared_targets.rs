@@ -0,0 +1,233 @@
+mod common;
+
+use actix_web::http::{Method, StatusCode};
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use common::factories::{create_target, create_user, TestUser};
+use common::TestApp;
+
+/// Undang `invitee` ke target lalu terima undangannya
+async fn join(app: &TestApp, owner: &TestUser, invitee: &TestUser, target_id: Uuid, role: &str) -> Value {
+    let invited = app
+        .post(
+            &format!("/api/v1/savings/targets/{}/invitations", target_id),
+            &owner.token,
+            json!({ "email": invitee.email.to_uppercase(), "role": role }),
+        )
+        .await;
+    assert_eq!(invited.status, StatusCode::CREATED, "{}", invited.body);
+    assert_eq!(invited.data()["status"], "pending");
+
+    let pending = app.get("/api/v1/savings/invitations", &invitee.token).await;
+    assert_eq!(pending.data().as_array().unwrap().len(), 1);
+
+    let accepted = app
+        .post(&format!("/api/v1/savings/invitations/{}/accept", invited.data()["id"].as_str().unwrap()), &invitee.token, json!({}))
+        .await;
+    assert_eq!(accepted.status, StatusCode::OK, "{}", accepted.body);
+    assert_eq!(accepted.data()["status"], "accepted");
+    accepted.data().clone()
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn members_share_a_target_with_role_based_access(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let owner = create_user(&pool).await;
+    let contributor = create_user(&pool).await;
+    let viewer = create_user(&pool).await;
+    let stranger = create_user(&pool).await;
+    let target = create_target(&pool, owner.id, "10000").await;
+    let target_uri = format!("/api/v1/savings/targets/{}", target.id);
+
+    join(&app, &owner, &contributor, target.id, "contributor").await;
+    join(&app, &owner, &viewer, target.id, "viewer").await;
+
+    let invitation_notifications: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM notifications WHERE user_id = $1 AND type = 'invitation'",
+    )
+    .bind(contributor.id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(invitation_notifications, 1);
+
+    // Target bersama muncul di daftar target anggota
+    let listed = app.get("/api/v1/savings/targets", &contributor.token).await;
+    assert_eq!(listed.data()["items"].as_array().unwrap().len(), 1, "{}", listed.body);
+
+    let own = app.post(&format!("{}/deposit", target_uri), &owner.token, json!({ "amount": "3000" })).await;
+    assert_eq!(own.status, StatusCode::OK, "{}", own.body);
+    let shared = app.post(&format!("{}/deposit", target_uri), &contributor.token, json!({ "amount": "1000" })).await;
+    assert_eq!(shared.status, StatusCode::OK, "{}", shared.body);
+    assert_eq!(shared.data()["target"]["current_amount"], "4000.00");
+
+    let viewer_deposit = app.post(&format!("{}/deposit", target_uri), &viewer.token, json!({ "amount": "1000" })).await;
+    assert_eq!(viewer_deposit.status, StatusCode::FORBIDDEN, "{}", viewer_deposit.body);
+    let stranger_deposit = app.post(&format!("{}/deposit", target_uri), &stranger.token, json!({ "amount": "1000" })).await;
+    assert_eq!(stranger_deposit.status, StatusCode::NOT_FOUND, "{}", stranger_deposit.body);
+
+    let withdraw = app.post(&format!("{}/withdraw", target_uri), &contributor.token, json!({ "amount": "500" })).await;
+    assert_eq!(withdraw.status, StatusCode::FORBIDDEN, "{}", withdraw.body);
+    let stranger_withdraw = app.post(&format!("{}/withdraw", target_uri), &stranger.token, json!({ "amount": "500" })).await;
+    assert_eq!(stranger_withdraw.status, StatusCode::NOT_FOUND, "{}", stranger_withdraw.body);
+    let rename = app.put(&target_uri, &contributor.token, json!({ "name": "Punya saya" })).await;
+    assert_eq!(rename.status, StatusCode::FORBIDDEN, "{}", rename.body);
+    let delete = app.request(Method::DELETE, &target_uri, Some(&contributor.token), None).await;
+    assert_eq!(delete.status, StatusCode::FORBIDDEN, "{}", delete.body);
+
+    assert_eq!(app.get(&target_uri, &viewer.token).await.status, StatusCode::OK);
+    assert_eq!(app.get(&target_uri, &stranger.token).await.status, StatusCode::NOT_FOUND);
+    let stranger_ledger = app.get(&format!("{}/transactions", target_uri), &stranger.token).await;
+    assert_eq!(stranger_ledger.status, StatusCode::NOT_FOUND);
+
+    // Kontribusi per anggota, owner di urutan pertama
+    let members = app.get(&format!("{}/members", target_uri), &viewer.token).await;
+    assert_eq!(members.status, StatusCode::OK, "{}", members.body);
+    let members = members.data().as_array().unwrap().clone();
+    assert_eq!(members.len(), 3);
+    assert_eq!(members[0]["role"], "owner");
+    assert_eq!(members[0]["contributed"], "3000.00");
+    assert_eq!(members[0]["share"], 75.0);
+    assert_eq!(members[1]["user_id"], contributor.id.to_string());
+    assert_eq!(members[1]["contributed"], "1000.00");
+    assert_eq!(members[1]["deposit_count"], 1);
+    assert_eq!(members[2]["contributed"], "0.00");
+
+    // Feed activity memperlihatkan siapa yang menabung
+    let feed = app.get(&format!("{}/activities?limit=2", target_uri), &viewer.token).await;
+    assert_eq!(feed.status, StatusCode::OK, "{}", feed.body);
+    let items = feed.data()["items"].as_array().unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["activity_type"], "deposit");
+    assert_eq!(items[0]["user_id"], contributor.id.to_string());
+    assert_eq!(items[0]["user_name"], "Test User");
+    assert_eq!(items[1]["user_id"], owner.id.to_string());
+
+    // Contributor dipromosikan jadi owner, sekarang boleh menarik dana
+    let promoted = app
+        .put(&format!("{}/members/{}", target_uri, contributor.id), &owner.token, json!({ "role": "owner" }))
+        .await;
+    assert_eq!(promoted.status, StatusCode::OK, "{}", promoted.body);
+    let withdraw = app.post(&format!("{}/withdraw", target_uri), &contributor.token, json!({ "amount": "500" })).await;
+    assert_eq!(withdraw.status, StatusCode::OK, "{}", withdraw.body);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn invitations_and_membership_changes_are_guarded(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let owner = create_user(&pool).await;
+    let member = create_user(&pool).await;
+    let other = create_user(&pool).await;
+    let target = create_target(&pool, owner.id, "10000").await;
+    let target_uri = format!("/api/v1/savings/targets/{}", target.id);
+    let invitations_uri = format!("{}/invitations", target_uri);
+
+    // Hanya owner yang boleh mengundang
+    let not_member = app.post(&invitations_uri, &other.token, json!({ "email": member.email })).await;
+    assert_eq!(not_member.status, StatusCode::NOT_FOUND, "{}", not_member.body);
+    let invalid = app.post(&invitations_uri, &owner.token, json!({ "email": "bukan-email" })).await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST, "{}", invalid.body);
+
+    let declined = app.post(&invitations_uri, &owner.token, json!({ "email": other.email })).await;
+    assert_eq!(declined.status, StatusCode::CREATED, "{}", declined.body);
+    assert_eq!(declined.data()["role"], "contributor");
+    let declined_id = declined.data()["id"].as_str().unwrap().to_string();
+
+    let duplicate = app.post(&invitations_uri, &owner.token, json!({ "email": other.email })).await;
+    assert_eq!(duplicate.status, StatusCode::CONFLICT, "{}", duplicate.body);
+
+    // Undangan hanya bisa dijawab pemilik email
+    let hijack = app.post(&format!("/api/v1/savings/invitations/{}/accept", declined_id), &member.token, json!({})).await;
+    assert_eq!(hijack.status, StatusCode::NOT_FOUND, "{}", hijack.body);
+    let decline = app.post(&format!("/api/v1/savings/invitations/{}/decline", declined_id), &other.token, json!({})).await;
+    assert_eq!(decline.status, StatusCode::OK, "{}", decline.body);
+    assert_eq!(decline.data()["status"], "declined");
+    let again = app.post(&format!("/api/v1/savings/invitations/{}/accept", declined_id), &other.token, json!({})).await;
+    assert_eq!(again.status, StatusCode::CONFLICT, "{}", again.body);
+
+    // Undangan yang dibatalkan owner tidak bisa diterima
+    let revoked = app.post(&invitations_uri, &owner.token, json!({ "email": other.email })).await;
+    let revoked_id = revoked.data()["id"].as_str().unwrap().to_string();
+    let revoke = app
+        .request(Method::DELETE, &format!("{}/{}", invitations_uri, revoked_id), Some(&owner.token), None)
+        .await;
+    assert_eq!(revoke.status, StatusCode::OK, "{}", revoke.body);
+    assert!(app.get("/api/v1/savings/invitations", &other.token).await.data().as_array().unwrap().is_empty());
+
+    let all = app.get(&invitations_uri, &owner.token).await;
+    assert_eq!(all.data().as_array().unwrap().len(), 2);
+
+    join(&app, &owner, &member, target.id, "contributor").await;
+    let already = app.post(&invitations_uri, &owner.token, json!({ "email": member.email })).await;
+    assert_eq!(already.status, StatusCode::CONFLICT, "{}", already.body);
+
+    // Contributor tidak boleh mengeluarkan owner, owner terakhir tidak boleh keluar
+    let kick_owner = app
+        .request(Method::DELETE, &format!("{}/members/{}", target_uri, owner.id), Some(&member.token), None)
+        .await;
+    assert_eq!(kick_owner.status, StatusCode::FORBIDDEN, "{}", kick_owner.body);
+    let owner_leaves = app
+        .request(Method::DELETE, &format!("{}/members/{}", target_uri, owner.id), Some(&owner.token), None)
+        .await;
+    assert_eq!(owner_leaves.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", owner_leaves.body);
+    let demote_owner = app
+        .put(&format!("{}/members/{}", target_uri, owner.id), &owner.token, json!({ "role": "viewer" }))
+        .await;
+    assert_eq!(demote_owner.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", demote_owner.body);
+
+    // Anggota yang keluar kehilangan akses, setorannya tetap tercatat di ledger
+    let deposit = app.post(&format!("{}/deposit", target_uri), &member.token, json!({ "amount": "1000" })).await;
+    assert_eq!(deposit.status, StatusCode::OK, "{}", deposit.body);
+    let leave = app
+        .request(Method::DELETE, &format!("{}/members/{}", target_uri, member.id), Some(&member.token), None)
+        .await;
+    assert_eq!(leave.status, StatusCode::OK, "{}", leave.body);
+    assert_eq!(app.get(&target_uri, &member.token).await.status, StatusCode::NOT_FOUND);
+
+    let ledger = app.get(&format!("{}/transactions", target_uri), &owner.token).await;
+    let entries = ledger.data().as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["user_id"], member.id.to_string());
+
+    // Setoran lama tidak bisa dibatalkan lagi setelah keluar
+    let reverse = app
+        .post(&format!("/api/v1/savings/transactions/{}/reverse", entries[0]["id"].as_str().unwrap()), &member.token, json!({}))
+        .await;
+    assert_eq!(reverse.status, StatusCode::NOT_FOUND, "{}", reverse.body);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn contributor_cannot_reverse_money_out_of_a_shared_target(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let owner = create_user(&pool).await;
+    let contributor = create_user(&pool).await;
+    let target = create_target(&pool, owner.id, "10000").await;
+    let target_uri = format!("/api/v1/savings/targets/{}", target.id);
+
+    join(&app, &owner, &contributor, target.id, "contributor").await;
+    let deposit = app.post(&format!("{}/deposit", target_uri), &contributor.token, json!({ "amount": "2000" })).await;
+    assert_eq!(deposit.status, StatusCode::OK, "{}", deposit.body);
+
+    let ledger = app.get(&format!("{}/transactions", target_uri), &contributor.token).await;
+    let reverse_uri = format!(
+        "/api/v1/savings/transactions/{}/reverse",
+        ledger.data()[0]["id"].as_str().unwrap()
+    );
+
+    // Membatalkan setoran sama dengan menarik dana: hanya owner
+    let reverse = app.post(&reverse_uri, &contributor.token, json!({})).await;
+    assert_eq!(reverse.status, StatusCode::FORBIDDEN, "{}", reverse.body);
+    let balance = app.get(&target_uri, &owner.token).await;
+    assert_eq!(balance.data()["current_amount"], "2000.00", "{}", balance.body);
+
+    let promoted = app
+        .put(&format!("{}/members/{}", target_uri, contributor.id), &owner.token, json!({ "role": "owner" }))
+        .await;
+    assert_eq!(promoted.status, StatusCode::OK, "{}", promoted.body);
+    let reverse = app.post(&reverse_uri, &contributor.token, json!({})).await;
+    assert_eq!(reverse.status, StatusCode::CREATED, "{}", reverse.body);
+    let balance = app.get(&target_uri, &owner.token).await;
+    assert_eq!(balance.data()["current_amount"], "0.00", "{}", balance.body);
+}
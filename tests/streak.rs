@@ -0,0 +1,86 @@
+mod common;
+
+use actix_web::http::StatusCode;
+use serde_json::json;
+use sqlx::PgPool;
+
+use common::factories::{create_activity, create_target, create_user, jakarta_noon, jakarta_today};
+use common::TestApp;
+
+#[sqlx::test(migrations = "./migrations")]
+async fn streak_counts_consecutive_days_back_from_today(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "1000000").await;
+
+    // Hari ini, kemarin, 2 hari lalu, lalu bolong 3 hari lalu, deposit lagi 4 hari lalu
+    for days_ago in [0, 1, 2, 4] {
+        create_activity(&pool, user.id, Some(target.id), "deposit", 10_000, jakarta_noon(days_ago)).await;
+    }
+    // Dua deposit di hari yang sama dijumlahkan, activity selain deposit diabaikan
+    create_activity(&pool, user.id, Some(target.id), "deposit", 5_000, jakarta_noon(1)).await;
+    create_activity(&pool, user.id, Some(target.id), "withdrawal", 1_000, jakarta_noon(3)).await;
+
+    let streak = app.get("/api/v1/statistics/streak?days=7", &user.token).await;
+    assert_eq!(streak.status, StatusCode::OK, "{}", streak.body);
+    let data = streak.data();
+    assert_eq!(data["current_streak"], 3);
+
+    let days = data["days"].as_array().unwrap();
+    assert_eq!(days.len(), 7);
+    let today = &days[6];
+    assert_eq!(today["date"], jakarta_today().to_string());
+    assert_eq!(today["is_today"], true);
+
+    let yesterday = &days[5];
+    assert_eq!(yesterday["deposit_amount"], "15000.00");
+    assert_eq!(yesterday["is_part_of_streak"], true);
+
+    let gap = &days[3];
+    assert_eq!(gap["has_deposit"], false);
+
+    let before_gap = &days[2];
+    assert_eq!(before_gap["has_deposit"], true);
+    assert_eq!(before_gap["is_part_of_streak"], false);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn streak_is_zero_without_a_deposit_today(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "1000000").await;
+
+    for days_ago in [1, 2] {
+        create_activity(&pool, user.id, Some(target.id), "deposit", 10_000, jakarta_noon(days_ago)).await;
+    }
+
+    let streak = app.get("/api/v1/statistics/streak?days=7", &user.token).await;
+    assert_eq!(streak.status, StatusCode::OK, "{}", streak.body);
+    assert_eq!(streak.data()["current_streak"], 0);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn deposit_through_the_api_starts_a_streak(pool: PgPool) {
+    let app = TestApp::new(pool.clone());
+    let user = create_user(&pool).await;
+    let other = create_user(&pool).await;
+    let target = create_target(&pool, user.id, "1000000").await;
+    create_activity(&pool, user.id, Some(target.id), "deposit", 10_000, jakarta_noon(1)).await;
+
+    let deposit = app
+        .post(
+            &format!("/api/v1/savings/targets/{}/deposit", target.id),
+            &user.token,
+            json!({ "amount": 20000 }),
+        )
+        .await;
+    assert_eq!(deposit.status, StatusCode::OK, "{}", deposit.body);
+
+    let streak = app.get("/api/v1/statistics/streak", &user.token).await;
+    assert_eq!(streak.status, StatusCode::OK, "{}", streak.body);
+    assert_eq!(streak.data()["current_streak"], 2);
+
+    // Deposit user lain tidak ikut terhitung
+    let other_streak = app.get("/api/v1/statistics/streak", &other.token).await;
+    assert_eq!(other_streak.data()["current_streak"], 0);
+}